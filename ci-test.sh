@@ -5,6 +5,8 @@ if [[ "${NEAR_RELEASE}" == "true" ]]; then
     sed -n '/^borsh/p' near-sdk/Cargo.toml 
     sed -n '/^near-vm-logic/p' near-sdk/Cargo.toml
     cargo test --all
+    echo "Test overflow checks of the JSON integers with the release profile"
+    cargo test --release -p near-sdk json_types::integers
 else
     echo "Test with git version of borsh and near-vm-logic"
 
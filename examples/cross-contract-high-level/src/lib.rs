@@ -65,7 +65,7 @@ impl CrossContract {
         let arr0 = arr[..pivot].to_vec();
         let arr1 = arr[pivot..].to_vec();
         let prepaid_gas = env::prepaid_gas();
-        let account_id = env::current_account_id();
+        let account_id = String::from(env::current_account_id());
 
         ext::merge_sort(arr0, &account_id, 0, prepaid_gas / 4)
             .and(ext::merge_sort(arr1, &account_id, 0, prepaid_gas / 4))
@@ -133,7 +133,7 @@ impl CrossContract {
         // Note, for a contract to simply call another contract (1) is sufficient.
         ext_status_message::set_status(message, &account_id, 0, SINGLE_CALL_GAS).then(
             ext_status_message::get_status(
-                env::signer_account_id(),
+                env::signer_account_id().into(),
                 &account_id,
                 0,
                 SINGLE_CALL_GAS,
@@ -44,7 +44,7 @@ impl CrossContract {
         let pivot = arr.len() / 2;
         let arr0 = arr[..pivot].to_vec();
         let arr1 = arr[pivot..].to_vec();
-        let account_id = env::current_account_id();
+        let account_id = String::from(env::current_account_id());
         let prepaid_gas = env::prepaid_gas();
         let promise0 = env::promise_create(
             account_id.clone(),
@@ -128,7 +128,7 @@ impl CrossContract {
         );
         let promise1 = env::promise_then(
             promise0,
-            env::current_account_id(),
+            env::current_account_id().into(),
             b"check_promise",
             json!({}).to_string().as_bytes(),
             0,
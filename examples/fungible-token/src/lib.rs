@@ -102,7 +102,7 @@ impl FungibleToken {
             env::is_valid_account_id(escrow_account_id.as_bytes()),
             "Escrow account ID is invalid"
         );
-        let owner_id = env::predecessor_account_id();
+        let owner_id: AccountId = env::predecessor_account_id().into();
         if escrow_account_id == owner_id {
             env::panic(b"Can not increment allowance for yourself");
         }
@@ -125,7 +125,7 @@ impl FungibleToken {
             env::is_valid_account_id(escrow_account_id.as_bytes()),
             "Escrow account ID is invalid"
         );
-        let owner_id = env::predecessor_account_id();
+        let owner_id: AccountId = env::predecessor_account_id().into();
         if escrow_account_id == owner_id {
             env::panic(b"Can not decrement allowance for yourself");
         }
@@ -170,7 +170,7 @@ impl FungibleToken {
         account.balance -= amount;
 
         // If transferring by escrow, need to check and update allowance.
-        let escrow_account_id = env::predecessor_account_id();
+        let escrow_account_id: AccountId = env::predecessor_account_id().into();
         if escrow_account_id != owner_id {
             let allowance = account.get_allowance(&escrow_account_id);
             if allowance < amount {
@@ -200,7 +200,7 @@ impl FungibleToken {
     pub fn transfer(&mut self, new_owner_id: AccountId, amount: U128) {
         // NOTE: New owner's Account ID checked in transfer_from.
         // Storage fees are also refunded in transfer_from.
-        self.transfer_from(env::predecessor_account_id(), new_owner_id, amount);
+        self.transfer_from(env::predecessor_account_id().into(), new_owner_id, amount);
     }
 
     /// Returns total supply of tokens.
@@ -264,7 +264,7 @@ impl FungibleToken {
         };
         if refund_amount > 0 {
             env::log(format!("Refunding {} tokens for storage", refund_amount).as_bytes());
-            Promise::new(env::predecessor_account_id()).transfer(refund_amount);
+            Promise::new(env::predecessor_account_id().into()).transfer(refund_amount);
         }
     }
 }
@@ -81,7 +81,7 @@ impl FunToken {
     /// decreased by the amount of locked tokens.
     pub fn set_allowance(&mut self, escrow_account_id: AccountId, allowance: String) {
         let allowance = u128::from_str(&allowance).expect("Failed to parse allowance");
-        let owner_id = env::predecessor_account_id();
+        let owner_id: AccountId = env::predecessor_account_id().into();
         if escrow_account_id == owner_id {
             env::panic(b"Can't set allowance for yourself");
         }
@@ -105,7 +105,7 @@ impl FunToken {
         if lock_amount == 0 {
             env::panic(b"Can't lock 0 tokens");
         }
-        let escrow_account_id = env::predecessor_account_id();
+        let escrow_account_id: AccountId = env::predecessor_account_id().into();
         let mut account = self.get_account(&owner_id);
 
         // Checking and updating unlocked balance
@@ -141,7 +141,7 @@ impl FunToken {
         if unlock_amount == 0 {
             env::panic(b"Can't unlock 0 tokens");
         }
-        let escrow_account_id = env::predecessor_account_id();
+        let escrow_account_id: AccountId = env::predecessor_account_id().into();
         let mut account = self.get_account(&owner_id);
 
         // Checking and updating locked balance
@@ -177,7 +177,7 @@ impl FunToken {
         if amount == 0 {
             env::panic(b"Can't transfer 0 tokens");
         }
-        let escrow_account_id = env::predecessor_account_id();
+        let escrow_account_id: AccountId = env::predecessor_account_id().into();
         let mut account = self.get_account(&owner_id);
 
         // Checking and updating locked balance
@@ -219,7 +219,7 @@ impl FunToken {
 
     /// Same as `transfer_from` with `owner_id` `predecessor_id`.
     pub fn transfer(&mut self, new_owner_id: AccountId, amount: String) {
-        self.transfer_from(env::predecessor_account_id(), new_owner_id, amount);
+        self.transfer_from(env::predecessor_account_id().into(), new_owner_id, amount);
     }
 
     /// Returns total supply of tokens.
@@ -21,7 +21,7 @@ pub struct MissionControl {
 #[near_bindgen]
 impl MissionControl {
     pub fn add_agent(&mut self) {
-        let account_id = env::signer_account_id().as_bytes().to_vec();
+        let account_id = env::signer_account_id().as_ref().as_bytes().to_vec();
         self.agents.insert(account_id, Agent { account: agent_default(), is_alive: true });
     }
 
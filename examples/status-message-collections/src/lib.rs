@@ -26,7 +26,7 @@ impl Default for StatusMessage {
 impl StatusMessage {
     /// Returns true if the message is unique
     pub fn set_status(&mut self, message: String) -> bool {
-        let account_id = env::signer_account_id();
+        let account_id = String::from(env::signer_account_id());
         self.records.insert(&account_id, &message);
         self.unique_values.insert(&message)
     }
@@ -40,7 +40,7 @@ impl StatusMessage {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use near_sdk::test_utils::VMContextBuilder;
+    use near_sdk::test_utils::{clear_storage, VMContextBuilder};
     use near_sdk::MockedBlockchain;
     use near_sdk::{testing_env, VMContext};
     use std::convert::TryInto;
@@ -85,4 +85,20 @@ mod tests {
         let contract = StatusMessage::default();
         assert_eq!(None, contract.get_status("francis.near".try_into().unwrap()));
     }
+
+    #[test]
+    fn clear_storage_between_runs() {
+        // The storage of the mocked blockchain is kept by `testing_env!`, so the records of
+        // the first run would otherwise be visible to the second one.
+        for _ in 0..2 {
+            testing_env!(get_context(false));
+            clear_storage();
+            let mut contract = StatusMessage::default();
+            assert_eq!(None, contract.get_status("bob_near".try_into().unwrap()));
+            assert!(contract.set_status("hello".to_string()));
+            env::state_write(&contract);
+        }
+        StatusMessage::__reset_state();
+        assert!(!env::state_exists());
+    }
 }
@@ -16,7 +16,7 @@ pub struct StatusMessage {
 impl StatusMessage {
     #[payable]
     pub fn set_status(&mut self, message: String) {
-        let account_id = env::signer_account_id();
+        let account_id = String::from(env::signer_account_id());
         log!("{} set_status with message {}", account_id, message);
         self.records.insert(account_id, message);
     }
@@ -1,9 +1,11 @@
 use syn::export::TokenStream2;
 
+use crate::code_generator::allow_lints;
 use crate::info_extractor::{
     ArgInfo, AttrSigInfo, BindgenArgType, InputStructType, SerializerType,
 };
-use quote::quote;
+use quote::{format_ident, quote};
+use syn::{Ident, Type};
 
 impl AttrSigInfo {
     /// Create struct representing input arguments.
@@ -28,9 +30,9 @@ impl AttrSigInfo {
             !args.is_empty(),
             "Can only generate input struct for when input args are specified"
         );
-        let attribute = match input_struct_type {
+        let mut attribute = match input_struct_type {
             InputStructType::Serialization => match &self.input_serializer {
-                SerializerType::JSON => quote! {
+                SerializerType::JSON | SerializerType::Query | SerializerType::Auto => quote! {
                     #[derive(near_sdk::serde::Serialize)]
                     #[serde(crate = "near_sdk::serde")]
                 },
@@ -41,7 +43,7 @@ impl AttrSigInfo {
                 }
             },
             InputStructType::Deserialization => match &self.input_serializer {
-                SerializerType::JSON => quote! {
+                SerializerType::JSON | SerializerType::Query => quote! {
                     #[derive(near_sdk::serde::Deserialize)]
                     #[serde(crate = "near_sdk::serde")]
                 },
@@ -50,23 +52,212 @@ impl AttrSigInfo {
                         #[derive(near_sdk::borsh::BorshDeserialize)]
                     }
                 }
+                SerializerType::Auto => quote! {
+                    #[derive(near_sdk::serde::Deserialize, near_sdk::borsh::BorshDeserialize)]
+                    #[serde(crate = "near_sdk::serde")]
+                },
             },
         };
+        // With `#[log_input]` the deserialized input is serialized back to JSON for logging.
+        if let (InputStructType::Deserialization, true) = (&input_struct_type, self.is_log_input) {
+            attribute.extend(match &self.input_serializer {
+                SerializerType::JSON | SerializerType::Auto => quote! {
+                    #[derive(near_sdk::serde::Serialize)]
+                },
+                SerializerType::Borsh => quote! {
+                    #[derive(near_sdk::serde::Serialize)]
+                    #[serde(crate = "near_sdk::serde")]
+                },
+                SerializerType::Query => quote! {
+                    #[derive(near_sdk::serde::Serialize)]
+                },
+            });
+        }
         let mut fields = TokenStream2::new();
+        // Helper functions that are referenced by the serde attributes of the fields.
+        let mut helpers = TokenStream2::new();
         for arg in args {
-            let ArgInfo { ty, ident, .. } = &arg;
+            let ArgInfo { ident, serde_attrs, .. } = &arg;
+            let ty = &arg.input_ty();
+            let mut field_attrs = quote! {
+                #(#serde_attrs)*
+            };
+            if let InputStructType::Deserialization = input_struct_type {
+                // Arguments with `#[default = expr]` can be omitted in the input.
+                if let Some(default) = &arg.default {
+                    let helper = format_ident!("__default_{}", ident);
+                    let helper_str = helper.to_string();
+                    field_attrs.extend(quote! {
+                        #[serde(default = #helper_str)]
+                    });
+                    helpers.extend(quote! {
+                        fn #helper() -> #ty {
+                            #default
+                        }
+                    });
+                }
+            }
+            // Fixed-size byte arrays are accepted as hex or base64 strings under JSON.
+            let byte_array_len = match (&input_struct_type, &self.input_serializer) {
+                (InputStructType::Deserialization, SerializerType::JSON) => arg.byte_array_len(),
+                _ => None,
+            };
+            if let Some(len) = byte_array_len {
+                let helper = format_ident!("__deserialize_byte_array_{}", ident);
+                let helper_str = helper.to_string();
+                field_attrs.extend(quote! {
+                    #[serde(deserialize_with = #helper_str)]
+                });
+                helpers.extend(quote! {
+                    fn #helper<'de, D>(deserializer: D) -> Result<#ty, D::Error>
+                    where
+                        D: near_sdk::serde::Deserializer<'de>,
+                    {
+                        let bytes = near_sdk::json_types::deserialize_byte_array(deserializer, #len)?;
+                        let mut result = [0u8; #len];
+                        result.copy_from_slice(&bytes);
+                        Ok(result)
+                    }
+                });
+            }
             fields.extend(quote! {
+                #field_attrs
                 #ident: #ty,
             });
         }
+        let allow_lints = allow_lints();
         quote! {
             #attribute
+            #allow_lints
             struct Input {
                 #fields
             }
+            #helpers
+        }
+    }
+
+    /// Create public struct with the input arguments of the method, named after the method,
+    /// e.g. `SetGreetingArgs` for `set_greeting`. It serializes into the input the method expects,
+    /// so it can be used to encode the arguments off-chain, in tests and for cross-contract calls.
+    /// # Example
+    /// ```ignore
+    /// /// Arguments of `set_greeting`.
+    /// #[derive(near_sdk::serde::Serialize)]
+    /// #[serde(crate = "near_sdk::serde")]
+    /// pub struct SetGreetingArgs {
+    ///   pub message: String,
+    /// }
+    /// ```
+    pub fn args_struct(&self) -> TokenStream2 {
+        let args: Vec<_> = self.input_args().collect();
+        assert!(
+            !args.is_empty(),
+            "Can only generate args struct for when input args are specified"
+        );
+        let attribute = match &self.input_serializer {
+            SerializerType::JSON | SerializerType::Query => quote! {
+                #[derive(near_sdk::serde::Serialize)]
+                #[serde(crate = "near_sdk::serde")]
+            },
+            SerializerType::Borsh => quote! {
+                #[derive(near_sdk::borsh::BorshSerialize)]
+            },
+            SerializerType::Auto => quote! {
+                #[derive(near_sdk::serde::Serialize, near_sdk::borsh::BorshSerialize)]
+                #[serde(crate = "near_sdk::serde")]
+            },
+        };
+        let mut fields = TokenStream2::new();
+        for arg in args {
+            let ArgInfo { ident, serde_attrs, .. } = &arg;
+            let ty = arg.input_ty();
+            fields.extend(quote! {
+                #(#serde_attrs)*
+                pub #ident: #ty,
+            });
+        }
+        let name = self.args_struct_ident();
+        let doc = format!(" Arguments of `{}`.", self.ident);
+        let allow_lints = allow_lints();
+        quote! {
+            #[doc = #doc]
+            #attribute
+            #allow_lints
+            pub struct #name {
+                #fields
+            }
         }
     }
 
+    /// Create `from_input` of the struct generated by `args_struct`, which deserializes the input
+    /// of the method with the same `Input` struct as the wrapper of the method, so that the tests
+    /// can check how an input is decoded without the wrapper, which is only compiled to Wasm.
+    /// # Example
+    /// ```ignore
+    /// impl SetGreetingArgs {
+    ///   pub fn from_input(input: &[u8]) -> Result<Self, String> {
+    ///     struct Input { message: String, }
+    ///     let Input { message, }: Input = near_sdk::serde_json::from_slice(input)
+    ///       .map_err(|err| err.to_string())?;
+    ///     Ok(Self { message, })
+    ///   }
+    /// }
+    /// ```
+    pub fn args_struct_from_input(&self) -> TokenStream2 {
+        let input_struct = self.input_struct(InputStructType::Deserialization);
+        let decomposition = self.decomposition_pattern();
+        let deserialization = match &self.input_serializer {
+            SerializerType::JSON => quote! {
+                near_sdk::serde_json::from_slice(input).map_err(|err| err.to_string())?
+            },
+            SerializerType::Borsh if self.input_allow_trailing() => quote! {
+                near_sdk::borsh_from_slice_allow_trailing(input).map_err(|err| err.to_string())?
+            },
+            SerializerType::Borsh => quote! {
+                near_sdk::borsh::BorshDeserialize::try_from_slice(input).map_err(|err| err.to_string())?
+            },
+            SerializerType::Query => quote! {
+                near_sdk::serde_urlencoded::from_bytes(input).map_err(|err| err.to_string())?
+            },
+            SerializerType::Auto => quote! {
+                near_sdk::deserialize_by_caller(input)
+                    .ok_or_else(|| "Failed to deserialize input from JSON or Borsh".to_string())?
+            },
+        };
+        let idents = self.input_args().map(|arg| &arg.ident);
+        let name = self.args_struct_ident();
+        let doc = format!(" Deserializes the input of `{}` like the method does.", self.ident);
+        let allow_lints = allow_lints();
+        quote! {
+            #allow_lints
+            impl #name {
+                #[doc = #doc]
+                pub fn from_input(input: &[u8]) -> Result<Self, String> {
+                    #input_struct
+                    let #decomposition: Input = #deserialization;
+                    Ok(Self { #(#idents,)* })
+                }
+            }
+        }
+    }
+
+    /// Name of the struct generated by `args_struct`.
+    pub fn args_struct_ident(&self) -> Ident {
+        let method_name = self.ident.to_string();
+        let camel_case: String = method_name
+            .trim_start_matches("r#")
+            .split('_')
+            .map(|word| {
+                let mut chars = word.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().chain(chars).collect(),
+                    None => String::new(),
+                }
+            })
+            .collect();
+        format_ident!("{}Args", camel_case)
+    }
+
     /// Create pattern that decomposes input struct using correct mutability modifiers.
     /// # Example:
     /// ```ignore
@@ -160,10 +351,14 @@ impl AttrSigInfo {
         result
     }
 
-    /// Create code that deserializes arguments that were decorated with `#[callback]`
+    /// Create code that deserializes arguments that were decorated with `#[callback]`.
+    /// Each argument is deserialized into its own type from the promise result with the same index
+    /// as the position of the argument among the `#[callback]` arguments. The empty result of a
+    /// method that returned nothing becomes `None` for `Option` arguments and `()` for unit ones.
+    /// A result that is not ready, which means the method was called without the promise being
+    /// resolved, panics with a different message than a failed one.
     pub fn callback_deserialization(&self) -> TokenStream2 {
-        self
-            .args
+        self.args
             .iter()
             .filter(|arg| match arg.bindgen_ty {
                 BindgenArgType::CallbackArg => true,
@@ -174,31 +369,29 @@ impl AttrSigInfo {
                 let idx = idx as u64;
                 let ArgInfo { mutability, ident, ty, .. } = arg;
                 let read_data = quote! {
-                let data: Vec<u8> = match near_sdk::env::promise_result(#idx) {
-                    near_sdk::PromiseResult::Successful(x) => x,
-                    _ => panic!("Callback computation {} was not successful", #idx)
-                };
-            };
-                let invocation = match arg.serializer_ty {
-                    SerializerType::JSON => quote! {
-                        near_sdk::serde_json::from_slice(&data).expect("Failed to deserialize callback using JSON")
-                    },
-                    SerializerType::Borsh => quote! {
-                        near_sdk::borsh::BorshDeserialize::try_from_slice(&data).expect("Failed to deserialize callback using Borsh")
-                    },
+                    let data: Vec<u8> = match near_sdk::env::promise_result(#idx) {
+                        near_sdk::PromiseResult::Successful(x) => x,
+                        near_sdk::PromiseResult::NotReady => panic!("Callback computation {} is not ready", #idx),
+                        near_sdk::PromiseResult::Failed => panic!("Callback computation {} was not successful", #idx)
+                    };
                 };
+                let mut invocation = callback_deserializer(arg);
+                if is_option_or_unit(ty) {
+                    invocation = quote! {
+                        if data.is_empty() { Default::default() } else { #invocation }
+                    };
+                }
                 quote! {
-                #acc
-                #read_data
-                let #mutability #ident: #ty = #invocation;
-            }
+                    #acc
+                    #read_data
+                    let #mutability #ident: #ty = #invocation;
+                }
             })
     }
 
     /// Create code that deserializes arguments that were decorated with `#[callback_vec]`.
     pub fn callback_vec_deserialization(&self) -> TokenStream2 {
-        self
-            .args
+        self.args
             .iter()
             .filter(|arg| match arg.bindgen_ty {
                 BindgenArgType::CallbackArgVec => true,
@@ -206,25 +399,56 @@ impl AttrSigInfo {
             })
             .fold(TokenStream2::new(), |acc, arg| {
                 let ArgInfo { mutability, ident, ty, .. } = arg;
-                let invocation = match arg.serializer_ty {
-                    SerializerType::JSON => quote! {
-                        near_sdk::serde_json::from_slice(&data).expect("Failed to deserialize callback using JSON")
-                    },
-                    SerializerType::Borsh => quote! {
-                        near_sdk::borsh::BorshDeserialize::try_from_slice(&data).expect("Failed to deserialize callback using Borsh")
-                    },
-                };
+                let invocation = callback_deserializer(arg);
                 quote! {
-                #acc
-                let #mutability #ident: #ty = (0..near_sdk::env::promise_results_count())
-                .map(|i| {
-                    let data: Vec<u8> = match near_sdk::env::promise_result(i) {
-                        near_sdk::PromiseResult::Successful(x) => x,
-                        _ => panic!("Callback computation {} was not successful", i)
-                    };
-                    #invocation
-                }).collect();
-            }
+                    #acc
+                    let #mutability #ident: #ty = (0..near_sdk::env::promise_results_count())
+                    .map(|i| {
+                        let data: Vec<u8> = match near_sdk::env::promise_result(i) {
+                            near_sdk::PromiseResult::Successful(x) => x,
+                            near_sdk::PromiseResult::NotReady => panic!("Callback computation {} is not ready", i),
+                            near_sdk::PromiseResult::Failed => panic!("Callback computation {} was not successful", i)
+                        };
+                        #invocation
+                    }).collect();
+                }
             })
     }
 }
+
+/// Create expression that deserializes the `#[callback]` or `#[callback_vec]` argument from `data`,
+/// using the serializer of the argument. Shared so that the two kinds of callbacks don't drift apart.
+fn callback_deserializer(arg: &ArgInfo) -> TokenStream2 {
+    match arg.serializer_ty {
+        SerializerType::JSON => quote! {
+            near_sdk::serde_json::from_slice(&data).expect("Failed to deserialize callback using JSON")
+        },
+        SerializerType::Borsh if arg.allow_trailing => quote! {
+            near_sdk::borsh_from_slice_allow_trailing(&data).expect("Failed to deserialize callback using Borsh")
+        },
+        SerializerType::Borsh => quote! {
+            near_sdk::borsh::BorshDeserialize::try_from_slice(&data).expect("Failed to deserialize callback using Borsh")
+        },
+        SerializerType::Query => unreachable!("Callback arguments can't use the query serializer"),
+        SerializerType::Auto => {
+            unreachable!("Callback arguments can't use the auto_by_caller serializer")
+        }
+    }
+}
+
+/// Whether the type is `Option<T>` or `()`, which have a natural value for an empty promise result.
+fn is_option_or_unit(ty: &Type) -> bool {
+    match ty {
+        Type::Tuple(tuple) => tuple.elems.is_empty(),
+        Type::Path(path) => {
+            path.qself.is_none()
+                && path
+                    .path
+                    .segments
+                    .last()
+                    .map(|segment| segment.ident == "Option")
+                    .unwrap_or(false)
+        }
+        _ => false,
+    }
+}
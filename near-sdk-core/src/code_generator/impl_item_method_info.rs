@@ -1,11 +1,20 @@
-use crate::info_extractor::{AttrSigInfo, ImplItemMethodInfo, InputStructType, SerializerType};
-use quote::quote;
+use crate::code_generator::allow_lints;
+use crate::info_extractor::{
+    AllowedAccount, ArgInfo, AttrSigInfo, ImplAttr, ImplItemMethodInfo, InputStructType,
+    SerializerType,
+};
+use quote::{format_ident, quote};
 use syn::export::TokenStream2;
 use syn::{ReturnType, Signature};
 
 impl ImplItemMethodInfo {
     /// Generate wrapper method for the given method of the contract.
     pub fn method_wrapper(&self) -> TokenStream2 {
+        self.method_wrapper_with_attr(&ImplAttr::default())
+    }
+
+    /// Generate wrapper method that calls `before_call` and `after_call` hooks if they are set.
+    pub fn method_wrapper_with_attr(&self, impl_attr: &ImplAttr) -> TokenStream2 {
         let ImplItemMethodInfo { attr_signature_info, struct_type, .. } = self;
         // Args provided by `env::input()`.
         let has_input_args = attr_signature_info.input_args().next().is_some();
@@ -19,28 +28,122 @@ impl ImplItemMethodInfo {
         let arg_struct;
         let arg_parsing;
         if has_input_args {
-            arg_struct = attr_signature_info.input_struct(InputStructType::Deserialization);
-            let decomposition = attr_signature_info.decomposition_pattern();
             let serializer_invocation = match attr_signature_info.input_serializer {
                 SerializerType::JSON => quote! {
                     near_sdk::serde_json::from_slice(
                         &near_sdk::env::input().expect("Expected input since method has arguments.")
-                    ).expect("Failed to deserialize input from JSON.")
+                    ).unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed {
+                        serializer: "JSON",
+                        error: Some(err.to_string()),
+                    }.panic())
+                },
+                SerializerType::Borsh if attr_signature_info.input_allow_trailing() => quote! {
+                    near_sdk::borsh_from_slice_allow_trailing(
+                        &near_sdk::env::input().expect("Expected input since method has arguments.")
+                    ).unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed {
+                        serializer: "Borsh",
+                        error: Some(err.to_string()),
+                    }.panic())
                 },
                 SerializerType::Borsh => quote! {
                     near_sdk::borsh::BorshDeserialize::try_from_slice(
                         &near_sdk::env::input().expect("Expected input since method has arguments.")
-                    ).expect("Failed to deserialize input from Borsh.")
+                    ).unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed {
+                        serializer: "Borsh",
+                        error: Some(err.to_string()),
+                    }.panic())
+                },
+                SerializerType::Query => quote! {
+                    near_sdk::serde_urlencoded::from_bytes(
+                        &near_sdk::env::input().expect("Expected input since method has arguments.")
+                    ).unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed {
+                        serializer: "the query string",
+                        error: Some(err.to_string()),
+                    }.panic())
+                },
+                SerializerType::Auto => quote! {
+                    near_sdk::deserialize_by_caller(
+                        &near_sdk::env::input().expect("Expected input since method has arguments.")
+                    ).unwrap_or_else(|| near_sdk::SdkError::DeserializationFailed {
+                        serializer: "JSON or Borsh",
+                        error: None,
+                    }.panic())
                 },
             };
-            arg_parsing = quote! {
-                let #decomposition : Input = #serializer_invocation ;
-            };
+            if let Some(arg) = attr_signature_info.single_value_arg() {
+                // The whole input is the value of the only argument, so no input struct is needed.
+                let ArgInfo { mutability, ident, ty, .. } = arg;
+                let log_input = if attr_signature_info.is_log_input {
+                    quote! {
+                        near_sdk::env::log(
+                            near_sdk::serde_json::to_string(&#ident)
+                                .expect("Failed to serialize the input using JSON.")
+                                .as_bytes()
+                        );
+                    }
+                } else {
+                    TokenStream2::new()
+                };
+                arg_struct = TokenStream2::new();
+                arg_parsing = quote! {
+                    let #mutability #ident: #ty = #serializer_invocation ;
+                    #log_input
+                };
+            } else {
+                arg_struct = attr_signature_info.input_struct(InputStructType::Deserialization);
+                let decomposition = attr_signature_info.decomposition_pattern();
+                arg_parsing = if attr_signature_info.is_log_input {
+                    quote! {
+                        let input: Input = #serializer_invocation ;
+                        near_sdk::env::log(
+                            near_sdk::serde_json::to_string(&input)
+                                .expect("Failed to serialize the input using JSON.")
+                                .as_bytes()
+                        );
+                        let #decomposition = input;
+                    }
+                } else {
+                    quote! {
+                        let #decomposition : Input = #serializer_invocation ;
+                    }
+                };
+            }
         } else {
             arg_struct = TokenStream2::new();
             arg_parsing = TokenStream2::new();
         };
 
+        // Arguments with `#[decrypt(with = "path")]` are deserialized as ciphertext and decrypted
+        // before the method is called.
+        let decryption = attr_signature_info.input_args().fold(TokenStream2::new(), |acc, arg| {
+            let ArgInfo { mutability, ident, ty, decrypt, .. } = arg;
+            match decrypt {
+                Some(with) => {
+                    let error = format!("Failed to decrypt argument `{}`", ident);
+                    quote! {
+                        #acc
+                        let #mutability #ident: #ty = match #with(#ident.into()) {
+                            Ok(value) => value,
+                            Err(err) => near_sdk::env::panic(format!("{}: {}", #error, err).as_bytes()),
+                        };
+                    }
+                }
+                None => acc,
+            }
+        });
+
+        // Arguments with `#[expires_at]` are checked before the method is called.
+        let expiry_check = attr_signature_info.input_args().filter(|arg| arg.expires_at).fold(
+            TokenStream2::new(),
+            |acc, arg| {
+                let ident = &arg.ident;
+                quote! {
+                    #acc
+                    near_sdk::assert_not_expired(#ident);
+                }
+            },
+        );
+
         let callback_deser = attr_signature_info.callback_deserialization();
         let callback_vec_deser = attr_signature_info.callback_vec_deserialization();
 
@@ -51,26 +154,44 @@ impl ImplItemMethodInfo {
             receiver,
             returns,
             result_serializer,
+            result_serializer_with,
+            result_serializer_sorted_keys,
+            result_serializer_gzip,
+            result_serializer_raw,
             is_init,
             is_payable,
+            is_assert_one_yocto,
             is_private,
+            only_accounts,
+            is_non_reentrant,
+            max_input_len,
+            min_gas,
             is_view,
+            deprecated,
+            is_log_deprecated,
             ..
         } = attr_signature_info;
-        let deposit_check = if *is_payable || *is_view {
+        let deposit_check = if *is_assert_one_yocto {
+            quote! {
+                near_sdk::assert_one_yocto();
+            }
+        } else if *is_payable || *is_view {
             // No check if the method is payable or a view method
             quote! {}
         } else {
             // If method is not payable, do a check to make sure that it doesn't consume deposit
-            let error = format!("Method {} doesn't accept deposit", ident.to_string());
+            let method_name = attr_signature_info.method_name();
             quote! {
                 if near_sdk::env::attached_deposit() != 0 {
-                    near_sdk::env::panic(#error.as_bytes());
+                    near_sdk::SdkError::DepositNotAccepted {
+                        method_name: #method_name.to_string()
+                    }
+                    .panic();
                 }
             }
         };
         let is_private_check = if *is_private {
-            let error = format!("Method {} is private", ident.to_string());
+            let error = format!("Method {} is private", attr_signature_info.method_name());
             quote! {
                 if env::current_account_id() != env::predecessor_account_id() {
                     near_sdk::env::panic(#error.as_bytes());
@@ -79,36 +200,116 @@ impl ImplItemMethodInfo {
         } else {
             quote! {}
         };
+        let only_check = if only_accounts.is_empty() {
+            quote! {}
+        } else {
+            let accounts = only_accounts.iter().map(|account| match account {
+                AllowedAccount::Current => quote! {
+                    near_sdk::env::current_account_id().as_ref().as_str()
+                },
+                AllowedAccount::Account(account_id) => quote! { #account_id },
+            });
+            quote! {
+                near_sdk::assert_only(&[#(#accounts),*]);
+            }
+        };
+        let deprecation_log = match deprecated {
+            Some(note) if *is_log_deprecated => {
+                let mut message =
+                    format!("Method {} is deprecated", attr_signature_info.method_name());
+                if !note.is_empty() {
+                    message = format!("{}: {}", message, note);
+                }
+                quote! {
+                    near_sdk::env::log_str(#message);
+                }
+            }
+            _ => TokenStream2::new(),
+        };
+        // The callbacks resolve the promises made before the contract was paused, so they still run.
+        let pause_check =
+            if impl_attr.pausable && !*is_view && !*is_init && !attr_signature_info.is_callback() {
+                quote! {
+                    near_sdk::assert_not_paused();
+                }
+            } else {
+                TokenStream2::new()
+            };
+        // The account that initializes the contract becomes its owner, unless the `#[init]` method
+        // sets another one.
+        let owner_init = if impl_attr.ownable && *is_init {
+            quote! {
+                near_sdk::set_owner_id(Some(near_sdk::env::predecessor_account_id().as_ref()));
+            }
+        } else {
+            TokenStream2::new()
+        };
+        let input_len_check = match max_input_len {
+            Some(len) => quote! {
+                near_sdk::assert_input_len(#len);
+            },
+            None => TokenStream2::new(),
+        };
+        let gas_check = match min_gas {
+            Some(gas) => quote! {
+                near_sdk::assert_min_gas(#gas);
+            },
+            None => TokenStream2::new(),
+        };
+        // The calls of the returned promise can call the contract back, so the flag stays set until
+        // the promise resolves and is cleared by the generated callback.
+        let returns_promise = *is_non_reentrant
+            && (attr_signature_info.returns_promise()
+                || attr_signature_info.returns_promise_or_value());
+        // The callback is exported under the name of the method, which can differ from the ident.
+        let exit_callback = format_ident!("__exit_non_reentrant_{}", ident);
+        let exit_callback_name =
+            format!("__exit_non_reentrant_{}", attr_signature_info.method_name());
+        let (reentrancy_enter, reentrancy_exit) = if *is_non_reentrant {
+            let ident_str = attr_signature_info.method_name();
+            (
+                quote! {
+                    near_sdk::enter_non_reentrant(#ident_str);
+                },
+                quote! {
+                    near_sdk::exit_non_reentrant();
+                },
+            )
+        } else {
+            (TokenStream2::new(), TokenStream2::new())
+        };
+        let promise_exit = |promise: TokenStream2| {
+            if returns_promise {
+                quote! {
+                    near_sdk::exit_non_reentrant_after(&#promise, #exit_callback_name);
+                }
+            } else {
+                TokenStream2::new()
+            }
+        };
         let body = if *is_init {
             quote! {
                 let contract = #struct_type::#ident(#arg_list);
                 near_sdk::env::state_write(&contract);
             }
         } else {
-            let contract_deser;
-            let method_invocation;
-            let contract_ser;
-            if let Some(receiver) = receiver {
-                let mutability = &receiver.mutability;
-                contract_deser = quote! {
-                    let #mutability contract: #struct_type = near_sdk::env::state_read().unwrap_or_default();
-                };
-                method_invocation = quote! {
+            let (contract_deser, contract_ser) = self.contract_state();
+            let mut method_invocation = if receiver.is_some() {
+                quote! {
                     contract.#ident(#arg_list)
-                };
-                if !is_view {
-                    contract_ser = quote! {
-                        near_sdk::env::state_write(&contract);
-                    };
-                } else {
-                    contract_ser = TokenStream2::new();
                 }
             } else {
-                contract_deser = TokenStream2::new();
-                method_invocation = quote! {
+                quote! {
                     #struct_type::#ident(#arg_list)
+                }
+            };
+            if attr_signature_info.is_handle_result {
+                method_invocation = quote! {
+                    match #method_invocation {
+                        Ok(result) => result,
+                        Err(err) => near_sdk::panic_code(&err),
+                    }
                 };
-                contract_ser = TokenStream2::new();
             }
             match returns {
                 ReturnType::Default => quote! {
@@ -117,20 +318,73 @@ impl ImplItemMethodInfo {
                     #contract_ser
                 },
                 ReturnType::Type(_, _) => {
-                    let value_ser = match result_serializer {
-                        SerializerType::JSON => quote! {
+                    let value_ser = match (result_serializer, result_serializer_with) {
+                        // The returned `Vec<u8>` is already the return value.
+                        _ if *result_serializer_raw => TokenStream2::new(),
+                        (SerializerType::JSON, Some(with)) => quote! {
+                            let result = {
+                                let mut buf = Vec::new();
+                                #with::serialize(&result, &mut near_sdk::serde_json::Serializer::new(&mut buf))
+                                    .expect("Failed to serialize the return value using JSON.");
+                                buf
+                            };
+                        },
+                        (SerializerType::JSON, None) if *result_serializer_sorted_keys => quote! {
+                            let result = near_sdk::to_json_vec_sorted(&result).expect("Failed to serialize the return value using JSON.");
+                        },
+                        (SerializerType::JSON, None) => quote! {
                             let result = near_sdk::serde_json::to_vec(&result).expect("Failed to serialize the return value using JSON.");
                         },
-                        SerializerType::Borsh => quote! {
+                        (SerializerType::Borsh, _) => quote! {
                             let result = near_sdk::borsh::BorshSerialize::try_to_vec(&result).expect("Failed to serialize the return value using Borsh.");
                         },
+                        (SerializerType::Query, _) => {
+                            unreachable!("The return value can't use the query serializer")
+                        }
+                        (SerializerType::Auto, _) => {
+                            unreachable!("The return value can't use the auto_by_caller serializer")
+                        }
                     };
-                    quote! {
-                    #contract_deser
-                    let result = #method_invocation;
-                    #value_ser
-                    near_sdk::env::value_return(&result);
-                    #contract_ser
+                    let value_ser = if *result_serializer_gzip {
+                        quote! {
+                            #value_ser
+                            let result = near_sdk::gzip(&result);
+                        }
+                    } else {
+                        value_ser
+                    };
+                    if attr_signature_info.returns_promise_or_value() {
+                        let value_exit = if returns_promise {
+                            reentrancy_exit.clone()
+                        } else {
+                            TokenStream2::new()
+                        };
+                        let promise_exit = promise_exit(quote! { promise });
+                        quote! {
+                        #contract_deser
+                        match #method_invocation {
+                            near_sdk::PromiseOrValue::Value(result) => {
+                                #value_ser
+                                near_sdk::env::value_return(&result);
+                                #value_exit
+                            }
+                            near_sdk::PromiseOrValue::Promise(promise) => {
+                                #promise_exit
+                                promise.as_return();
+                            }
+                        }
+                        #contract_ser
+                        }
+                    } else {
+                        let promise_exit = promise_exit(quote! { result });
+                        quote! {
+                        #contract_deser
+                        let result = #method_invocation;
+                        #promise_exit
+                        #value_ser
+                        near_sdk::env::value_return(&result);
+                        #contract_ser
+                        }
                     }
                 }
             }
@@ -141,30 +395,119 @@ impl ImplItemMethodInfo {
                 #value
             }
         });
+        let before_call = match &impl_attr.before_call {
+            Some(path) => quote! { #path(); },
+            None => TokenStream2::new(),
+        };
+        let storage_check = match &impl_attr.max_storage {
+            Some(max_storage) if !is_view => quote! {
+                near_sdk::assert_storage_usage(#max_storage);
+            },
+            _ => TokenStream2::new(),
+        };
+        let after_call = match &impl_attr.after_call {
+            Some(path) => quote! { #path(); },
+            None => TokenStream2::new(),
+        };
+        let export = match &attr_signature_info.export_name {
+            Some(name) => quote! { #[export_name = #name] },
+            None => quote! { #[no_mangle] },
+        };
+        let allow_lints = allow_lints();
+        let reentrancy_exit = if returns_promise { TokenStream2::new() } else { reentrancy_exit };
+        // Only the contract itself calls it, after the promise returned by the method resolves.
+        let exit_export = match &attr_signature_info.export_name {
+            Some(_) => quote! { #[export_name = #exit_callback_name] },
+            None => quote! { #[no_mangle] },
+        };
+        let exit_callback = if returns_promise {
+            quote! {
+                #non_bindgen_attrs
+                #[cfg(target_arch = "wasm32")]
+                #exit_export
+                #allow_lints
+                pub extern "C" fn #exit_callback() {
+                    #panic_hook
+                    #env_creation
+                    near_sdk::exit_non_reentrant_callback();
+                }
+            }
+        } else {
+            TokenStream2::new()
+        };
+        // The wrapper calls the method, which would otherwise warn about the deprecation.
+        let allow_deprecated = match deprecated {
+            Some(_) => quote! { #[allow(deprecated)] },
+            None => TokenStream2::new(),
+        };
         quote! {
             #non_bindgen_attrs
             #[cfg(target_arch = "wasm32")]
-            #[no_mangle]
+            #export
+            #allow_lints
+            #allow_deprecated
             pub extern "C" fn #ident() {
                 #panic_hook
                 #env_creation
+                #before_call
+                #deprecation_log
+                #pause_check
                 #is_private_check
+                #only_check
                 #deposit_check
+                #gas_check
+                #reentrancy_enter
+                #input_len_check
                 #arg_struct
                 #arg_parsing
+                #decryption
+                #expiry_check
                 #callback_deser
                 #callback_vec_deser
+                #owner_init
                 #body
+                #storage_check
+                #reentrancy_exit
+                #after_call
             }
+            #exit_callback
         }
     }
 
+    /// Generates loading of the contract state into `contract` before the method is called and
+    /// writing it back after the method returns. The state is only written back for the methods
+    /// that take `&mut self` or `mut self`, and neither is generated for the methods without `self`.
+    pub fn contract_state(&self) -> (TokenStream2, TokenStream2) {
+        let ImplItemMethodInfo { attr_signature_info, struct_type, .. } = self;
+        let receiver = match &attr_signature_info.receiver {
+            Some(receiver) => receiver,
+            None => return (TokenStream2::new(), TokenStream2::new()),
+        };
+        let mutability = &receiver.mutability;
+        let load = quote! {
+            let #mutability contract: #struct_type = near_sdk::env::state_read().unwrap_or_default();
+        };
+        let store = if attr_signature_info.is_view {
+            TokenStream2::new()
+        } else {
+            quote! {
+                near_sdk::env::state_write(&contract);
+            }
+        };
+        (load, store)
+    }
+
     pub fn marshal_method(&self) -> TokenStream2 {
         let ImplItemMethodInfo { attr_signature_info, .. } = self;
         let has_input_args = attr_signature_info.input_args().next().is_some();
 
         let pat_type_list = attr_signature_info.pat_type_list();
-        let json_args = if has_input_args {
+        let json_args = if let Some(arg) = attr_signature_info.single_value_arg() {
+            let ident = &arg.ident;
+            quote! {
+              let args = near_sdk::serde_json::json!(#ident);
+            }
+        } else if has_input_args {
             let args: TokenStream2 = attr_signature_info
                 .input_args()
                 .fold(None, |acc: Option<TokenStream2>, value| {
@@ -200,7 +543,7 @@ impl ImplItemMethodInfo {
         let params = quote! {
             &self, #pat_type_list
         };
-        let ident_str = format!("{}", ident.to_string());
+        let ident_str = attr_signature_info.method_name();
         let body = if *is_view {
             quote! {
                 near_sdk::PendingContractTx::new(&self.account_id, #ident_str, args, true)
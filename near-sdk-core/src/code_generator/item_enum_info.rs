@@ -0,0 +1,103 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{Error, ItemEnum, LitStr, Meta, NestedMeta, Token};
+
+/// Generates the implementation of `near_sdk::PanicCode` for the enum whose variants are decorated
+/// with `#[panic_code("E101", "Insufficient balance")]`.
+pub fn generate_panic_code(input: &ItemEnum) -> syn::Result<TokenStream> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut codes = TokenStream::new();
+    let mut messages = TokenStream::new();
+    for variant in &input.variants {
+        let attr = variant.attrs.iter().find(|attr| attr.path.is_ident("panic_code")).ok_or_else(
+            || {
+                Error::new(
+                    variant.span(),
+                    "Every variant must have `#[panic_code(\"code\", \"message\")]`.",
+                )
+            },
+        )?;
+        let args = attr.parse_args_with(Punctuated::<LitStr, Token![,]>::parse_terminated)?;
+        let (code, message) = match args.iter().collect::<Vec<_>>().as_slice() {
+            [code, message] => (*code, *message),
+            _ => {
+                return Err(Error::new(
+                    attr.span(),
+                    "Expected `#[panic_code(\"code\", \"message\")]`.",
+                ))
+            }
+        };
+        let variant_ident = &variant.ident;
+        codes.extend(quote! {
+            Self::#variant_ident { .. } => #code,
+        });
+        messages.extend(quote! {
+            Self::#variant_ident { .. } => #message,
+        });
+    }
+    Ok(quote! {
+        impl #impl_generics near_sdk::PanicCode for #ident #ty_generics #where_clause {
+            fn code(&self) -> &'static str {
+                match self {
+                    #codes
+                }
+            }
+
+            fn message(&self) -> &'static str {
+                match self {
+                    #messages
+                }
+            }
+        }
+    })
+}
+
+/// Checks the enum decorated with `#[near_bindgen]`, which can be the type of a method argument.
+/// Borsh ignores serde attributes and always encodes the index of the variant, so an enum with
+/// `#[serde(untagged)]` that derives `BorshDeserialize` or `BorshSerialize` is rejected, as its
+/// Borsh and JSON encodings would silently disagree.
+pub fn check_enum_serializers(input: &ItemEnum) -> syn::Result<()> {
+    let untagged = input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("serde"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::List(list)) => Some(list.nested),
+            _ => None,
+        })
+        .flatten()
+        .find_map(|nested| match nested {
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("untagged") => Some(path),
+            _ => None,
+        });
+    let derives_borsh = input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("derive"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::List(list)) => Some(list.nested),
+            _ => None,
+        })
+        .flatten()
+        .any(|nested| match nested {
+            NestedMeta::Meta(Meta::Path(path)) => path
+                .segments
+                .last()
+                .map(|segment| {
+                    segment.ident == "BorshDeserialize" || segment.ident == "BorshSerialize"
+                })
+                .unwrap_or(false),
+            _ => false,
+        });
+    match untagged {
+        Some(path) if derives_borsh => Err(Error::new(
+            path.span(),
+            "Borsh can't encode untagged enums, it ignores `#[serde(untagged)]` and encodes the \
+             index of the variant. Use the enum only with the JSON serializer.",
+        )),
+        _ => Ok(()),
+    }
+}
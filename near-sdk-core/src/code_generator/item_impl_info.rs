@@ -1,4 +1,6 @@
+use crate::code_generator::allow_lints;
 use crate::ItemImplInfo;
+use quote::{format_ident, quote};
 use syn::export::TokenStream2;
 use syn::Ident;
 
@@ -6,14 +8,142 @@ impl ItemImplInfo {
     /// Generate the code that wraps
     pub fn wrapper_code(&self) -> TokenStream2 {
         let mut res = TokenStream2::new();
+        for ident in &self.attr.exports {
+            res.extend(export_marker(ident));
+        }
         for method in &self.methods {
             if method.is_public || self.is_trait_impl {
-                res.extend(method.method_wrapper());
+                res.extend(method.method_wrapper_with_attr(&self.attr));
+                let sig = &method.attr_signature_info;
+                if self.attr.args_structs
+                    && sig.input_args().next().is_some()
+                    && sig.single_value_arg().is_none()
+                {
+                    res.extend(sig.args_struct());
+                    res.extend(sig.args_struct_from_input());
+                }
             }
         }
+        if self.attr.list_methods {
+            res.extend(self.methods_list());
+        }
+        if self.attr.debug_storage {
+            res.extend(self.debug_storage());
+        }
+        if self.attr.pausable {
+            res.extend(pause_methods(self.attr.ownable));
+        }
+        if self.attr.ownable {
+            res.extend(ownership_methods());
+        }
+        if self.attr.verifying_key {
+            res.extend(verifying_key_method());
+        }
+        if let Some(field) = &self.attr.state_root {
+            res.extend(self.state_root_method(field));
+        }
+        if self.attr.reset_non_reentrant {
+            res.extend(reset_non_reentrant_method(self.attr.ownable));
+        }
         res
     }
 
+    /// Generate `__methods` that returns the names of the methods exported by this `impl` section,
+    /// both as an associated function and as a view method of the contract. Only one `impl`
+    /// section can generate it, so the methods of the other sections are not listed.
+    fn methods_list(&self) -> TokenStream2 {
+        let ty = &self.ty;
+        let names = self
+            .methods
+            .iter()
+            .filter(|method| method.is_public || self.is_trait_impl)
+            .map(|method| method.attr_signature_info.method_name());
+        let allow_lints = allow_lints();
+        quote! {
+            #allow_lints
+            impl #ty {
+                /// Names of the methods exported by the contract.
+                pub fn __methods() -> Vec<String> {
+                    vec![#(#names.to_string()),*]
+                }
+            }
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #allow_lints
+            pub extern "C" fn __methods() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                let result = near_sdk::serde_json::to_vec(&<#ty>::__methods())
+                    .expect("Failed to serialize the return value using JSON.");
+                near_sdk::env::value_return(&result);
+            }
+        }
+    }
+
+    /// Generate `__debug_storage` that returns the raw value stored under the given key, both as an
+    /// associated function and as a view method of the contract. The view method is only exported
+    /// with the `debug` feature of the contract, so that it never ends up in a production build,
+    /// while the unit tests of the contract can always call the associated function.
+    fn debug_storage(&self) -> TokenStream2 {
+        let ty = &self.ty;
+        let allow_lints = allow_lints();
+        quote! {
+            #[cfg(any(feature = "debug", not(target_arch = "wasm32")))]
+            #allow_lints
+            impl #ty {
+                /// The raw value stored under the key.
+                pub fn __debug_storage(
+                    key: near_sdk::json_types::Base64VecU8,
+                ) -> Option<near_sdk::json_types::Base64VecU8> {
+                    near_sdk::env::storage_read(&key.0).map(near_sdk::json_types::Base64VecU8)
+                }
+            }
+            #[cfg(feature = "debug")]
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #allow_lints
+            pub extern "C" fn __debug_storage() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                #[derive(near_sdk::serde::Deserialize)]
+                #[serde(crate = "near_sdk::serde")]
+                struct Input {
+                    key: near_sdk::json_types::Base64VecU8,
+                }
+                let Input { key }: Input = near_sdk::serde_json::from_slice(
+                    &near_sdk::env::input().expect("Expected input since method has arguments.")
+                )
+                .unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed {
+                    serializer: "JSON",
+                    error: Some(err.to_string()),
+                }.panic());
+                let result = near_sdk::serde_json::to_vec(&<#ty>::__debug_storage(key))
+                    .expect("Failed to serialize the return value using JSON.");
+                near_sdk::env::value_return(&result);
+            }
+        }
+    }
+
+    /// Generate the `state_root` view method that returns the root of the `MerkleTree` in the
+    /// `field` of the contract with its version, see `near_sdk::collections::StateRoot`.
+    fn state_root_method(&self, field: &Ident) -> TokenStream2 {
+        let ty = &self.ty;
+        let allow_lints = allow_lints();
+        quote! {
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #allow_lints
+            pub extern "C" fn state_root() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                let contract: #ty = near_sdk::env::state_read().unwrap_or_default();
+                let result = near_sdk::serde_json::to_vec(&contract.#field.state_root())
+                    .expect("Failed to serialize the return value using JSON.");
+                near_sdk::env::value_return(&result);
+            }
+        }
+    }
+
     #[cfg(target_arch = "wasm32")]
     pub fn marshall_code(&self) -> TokenStream2 {
         quote! {}
@@ -34,20 +164,193 @@ impl ItemImplInfo {
                 res.extend(method.marshal_method());
             }
         }
+        let allow_lints = allow_lints();
         quote! {
+         #allow_lints
          impl #name {
            #res
          }
         }
     }
 }
+
+/// Generate the check that rejects the attached deposit, like in the wrappers of the methods that
+/// are not `#[payable]`.
+fn deposit_check(method_name: &str) -> TokenStream2 {
+    quote! {
+        if near_sdk::env::attached_deposit() != 0 {
+            near_sdk::SdkError::DepositNotAccepted {
+                method_name: #method_name.to_string()
+            }
+            .panic();
+        }
+    }
+}
+
+/// Generate the `pause` and `unpause` methods of a `#[near_bindgen(pausable)]` contract. They can
+/// only be called by the contract itself, e.g. from a method that checks for the owner or from
+/// a DAO proposal, and also by the owner if the contract is `ownable`.
+fn pause_methods(ownable: bool) -> TokenStream2 {
+    let allow_lints = allow_lints();
+    let methods = [("pause", true), ("unpause", false)].iter().map(|(name, paused)| {
+        let ident = format_ident!("{}", name);
+        let error = format!("Method {} is private", name);
+        let deposit_check = deposit_check(name);
+        let access_check = if ownable {
+            quote! {
+                near_sdk::assert_owner();
+            }
+        } else {
+            quote! {
+                near_sdk::env::panic(#error.as_bytes());
+            }
+        };
+        quote! {
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #allow_lints
+            pub extern "C" fn #ident() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                if near_sdk::env::current_account_id() != near_sdk::env::predecessor_account_id() {
+                    #access_check
+                }
+                #deposit_check
+                near_sdk::set_paused(#paused);
+            }
+        }
+    });
+    quote! {
+        #(#methods)*
+    }
+}
+
+/// Generate the `reset_non_reentrant` method of a `#[near_bindgen(reset_non_reentrant)]` contract,
+/// which clears the reentrancy flag if the callback of a `#[non_reentrant]` method that returned
+/// a promise failed and left it set. Like `pause`, it can only be called by the contract itself,
+/// and also by the owner if the contract is `ownable`.
+fn reset_non_reentrant_method(ownable: bool) -> TokenStream2 {
+    let allow_lints = allow_lints();
+    let deposit_check = deposit_check("reset_non_reentrant");
+    let access_check = if ownable {
+        quote! {
+            near_sdk::assert_owner();
+        }
+    } else {
+        quote! {
+            near_sdk::env::panic("Method reset_non_reentrant is private".as_bytes());
+        }
+    };
+    quote! {
+        #[cfg(target_arch = "wasm32")]
+        #[no_mangle]
+        #allow_lints
+        pub extern "C" fn reset_non_reentrant() {
+            near_sdk::env::setup_panic_hook();
+            near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+            if near_sdk::env::current_account_id() != near_sdk::env::predecessor_account_id() {
+                #access_check
+            }
+            #deposit_check
+            near_sdk::exit_non_reentrant();
+        }
+    }
+}
+
+/// Generate a macro named after the `impl` argument that generates exported functions. The macros
+/// are exported at the root of the crate, so if another `impl` section has the same argument, the
+/// compilation fails with "the name `__near_bindgen_only_one_impl_can_use_ownable` is defined
+/// multiple times" pointing at the argument, instead of a duplicate symbol when linking.
+fn export_marker(ident: &Ident) -> TokenStream2 {
+    let marker = format_ident!("__near_bindgen_only_one_impl_can_use_{}", ident);
+    quote! {
+        #[doc(hidden)]
+        #[macro_export]
+        macro_rules! #marker {
+            () => {};
+        }
+    }
+}
+
+/// Generate the `owner`, `transfer_ownership` and `renounce_ownership` methods of
+/// a `#[near_bindgen(ownable)]` contract. The owner is kept under its own storage key, so the state
+/// struct of the contract doesn't change.
+fn ownership_methods() -> TokenStream2 {
+    let allow_lints = allow_lints();
+    let transfer_deposit_check = deposit_check("transfer_ownership");
+    let renounce_deposit_check = deposit_check("renounce_ownership");
+    quote! {
+        #[cfg(target_arch = "wasm32")]
+        #[no_mangle]
+        #allow_lints
+        pub extern "C" fn owner() {
+            near_sdk::env::setup_panic_hook();
+            near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+            let result = near_sdk::serde_json::to_vec(&near_sdk::owner_id())
+                .expect("Failed to serialize the return value using JSON.");
+            near_sdk::env::value_return(&result);
+        }
+        #[cfg(target_arch = "wasm32")]
+        #[no_mangle]
+        #allow_lints
+        pub extern "C" fn transfer_ownership() {
+            near_sdk::env::setup_panic_hook();
+            near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+            #transfer_deposit_check
+            #[derive(near_sdk::serde::Deserialize)]
+            #[serde(crate = "near_sdk::serde")]
+            struct Input {
+                new_owner_id: near_sdk::json_types::ValidAccountId,
+            }
+            let Input { new_owner_id }: Input = near_sdk::serde_json::from_slice(
+                &near_sdk::env::input().expect("Expected input since method has arguments.")
+            )
+            .unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed {
+                serializer: "JSON",
+                error: Some(err.to_string()),
+            }.panic());
+            near_sdk::transfer_ownership(new_owner_id.as_ref());
+        }
+        #[cfg(target_arch = "wasm32")]
+        #[no_mangle]
+        #allow_lints
+        pub extern "C" fn renounce_ownership() {
+            near_sdk::env::setup_panic_hook();
+            near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+            #renounce_deposit_check
+            near_sdk::renounce_ownership();
+        }
+    }
+}
+
+/// Generate the `set_verifying_key` method of a `#[near_bindgen(ownable, verifying_key)]`
+/// contract, which takes the Borsh serialization of the key as the input. The key is kept under
+/// its own storage key like the owner, and the contract reads it with
+/// `near_sdk::groth16::verifying_key()`.
+fn verifying_key_method() -> TokenStream2 {
+    let allow_lints = allow_lints();
+    let deposit_check = deposit_check("set_verifying_key");
+    quote! {
+        #[cfg(target_arch = "wasm32")]
+        #[no_mangle]
+        #allow_lints
+        pub extern "C" fn set_verifying_key() {
+            near_sdk::env::setup_panic_hook();
+            near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+            #deposit_check
+            near_sdk::groth16::update_verifying_key(
+                &near_sdk::env::input().expect("Expected input since method has arguments.")
+            );
+        }
+    }
+}
 // Rustfmt removes comas.
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
-    use syn::{Type, ImplItemMethod, parse_quote};
+    use syn::{Type, ImplItemMethod, ItemImpl, parse_quote};
     use quote::quote;
-    use crate::info_extractor::ImplItemMethodInfo;
+    use crate::info_extractor::{ImplAttr, ImplItemMethodInfo, InputStructType, ItemImplInfo};
 
 
     #[test]
@@ -59,6 +362,7 @@ mod tests {
         let expected = quote!(
             #[cfg(target_arch = "wasm32")]
             #[no_mangle]
+            #[allow(clippy::all, unused)]
             pub extern "C" fn method() {
                 near_sdk::env::setup_panic_hook();
                 near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
@@ -78,6 +382,7 @@ mod tests {
         let expected = quote!(
             #[cfg(target_arch = "wasm32")]
             #[no_mangle]
+            #[allow(clippy::all, unused)]
             pub extern "C" fn method() {
                 near_sdk::env::setup_panic_hook();
                 near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
@@ -97,11 +402,12 @@ mod tests {
         let expected = quote!(
             #[cfg(target_arch = "wasm32")]
             #[no_mangle]
+            #[allow(clippy::all, unused)]
             pub extern "C" fn method() {
                 near_sdk::env::setup_panic_hook();
                 near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
                 if near_sdk::env::attached_deposit() != 0 {
-                    near_sdk::env::panic("Method method doesn't accept deposit".as_bytes());
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "method".to_string() }.panic();
                 }
                 let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
                 contract.method();
@@ -111,6 +417,59 @@ mod tests {
         assert_eq!(expected.to_string(), actual.to_string());
     }
 
+    #[test]
+    fn contract_state() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = syn::parse_str("pub fn method(&mut self) { }").unwrap();
+        let (load, store) = ImplItemMethodInfo::new(&mut method, impl_type.clone()).unwrap().contract_state();
+        assert_eq!(
+            load.to_string(),
+            quote!(let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();).to_string()
+        );
+        assert_eq!(store.to_string(), quote!(near_sdk::env::state_write(&contract);).to_string());
+
+        let mut method: ImplItemMethod = syn::parse_str("pub fn method(&self) { }").unwrap();
+        let (load, store) = ImplItemMethodInfo::new(&mut method, impl_type.clone()).unwrap().contract_state();
+        assert_eq!(
+            load.to_string(),
+            quote!(let contract: Hello = near_sdk::env::state_read().unwrap_or_default();).to_string()
+        );
+        assert!(store.is_empty());
+
+        let mut method: ImplItemMethod = syn::parse_str("pub fn method() { }").unwrap();
+        let (load, store) = ImplItemMethodInfo::new(&mut method, impl_type).unwrap().contract_state();
+        assert!(load.is_empty());
+        assert!(store.is_empty());
+    }
+
+    #[test]
+    fn method_name() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[method_name("ftTransfer")]
+            pub fn ft_transfer(&mut self) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[export_name = "ftTransfer"]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn ft_transfer() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "ftTransfer".to_string() }.panic();
+                }
+                let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                contract.ft_transfer();
+                near_sdk::env::state_write(&contract);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+        assert!(method.attrs.is_empty());
+    }
+
     #[test]
     fn arg_no_return_no_mut() {
         let impl_type: Type = syn::parse_str("Hello").unwrap();
@@ -120,18 +479,20 @@ mod tests {
         let expected = quote!(
             #[cfg(target_arch = "wasm32")]
             #[no_mangle]
+            #[allow(clippy::all, unused)]
             pub extern "C" fn method() {
                 near_sdk::env::setup_panic_hook();
                 near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
                 #[derive(near_sdk :: serde :: Deserialize)]
                 #[serde(crate = "near_sdk::serde")]
+                #[allow(clippy::all, unused)]
                 struct Input {
                     k: u64,
                 }
                 let Input { k, }: Input = near_sdk::serde_json::from_slice(
                     &near_sdk::env::input().expect("Expected input since method has arguments.")
                 )
-                .expect("Failed to deserialize input from JSON.");
+                .unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed { serializer: "JSON", error: Some(err.to_string()) }.panic());
                 let contract: Hello = near_sdk::env::state_read().unwrap_or_default();
                 contract.method(k, );
             }
@@ -149,14 +510,16 @@ mod tests {
         let expected = quote!(
                 #[cfg(target_arch = "wasm32")]
                 #[no_mangle]
+                #[allow(clippy::all, unused)]
                 pub extern "C" fn method() {
                     near_sdk::env::setup_panic_hook();
                     near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
                     if near_sdk::env::attached_deposit() != 0 {
-                        near_sdk::env::panic("Method method doesn't accept deposit".as_bytes());
+                        near_sdk::SdkError::DepositNotAccepted { method_name: "method".to_string() }.panic();
                     }
                     #[derive(near_sdk :: serde :: Deserialize)]
                     #[serde(crate = "near_sdk::serde")]
+                    #[allow(clippy::all, unused)]
                     struct Input {
                         k: u64,
                         m: Bar,
@@ -164,7 +527,7 @@ mod tests {
                     let Input { k, m, }: Input = near_sdk::serde_json::from_slice(
                         &near_sdk::env::input().expect("Expected input since method has arguments.")
                     )
-                    .expect("Failed to deserialize input from JSON.");
+                    .unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed { serializer: "JSON", error: Some(err.to_string()) }.panic());
                     let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
                     contract.method(k, m, );
                     near_sdk::env::state_write(&contract);
@@ -183,14 +546,16 @@ mod tests {
         let expected = quote!(
                 #[cfg(target_arch = "wasm32")]
                 #[no_mangle]
+                #[allow(clippy::all, unused)]
                 pub extern "C" fn method() {
                     near_sdk::env::setup_panic_hook();
                     near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
                     if near_sdk::env::attached_deposit() != 0 {
-                        near_sdk::env::panic("Method method doesn't accept deposit".as_bytes());
+                        near_sdk::SdkError::DepositNotAccepted { method_name: "method".to_string() }.panic();
                     }
                     #[derive(near_sdk :: serde :: Deserialize)]
                     #[serde(crate = "near_sdk::serde")]
+                    #[allow(clippy::all, unused)]
                     struct Input {
                         k: u64,
                         m: Bar,
@@ -198,7 +563,7 @@ mod tests {
                     let Input { k, m, }: Input = near_sdk::serde_json::from_slice(
                         &near_sdk::env::input().expect("Expected input since method has arguments.")
                     )
-                    .expect("Failed to deserialize input from JSON.");
+                    .unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed { serializer: "JSON", error: Some(err.to_string()) }.panic());
                     let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
                     let result = contract.method(k, m, );
                     let result =
@@ -210,6 +575,94 @@ mod tests {
         assert_eq!(expected.to_string(), actual.to_string());
     }
 
+    #[test]
+    fn handle_result() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod =
+            syn::parse_str("#[handle_result] pub fn method(&mut self) -> Result<u64, TokenError> { }").unwrap();
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type.clone()).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "method".to_string() }.panic();
+                }
+                let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                let result = match contract.method() {
+                    Ok(result) => result,
+                    Err(err) => near_sdk::panic_code(&err),
+                };
+                let result = near_sdk::serde_json::to_vec(&result).expect("Failed to serialize the return value using JSON.");
+                near_sdk::env::value_return(&result);
+                near_sdk::env::state_write(&contract);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+        assert!(method.attrs.is_empty());
+
+        let mut method: ImplItemMethod =
+            syn::parse_str("#[handle_result] pub fn method(&mut self) -> u64 { }").unwrap();
+        let error = ImplItemMethodInfo::new(&mut method, impl_type).err().unwrap();
+        assert_eq!(error.to_string(), "`#[handle_result]` requires the method to return `Result<T, E>`.");
+    }
+
+    #[test]
+    fn return_tuple() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod =
+            syn::parse_str("pub fn method(&self) -> (u64, String) { }").unwrap();
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                let contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                let result = contract.method();
+                let result = near_sdk::serde_json::to_vec(&result).expect("Failed to serialize the return value using JSON.");
+                near_sdk::env::value_return(&result);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn return_promise_or_value() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod =
+            syn::parse_str("pub fn method(&self) -> PromiseOrValue<u64> { }").unwrap();
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                let contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                match contract.method() {
+                    near_sdk::PromiseOrValue::Value(result) => {
+                        let result = near_sdk::serde_json::to_vec(&result).expect("Failed to serialize the return value using JSON.");
+                        near_sdk::env::value_return(&result);
+                    }
+                    near_sdk::PromiseOrValue::Promise(promise) => {
+                        promise.as_return();
+                    }
+                }
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
     #[test]
     fn args_return_ref() {
         let impl_type: Type = syn::parse_str("Hello").unwrap();
@@ -220,6 +673,7 @@ mod tests {
         let expected = quote!(
             #[cfg(target_arch = "wasm32")]
             #[no_mangle]
+            #[allow(clippy::all, unused)]
             pub extern "C" fn method() {
                 near_sdk::env::setup_panic_hook();
                 near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
@@ -242,18 +696,20 @@ mod tests {
         let expected = quote!(
                 #[cfg(target_arch = "wasm32")]
                 #[no_mangle]
+                #[allow(clippy::all, unused)]
                 pub extern "C" fn method() {
                     near_sdk::env::setup_panic_hook();
                     near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
                     #[derive(near_sdk :: serde :: Deserialize)]
                     #[serde(crate = "near_sdk::serde")]
+                    #[allow(clippy::all, unused)]
                     struct Input {
                         k: u64,
                     }
                     let Input { k, }: Input = near_sdk::serde_json::from_slice(
                         &near_sdk::env::input().expect("Expected input since method has arguments.")
                     )
-                    .expect("Failed to deserialize input from JSON.");
+                    .unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed { serializer: "JSON", error: Some(err.to_string()) }.panic());
                     let contract: Hello = near_sdk::env::state_read().unwrap_or_default();
                     contract.method(&k, );
                 }
@@ -271,18 +727,20 @@ mod tests {
         let expected = quote!(
             #[cfg(target_arch = "wasm32")]
             #[no_mangle]
+            #[allow(clippy::all, unused)]
             pub extern "C" fn method() {
                 near_sdk::env::setup_panic_hook();
                 near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
                 #[derive(near_sdk :: serde :: Deserialize)]
                 #[serde(crate = "near_sdk::serde")]
+                #[allow(clippy::all, unused)]
                 struct Input {
                     k: u64,
                 }
                 let Input { mut k, }: Input = near_sdk::serde_json::from_slice(
                     &near_sdk::env::input().expect("Expected input since method has arguments.")
                 )
-                .expect("Failed to deserialize input from JSON.");
+                .unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed { serializer: "JSON", error: Some(err.to_string()) }.panic());
                 let contract: Hello = near_sdk::env::state_read().unwrap_or_default();
                 contract.method(&mut k, );
             }
@@ -290,6 +748,66 @@ mod tests {
         assert_eq!(expected.to_string(), actual.to_string());
     }
 
+    #[test]
+    fn arg_byte_array() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = syn::parse_str("pub fn method(&self, hash: [u8; 32]) { }").unwrap();
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                #[derive(near_sdk :: serde :: Deserialize)]
+                #[serde(crate = "near_sdk::serde")]
+                #[allow(clippy::all, unused)]
+                struct Input {
+                    #[serde(deserialize_with = "__deserialize_byte_array_hash")]
+                    hash: [u8; 32],
+                }
+                fn __deserialize_byte_array_hash<'de, D>(deserializer: D) -> Result<[u8; 32], D::Error>
+                where
+                    D: near_sdk::serde::Deserializer<'de>,
+                {
+                    let bytes = near_sdk::json_types::deserialize_byte_array(deserializer, 32)?;
+                    let mut result = [0u8; 32];
+                    result.copy_from_slice(&bytes);
+                    Ok(result)
+                }
+                let Input { hash, }: Input = near_sdk::serde_json::from_slice(
+                    &near_sdk::env::input().expect("Expected input since method has arguments.")
+                )
+                .unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed { serializer: "JSON", error: Some(err.to_string()) }.panic());
+                let contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                contract.method(hash, );
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn callback_reload_state() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[private] pub fn method(&self, #[callback(reload_state)] x: u64) { }
+        };
+        let actual = ImplItemMethodInfo::new(&mut method, impl_type.clone()).unwrap().method_wrapper();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[private] pub fn method(&self, #[callback] x: u64) { }
+        };
+        let expected = ImplItemMethodInfo::new(&mut method, impl_type.clone()).unwrap().method_wrapper();
+        // The state is read after the promise results, at the entry of the callback.
+        assert_eq!(expected.to_string(), actual.to_string());
+        assert!(actual.to_string().contains(&quote!(let contract: Hello = near_sdk::env::state_read().unwrap_or_default();).to_string()));
+        let mut method: ImplItemMethod = parse_quote! {
+            #[private] pub fn method(&self, #[callback(reload)] x: u64) { }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type).is_err());
+    }
+
     #[test]
     fn callback_args() {
         let impl_type: Type = syn::parse_str("Hello").unwrap();
@@ -301,6 +819,7 @@ mod tests {
         let expected = quote!(
             #[cfg(target_arch = "wasm32")]
             #[no_mangle]
+            #[allow(clippy::all, unused)]
             pub extern "C" fn method() {
                 near_sdk::env::setup_panic_hook();
                 near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
@@ -309,22 +828,25 @@ mod tests {
                 }
                 #[derive(near_sdk :: serde :: Deserialize)]
                 #[serde(crate = "near_sdk::serde")]
+                #[allow(clippy::all, unused)]
                 struct Input {
                     y: String,
                 }
                 let Input { y, }: Input = near_sdk::serde_json::from_slice(
                     &near_sdk::env::input().expect("Expected input since method has arguments.")
                 )
-                .expect("Failed to deserialize input from JSON.");
+                .unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed { serializer: "JSON", error: Some(err.to_string()) }.panic());
                 let data: Vec<u8> = match near_sdk::env::promise_result(0u64) {
                     near_sdk::PromiseResult::Successful(x) => x,
-                    _ => panic!("Callback computation {} was not successful", 0u64)
+                    near_sdk::PromiseResult::NotReady => panic!("Callback computation {} is not ready", 0u64),
+                    near_sdk::PromiseResult::Failed => panic!("Callback computation {} was not successful", 0u64)
                 };
                 let mut x: u64 =
                     near_sdk::serde_json::from_slice(&data).expect("Failed to deserialize callback using JSON");
                 let data: Vec<u8> = match near_sdk::env::promise_result(1u64) {
                     near_sdk::PromiseResult::Successful(x) => x,
-                    _ => panic!("Callback computation {} was not successful", 1u64)
+                    near_sdk::PromiseResult::NotReady => panic!("Callback computation {} is not ready", 1u64),
+                    near_sdk::PromiseResult::Failed => panic!("Callback computation {} was not successful", 1u64)
                 };
                 let z: Vec<u8> =
                     near_sdk::serde_json::from_slice(&data).expect("Failed to deserialize callback using JSON");
@@ -336,86 +858,212 @@ mod tests {
     }
 
     #[test]
-    fn callback_args_only() {
+    fn callback_args_heterogeneous() {
         let impl_type: Type = syn::parse_str("Hello").unwrap();
         let mut method: ImplItemMethod = parse_quote! {
-            #[private] pub fn method(&self, #[callback] x: &mut u64, #[callback] y: String) { }
+            pub fn method(&self, #[callback] price: u64, #[callback] #[serializer(borsh)] symbol: String) { }
         };
         let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
         let actual = method_info.method_wrapper();
         let expected = quote!(
             #[cfg(target_arch = "wasm32")]
             #[no_mangle]
+            #[allow(clippy::all, unused)]
             pub extern "C" fn method() {
                 near_sdk::env::setup_panic_hook();
                 near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
-                if env::current_account_id() != env::predecessor_account_id() {
-                    near_sdk::env::panic("Method method is private".as_bytes());
-                }
                 let data: Vec<u8> = match near_sdk::env::promise_result(0u64) {
                     near_sdk::PromiseResult::Successful(x) => x,
-                    _ => panic!("Callback computation {} was not successful", 0u64)
+                    near_sdk::PromiseResult::NotReady => panic!("Callback computation {} is not ready", 0u64),
+                    near_sdk::PromiseResult::Failed => panic!("Callback computation {} was not successful", 0u64)
                 };
-                let mut x: u64 =
-                    near_sdk::serde_json::from_slice(&data).expect("Failed to deserialize callback using JSON");
+                let price: u64 = near_sdk::serde_json::from_slice(&data).expect("Failed to deserialize callback using JSON");
                 let data: Vec<u8> = match near_sdk::env::promise_result(1u64) {
                     near_sdk::PromiseResult::Successful(x) => x,
-                    _ => panic!("Callback computation {} was not successful", 1u64)
+                    near_sdk::PromiseResult::NotReady => panic!("Callback computation {} is not ready", 1u64),
+                    near_sdk::PromiseResult::Failed => panic!("Callback computation {} was not successful", 1u64)
                 };
-                let y: String =
-                    near_sdk::serde_json::from_slice(&data).expect("Failed to deserialize callback using JSON");
+                let symbol: String = near_sdk::borsh::BorshDeserialize::try_from_slice(&data).expect("Failed to deserialize callback using Borsh");
                 let contract: Hello = near_sdk::env::state_read().unwrap_or_default();
-                contract.method(&mut x, y, );
+                contract.method(price, symbol, );
             }
         );
-
         assert_eq!(expected.to_string(), actual.to_string());
     }
 
     #[test]
-    fn callback_args_vec() {
+    fn callback_args_empty_result() {
         let impl_type: Type = syn::parse_str("Hello").unwrap();
         let mut method: ImplItemMethod = parse_quote! {
-            #[private] pub fn method(&self, #[callback_vec] x: Vec<String>, y: String) { }
+            #[private]
+            pub fn on_transfer(&mut self, #[callback] balance: Option<u64>, #[callback] #[serializer(borsh)] done: ()) { }
         };
         let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
         let actual = method_info.method_wrapper();
         let expected = quote!(
             #[cfg(target_arch = "wasm32")]
             #[no_mangle]
-            pub extern "C" fn method() {
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn on_transfer() {
                 near_sdk::env::setup_panic_hook();
                 near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
                 if env::current_account_id() != env::predecessor_account_id() {
-                    near_sdk::env::panic("Method method is private".as_bytes());
+                    near_sdk::env::panic("Method on_transfer is private".as_bytes());
                 }
-                #[derive(near_sdk :: serde :: Deserialize)]
-                #[serde(crate = "near_sdk::serde")]
-                struct Input {
-                    y: String,
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "on_transfer".to_string() }.panic();
                 }
-                let Input { y, }: Input = near_sdk::serde_json::from_slice(
-                    &near_sdk::env::input().expect("Expected input since method has arguments.")
-                )
-                .expect("Failed to deserialize input from JSON.");
-                let x: Vec<String> = (0..near_sdk::env::promise_results_count())
-                    .map(|i| {
-                        let data: Vec<u8> = match near_sdk::env::promise_result(i) {
-                            near_sdk::PromiseResult::Successful(x) => x,
-                            _ => panic!("Callback computation {} was not successful", i)
-                        };
-                        near_sdk::serde_json::from_slice(&data).expect("Failed to deserialize callback using JSON")
-                    })
-                    .collect();
-                let contract: Hello = near_sdk::env::state_read().unwrap_or_default();
-                contract.method(x, y, );
-            }
-        );
-        assert_eq!(expected.to_string(), actual.to_string());
-    }
-
-    #[test]
-    fn simple_init() {
+                let data: Vec<u8> = match near_sdk::env::promise_result(0u64) {
+                    near_sdk::PromiseResult::Successful(x) => x,
+                    near_sdk::PromiseResult::NotReady => panic!("Callback computation {} is not ready", 0u64),
+                    near_sdk::PromiseResult::Failed => panic!("Callback computation {} was not successful", 0u64)
+                };
+                let balance: Option<u64> = if data.is_empty() {
+                    Default::default()
+                } else {
+                    near_sdk::serde_json::from_slice(&data).expect("Failed to deserialize callback using JSON")
+                };
+                let data: Vec<u8> = match near_sdk::env::promise_result(1u64) {
+                    near_sdk::PromiseResult::Successful(x) => x,
+                    near_sdk::PromiseResult::NotReady => panic!("Callback computation {} is not ready", 1u64),
+                    near_sdk::PromiseResult::Failed => panic!("Callback computation {} was not successful", 1u64)
+                };
+                let done: () = if data.is_empty() {
+                    Default::default()
+                } else {
+                    near_sdk::borsh::BorshDeserialize::try_from_slice(&data).expect("Failed to deserialize callback using Borsh")
+                };
+                let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                contract.on_transfer(balance, done, );
+                near_sdk::env::state_write(&contract);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn callback_args_only() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[private] pub fn method(&self, #[callback] x: &mut u64, #[callback] y: String) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                if env::current_account_id() != env::predecessor_account_id() {
+                    near_sdk::env::panic("Method method is private".as_bytes());
+                }
+                let data: Vec<u8> = match near_sdk::env::promise_result(0u64) {
+                    near_sdk::PromiseResult::Successful(x) => x,
+                    near_sdk::PromiseResult::NotReady => panic!("Callback computation {} is not ready", 0u64),
+                    near_sdk::PromiseResult::Failed => panic!("Callback computation {} was not successful", 0u64)
+                };
+                let mut x: u64 =
+                    near_sdk::serde_json::from_slice(&data).expect("Failed to deserialize callback using JSON");
+                let data: Vec<u8> = match near_sdk::env::promise_result(1u64) {
+                    near_sdk::PromiseResult::Successful(x) => x,
+                    near_sdk::PromiseResult::NotReady => panic!("Callback computation {} is not ready", 1u64),
+                    near_sdk::PromiseResult::Failed => panic!("Callback computation {} was not successful", 1u64)
+                };
+                let y: String =
+                    near_sdk::serde_json::from_slice(&data).expect("Failed to deserialize callback using JSON");
+                let contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                contract.method(&mut x, y, );
+            }
+        );
+
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn callback_args_vec() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[private] pub fn method(&self, #[callback_vec] x: Vec<String>, y: String) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                if env::current_account_id() != env::predecessor_account_id() {
+                    near_sdk::env::panic("Method method is private".as_bytes());
+                }
+                #[derive(near_sdk :: serde :: Deserialize)]
+                #[serde(crate = "near_sdk::serde")]
+                #[allow(clippy::all, unused)]
+                struct Input {
+                    y: String,
+                }
+                let Input { y, }: Input = near_sdk::serde_json::from_slice(
+                    &near_sdk::env::input().expect("Expected input since method has arguments.")
+                )
+                .unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed { serializer: "JSON", error: Some(err.to_string()) }.panic());
+                // A single failed result panics inside the loop, which reverts the whole callback.
+                let x: Vec<String> = (0..near_sdk::env::promise_results_count())
+                    .map(|i| {
+                        let data: Vec<u8> = match near_sdk::env::promise_result(i) {
+                            near_sdk::PromiseResult::Successful(x) => x,
+                            near_sdk::PromiseResult::NotReady => panic!("Callback computation {} is not ready", i),
+                            near_sdk::PromiseResult::Failed => panic!("Callback computation {} was not successful", i)
+                        };
+                        near_sdk::serde_json::from_slice(&data).expect("Failed to deserialize callback using JSON")
+                    })
+                    .collect();
+                let contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                contract.method(x, y, );
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn callback_args_vec_borsh() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[private] pub fn method(&self, #[callback_vec] #[serializer(borsh)] x: Vec<Quote>) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                if env::current_account_id() != env::predecessor_account_id() {
+                    near_sdk::env::panic("Method method is private".as_bytes());
+                }
+                let x: Vec<Quote> = (0..near_sdk::env::promise_results_count())
+                    .map(|i| {
+                        let data: Vec<u8> = match near_sdk::env::promise_result(i) {
+                            near_sdk::PromiseResult::Successful(x) => x,
+                            near_sdk::PromiseResult::NotReady => panic!("Callback computation {} is not ready", i),
+                            near_sdk::PromiseResult::Failed => panic!("Callback computation {} was not successful", i)
+                        };
+                        near_sdk::borsh::BorshDeserialize::try_from_slice(&data).expect("Failed to deserialize callback using Borsh")
+                    })
+                    .collect();
+                let contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                contract.method(x, );
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn simple_init() {
         let impl_type: Type = syn::parse_str("Hello").unwrap();
         let mut method: ImplItemMethod = parse_quote! {
             #[init]
@@ -426,21 +1074,23 @@ mod tests {
         let expected = quote!(
             #[cfg(target_arch = "wasm32")]
             #[no_mangle]
+            #[allow(clippy::all, unused)]
             pub extern "C" fn method() {
                 near_sdk::env::setup_panic_hook();
                 near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
                 if near_sdk::env::attached_deposit() != 0 {
-                    near_sdk::env::panic("Method method doesn't accept deposit".as_bytes());
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "method".to_string() }.panic();
                 }
                 #[derive(near_sdk :: serde :: Deserialize)]
                 #[serde(crate = "near_sdk::serde")]
+                #[allow(clippy::all, unused)]
                 struct Input {
                     k: u64,
                 }
                 let Input { mut k, }: Input = near_sdk::serde_json::from_slice(
                     &near_sdk::env::input().expect("Expected input since method has arguments.")
                 )
-                .expect("Failed to deserialize input from JSON.");
+                .unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed { serializer: "JSON", error: Some(err.to_string()) }.panic());
                 let contract = Hello::method(&mut k,);
                 near_sdk::env::state_write(&contract);
             }
@@ -461,18 +1111,20 @@ mod tests {
         let expected = quote!(
             #[cfg(target_arch = "wasm32")]
             #[no_mangle]
+            #[allow(clippy::all, unused)]
             pub extern "C" fn method() {
                 near_sdk::env::setup_panic_hook();
                 near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
                 #[derive(near_sdk :: serde :: Deserialize)]
                 #[serde(crate = "near_sdk::serde")]
+                #[allow(clippy::all, unused)]
                 struct Input {
                     k: u64,
                 }
                 let Input { mut k, }: Input = near_sdk::serde_json::from_slice(
                     &near_sdk::env::input().expect("Expected input since method has arguments.")
                 )
-                .expect("Failed to deserialize input from JSON.");
+                .unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed { serializer: "JSON", error: Some(err.to_string()) }.panic());
                 let contract = Hello::method(&mut k,);
                 near_sdk::env::state_write(&contract);
             }
@@ -492,13 +1144,15 @@ mod tests {
         let expected = quote!(
             #[cfg(target_arch = "wasm32")]
             #[no_mangle]
+            #[allow(clippy::all, unused)]
             pub extern "C" fn method() {
                 near_sdk::env::setup_panic_hook();
                 near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
                 if near_sdk::env::attached_deposit() != 0 {
-                    near_sdk::env::panic("Method method doesn't accept deposit".as_bytes());
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "method".to_string() }.panic();
                 }
                 #[derive(near_sdk :: borsh :: BorshDeserialize)]
+                #[allow(clippy::all, unused)]
                 struct Input {
                     k: u64,
                     m: Bar,
@@ -506,7 +1160,7 @@ mod tests {
                 let Input { k, m, }: Input = near_sdk::borsh::BorshDeserialize::try_from_slice(
                     &near_sdk::env::input().expect("Expected input since method has arguments.")
                 )
-                .expect("Failed to deserialize input from Borsh.");
+                .unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed { serializer: "Borsh", error: Some(err.to_string()) }.panic());
                 let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
                 let result = contract.method(k, m, );
                 let result = near_sdk::borsh::BorshSerialize::try_to_vec(&result)
@@ -519,63 +1173,135 @@ mod tests {
     }
 
     #[test]
-    fn callback_args_mixed_serialization() {
+    fn borsh_allow_trailing() {
         let impl_type: Type = syn::parse_str("Hello").unwrap();
         let mut method: ImplItemMethod = parse_quote! {
-            #[private] pub fn method(&self, #[callback] #[serializer(borsh)] x: &mut u64, #[serializer(borsh)] y: String, #[callback] #[serializer(json)] z: Vec<u8>) { }
+            pub fn method(&mut self, #[serializer(borsh, allow_trailing)] k: u64, #[serializer(borsh)] m: Bar, #[callback] #[serializer(borsh, allow_trailing)] x: u64) { }
         };
         let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
         let actual = method_info.method_wrapper();
         let expected = quote!(
             #[cfg(target_arch = "wasm32")]
             #[no_mangle]
+            #[allow(clippy::all, unused)]
             pub extern "C" fn method() {
                 near_sdk::env::setup_panic_hook();
                 near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
-                if env::current_account_id() != env::predecessor_account_id() {
-                    near_sdk::env::panic("Method method is private".as_bytes());
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "method".to_string() }.panic();
                 }
                 #[derive(near_sdk :: borsh :: BorshDeserialize)]
+                #[allow(clippy::all, unused)]
                 struct Input {
-                    y: String,
+                    k: u64,
+                    m: Bar,
                 }
-                let Input { y, }: Input = near_sdk::borsh::BorshDeserialize::try_from_slice(
+                let Input { k, m, }: Input = near_sdk::borsh_from_slice_allow_trailing(
                     &near_sdk::env::input().expect("Expected input since method has arguments.")
                 )
-                .expect("Failed to deserialize input from Borsh.");
+                .unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed { serializer: "Borsh", error: Some(err.to_string()) }.panic());
                 let data: Vec<u8> = match near_sdk::env::promise_result(0u64) {
                     near_sdk::PromiseResult::Successful(x) => x,
-                    _ => panic!("Callback computation {} was not successful", 0u64)
+                    near_sdk::PromiseResult::NotReady => panic!("Callback computation {} is not ready", 0u64),
+                    near_sdk::PromiseResult::Failed => panic!("Callback computation {} was not successful", 0u64)
                 };
-                let mut x: u64 = near_sdk::borsh::BorshDeserialize::try_from_slice(&data)
-                    .expect("Failed to deserialize callback using Borsh");
-                let data: Vec<u8> = match near_sdk::env::promise_result(1u64) {
-                    near_sdk::PromiseResult::Successful(x) => x,
-                    _ => panic!("Callback computation {} was not successful", 1u64)
-                };
-                let z: Vec<u8> =
-                    near_sdk::serde_json::from_slice(&data).expect("Failed to deserialize callback using JSON");
+                let x: u64 = near_sdk::borsh_from_slice_allow_trailing(&data).expect("Failed to deserialize callback using Borsh");
+                let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                contract.method(k, m, x, );
+                near_sdk::env::state_write(&contract);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn borsh_allow_trailing_json() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn method(&mut self, #[serializer(json, allow_trailing)] k: u64) { }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type).is_err());
+    }
+
+    #[test]
+    fn borsh_allow_trailing_twice() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn method(&mut self, #[serializer(borsh, allow_trailing)] k: u64, #[serializer(borsh, allow_trailing)] m: u64) { }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type).is_err());
+    }
+
+    #[test]
+    fn result_serializer_raw() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[result_serializer(raw)]
+            pub fn method(&self) -> Vec<u8> { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
                 let contract: Hello = near_sdk::env::state_read().unwrap_or_default();
-                contract.method(&mut x, y, z, );
+                let result = contract.method();
+                near_sdk::env::value_return(&result);
             }
         );
         assert_eq!(expected.to_string(), actual.to_string());
     }
 
     #[test]
-    fn no_args_no_return_mut_payable() {
+    fn result_serializer_raw_not_bytes() {
         let impl_type: Type = syn::parse_str("Hello").unwrap();
-        let mut method: ImplItemMethod = syn::parse_str("#[payable] pub fn method(&mut self) { }").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[result_serializer(raw)]
+            pub fn method(&self) -> Vec<u64> { }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type.clone()).is_err());
+        let mut method: ImplItemMethod = parse_quote! {
+            #[result_serializer(raw)]
+            pub fn method(&self) { }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type).is_err());
+    }
+
+    #[test]
+    fn max_input_len() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[max_input_len = 1024]
+            pub fn method(&mut self, #[serializer(borsh)] values: Vec<u64>) { }
+        };
         let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
         let actual = method_info.method_wrapper();
         let expected = quote!(
             #[cfg(target_arch = "wasm32")]
             #[no_mangle]
+            #[allow(clippy::all, unused)]
             pub extern "C" fn method() {
                 near_sdk::env::setup_panic_hook();
                 near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "method".to_string() }.panic();
+                }
+                near_sdk::assert_input_len(1024);
+                #[derive(near_sdk :: borsh :: BorshDeserialize)]
+                #[allow(clippy::all, unused)]
+                struct Input {
+                    values: Vec<u64>,
+                }
+                let Input { values, }: Input = near_sdk::borsh::BorshDeserialize::try_from_slice(
+                    &near_sdk::env::input().expect("Expected input since method has arguments.")
+                )
+                .unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed { serializer: "Borsh", error: Some(err.to_string()) }.panic());
                 let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
-                contract.method();
+                contract.method(values, );
                 near_sdk::env::state_write(&contract);
             }
         );
@@ -583,31 +1309,1770 @@ mod tests {
     }
 
     #[test]
-    fn private_method() {
+    fn max_input_len_not_integer() {
         let impl_type: Type = syn::parse_str("Hello").unwrap();
-        let mut method: ImplItemMethod = syn::parse_str("#[private] pub fn private_method(&mut self) { }").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[max_input_len = "1024"]
+            pub fn method(&mut self, values: Vec<u64>) { }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type).is_err());
+    }
+
+    #[test]
+    fn min_gas() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[min_gas(tgas = 20)]
+            pub fn on_transfer(&mut self) { }
+        };
         let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
         let actual = method_info.method_wrapper();
         let expected = quote!(
             #[cfg(target_arch = "wasm32")]
             #[no_mangle]
-            pub extern "C" fn private_method() {
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn on_transfer() {
                 near_sdk::env::setup_panic_hook();
                 near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
-                if env::current_account_id() != env::predecessor_account_id() {
-                    near_sdk::env::panic("Method private_method is private".as_bytes());
-                }
                 if near_sdk::env::attached_deposit() != 0 {
-                    near_sdk::env::panic("Method private_method doesn't accept deposit".as_bytes());
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "on_transfer".to_string() }.panic();
                 }
+                near_sdk::assert_min_gas(20000000000000u64);
                 let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
-                contract.private_method();
+                contract.on_transfer();
                 near_sdk::env::state_write(&contract);
             }
         );
         assert_eq!(expected.to_string(), actual.to_string());
     }
 
+    #[test]
+    fn min_gas_invalid() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[min_gas(gas = 20)]
+            pub fn method(&mut self) { }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type.clone()).is_err());
+        let mut method: ImplItemMethod = parse_quote! {
+            #[min_gas(tgas = 100000000)]
+            pub fn method(&mut self) { }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type.clone()).is_err());
+        let mut method: ImplItemMethod = parse_quote! {
+            #[min_gas(tgas = 20)]
+            pub fn method(&self) { }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type).is_err());
+    }
+
+    #[test]
+    fn result_serializer_with() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[result_serializer(json, with = "hex_bytes")]
+            pub fn method(&self) -> Vec<u8> { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                let contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                let result = contract.method();
+                let result = {
+                    let mut buf = Vec::new();
+                    hex_bytes::serialize(&result, &mut near_sdk::serde_json::Serializer::new(&mut buf))
+                        .expect("Failed to serialize the return value using JSON.");
+                    buf
+                };
+                near_sdk::env::value_return(&result);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn result_serializer_with_unsupported() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[result_serializer(borsh, with = "hex_bytes")]
+            pub fn method(&self) -> Vec<u8> { }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type.clone()).is_err());
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn method(&self, #[serializer(json, with = "hex_bytes")] x: Vec<u8>) { }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type).is_err());
+    }
+
+    #[test]
+    fn result_serializer_sorted_keys() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[result_serializer(json, sorted_keys)]
+            pub fn method(&self) -> HashMap<String, u64> { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                let contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                let result = contract.method();
+                let result = near_sdk::to_json_vec_sorted(&result).expect("Failed to serialize the return value using JSON.");
+                near_sdk::env::value_return(&result);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn result_serializer_gzip() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[result_serializer(json, sorted_keys, gzip)]
+            pub fn method(&self) -> HashMap<String, u64> { }
+        };
+        if !cfg!(feature = "gzip") {
+            // Without the feature `near_sdk::gzip` doesn't exist.
+            assert!(ImplItemMethodInfo::new(&mut method, impl_type).is_err());
+            return;
+        }
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type.clone()).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                let contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                let result = contract.method();
+                let result = near_sdk::to_json_vec_sorted(&result).expect("Failed to serialize the return value using JSON.");
+                let result = near_sdk::gzip(&result);
+                near_sdk::env::value_return(&result);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+        let mut method: ImplItemMethod = parse_quote! {
+            #[result_serializer(borsh, gzip)]
+            pub fn method(&self) -> u64 { }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type.clone()).is_err());
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn method(&self, #[serializer(json, gzip)] x: u64) { }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type).is_err());
+    }
+
+    #[test]
+    fn result_serializer_sorted_keys_unsupported() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[result_serializer(borsh, sorted_keys)]
+            pub fn method(&self) -> HashMap<String, u64> { }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type.clone()).is_err());
+        let mut method: ImplItemMethod = parse_quote! {
+            #[result_serializer(json, with = "hex_bytes", sorted_keys)]
+            pub fn method(&self) -> Vec<u8> { }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type.clone()).is_err());
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn method(&self, #[serializer(json, sorted_keys)] x: HashMap<String, u64>) { }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type).is_err());
+    }
+
+    #[test]
+    fn callback_args_mixed_serialization() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[private] pub fn method(&self, #[callback] #[serializer(borsh)] x: &mut u64, #[serializer(borsh)] y: String, #[callback] #[serializer(json)] z: Vec<u8>) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                if env::current_account_id() != env::predecessor_account_id() {
+                    near_sdk::env::panic("Method method is private".as_bytes());
+                }
+                #[derive(near_sdk :: borsh :: BorshDeserialize)]
+                #[allow(clippy::all, unused)]
+                struct Input {
+                    y: String,
+                }
+                let Input { y, }: Input = near_sdk::borsh::BorshDeserialize::try_from_slice(
+                    &near_sdk::env::input().expect("Expected input since method has arguments.")
+                )
+                .unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed { serializer: "Borsh", error: Some(err.to_string()) }.panic());
+                let data: Vec<u8> = match near_sdk::env::promise_result(0u64) {
+                    near_sdk::PromiseResult::Successful(x) => x,
+                    near_sdk::PromiseResult::NotReady => panic!("Callback computation {} is not ready", 0u64),
+                    near_sdk::PromiseResult::Failed => panic!("Callback computation {} was not successful", 0u64)
+                };
+                let mut x: u64 = near_sdk::borsh::BorshDeserialize::try_from_slice(&data)
+                    .expect("Failed to deserialize callback using Borsh");
+                let data: Vec<u8> = match near_sdk::env::promise_result(1u64) {
+                    near_sdk::PromiseResult::Successful(x) => x,
+                    near_sdk::PromiseResult::NotReady => panic!("Callback computation {} is not ready", 1u64),
+                    near_sdk::PromiseResult::Failed => panic!("Callback computation {} was not successful", 1u64)
+                };
+                let z: Vec<u8> =
+                    near_sdk::serde_json::from_slice(&data).expect("Failed to deserialize callback using JSON");
+                let contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                contract.method(&mut x, y, z, );
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn assert_one_yocto() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        for attrs in &["#[assert_one_yocto]", "#[payable] #[assert_one_yocto]"] {
+            let mut method: ImplItemMethod =
+                syn::parse_str(&format!("{} pub fn method(&mut self) {{ }}", attrs)).unwrap();
+            let method_info = ImplItemMethodInfo::new(&mut method, impl_type.clone()).unwrap();
+            let actual = method_info.method_wrapper();
+            let expected = quote!(
+                #[cfg(target_arch = "wasm32")]
+                #[no_mangle]
+                #[allow(clippy::all, unused)]
+                pub extern "C" fn method() {
+                    near_sdk::env::setup_panic_hook();
+                    near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                    near_sdk::assert_one_yocto();
+                    let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                    contract.method();
+                    near_sdk::env::state_write(&contract);
+                }
+            );
+            assert_eq!(expected.to_string(), actual.to_string());
+            assert!(method.attrs.is_empty());
+        }
+    }
+
+    #[test]
+    fn assert_one_yocto_view() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = syn::parse_str("#[assert_one_yocto] pub fn method(&self) { }").unwrap();
+        let error = ImplItemMethodInfo::new(&mut method, impl_type).err().unwrap();
+        assert_eq!(error.to_string(), "Method with `#[assert_one_yocto]` must be mutable (not view)");
+    }
+
+    #[test]
+    fn no_args_no_return_mut_payable() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = syn::parse_str("#[payable] pub fn method(&mut self) { }").unwrap();
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                contract.method();
+                near_sdk::env::state_write(&contract);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn private_method() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = syn::parse_str("#[private] pub fn private_method(&mut self) { }").unwrap();
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn private_method() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                if env::current_account_id() != env::predecessor_account_id() {
+                    near_sdk::env::panic("Method private_method is private".as_bytes());
+                }
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "private_method".to_string() }.panic();
+                }
+                let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                contract.private_method();
+                near_sdk::env::state_write(&contract);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn cfg_feature_method() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = syn::parse_str("#[cfg(feature = \"testnet\")] pub fn method(&mut self) { }").unwrap();
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(feature = "testnet")]
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "method".to_string() }.panic();
+                }
+                let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                contract.method();
+                near_sdk::env::state_write(&contract);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+        let actual = method_info.marshal_method();
+        let expected = quote!(
+                #[cfg(not(target_arch = "wasm32"))]
+                #[cfg(feature = "testnet")]
+                pub fn method(&self, ) -> near_sdk::PendingContractTx {
+                    let args = near_sdk::serde_json::json!({});
+                    near_sdk::PendingContractTx::new(& self . account_id, "method", args, false)
+                }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn log_input() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = syn::parse_str("#[log_input] pub fn method(&self, k: u64, m: Bar) { }").unwrap();
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                #[derive(near_sdk :: serde :: Deserialize)]
+                #[serde(crate = "near_sdk::serde")]
+                #[derive(near_sdk::serde::Serialize)]
+                #[allow(clippy::all, unused)]
+                struct Input {
+                    k: u64,
+                    m: Bar,
+                }
+                let input: Input = near_sdk::serde_json::from_slice(
+                    &near_sdk::env::input().expect("Expected input since method has arguments.")
+                )
+                .unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed { serializer: "JSON", error: Some(err.to_string()) }.panic());
+                near_sdk::env::log(
+                    near_sdk::serde_json::to_string(&input)
+                        .expect("Failed to serialize the input using JSON.")
+                        .as_bytes()
+                );
+                let Input { k, m, } = input;
+                let contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                contract.method(k, m, );
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn call_hooks() {
+        let mut item_impl: ItemImpl = syn::parse_str("impl Hello { pub fn a(&self) { } pub fn b(&self) { } }").unwrap();
+        let mut item_impl_info = ItemImplInfo::new(&mut item_impl).unwrap();
+        item_impl_info.attr = syn::parse_str("before_call = log_call, after_call = metrics::record").unwrap();
+        let actual = item_impl_info.wrapper_code();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn a() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                log_call();
+                let contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                contract.a();
+                metrics::record();
+            }
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn b() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                log_call();
+                let contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                contract.b();
+                metrics::record();
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn call_hooks_unsupported() {
+        assert!(syn::parse_str::<ImplAttr>("before_call = a, after = b").is_err());
+        assert!(syn::parse_str::<ImplAttr>("before_call = a, before_call = b").is_err());
+        assert!(syn::parse_str::<ImplAttr>("before_call = 10").is_err());
+        assert!(syn::parse_str::<ImplAttr>("max_storage = a").is_err());
+        assert!(syn::parse_str::<ImplAttr>("max_storage = \"10\"").is_err());
+        assert!(syn::parse_str::<ImplAttr>("max_storage = 10, max_storage = 20").is_err());
+        // The legacy `init => new` form is accepted and ignored.
+        let attr = syn::parse_str::<ImplAttr>("init => new, before_call = a").unwrap();
+        assert!(attr.before_call.is_some() && attr.after_call.is_none());
+    }
+
+    #[test]
+    fn associated_types() {
+        let mut item_impl: ItemImpl = parse_quote! {
+            impl FungibleToken for Hello {
+                type Balance = U128;
+                fn balance_of(&self, account_id: String) -> Self::Balance { }
+                fn transfer(&mut self, amounts: Vec<Self::Balance>) { }
+            }
+        };
+        let item_impl_info = ItemImplInfo::new(&mut item_impl).unwrap();
+        let returns = &item_impl_info.methods[0].attr_signature_info.returns;
+        assert_eq!(quote!(#returns).to_string(), quote!(-> U128).to_string());
+        let actual = item_impl_info.methods[1].method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn transfer() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "transfer".to_string() }.panic();
+                }
+                #[derive(near_sdk :: serde :: Deserialize)]
+                #[serde(crate = "near_sdk::serde")]
+                #[allow(clippy::all, unused)]
+                struct Input {
+                    amounts: Vec<U128>,
+                }
+                let Input { amounts, }: Input = near_sdk::serde_json::from_slice(
+                    &near_sdk::env::input().expect("Expected input since method has arguments.")
+                )
+                .unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed { serializer: "JSON", error: Some(err.to_string()) }.panic());
+                let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                contract.transfer(amounts, );
+                near_sdk::env::state_write(&contract);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn promise_variant() {
+        let mut item_impl: ItemImpl = parse_quote! {
+            impl Hello {
+                #[promise_variant]
+                pub fn get(&self, mut k: u64) -> u64 { }
+            }
+        };
+        let item_impl_info = ItemImplInfo::new(&mut item_impl).unwrap();
+        let names: Vec<_> = item_impl_info.methods.iter().map(|m| m.attr_signature_info.method_name()).collect();
+        assert_eq!(names, vec!["get", "get_promise"]);
+        let expected: ItemImpl = parse_quote! {
+            impl Hello {
+                pub fn get(&self, mut k: u64) -> u64 { }
+                pub fn get_promise(&self, k: u64) -> near_sdk::PromiseOrValue<u64> {
+                    near_sdk::PromiseOrValue::Value(self.get(k))
+                }
+            }
+        };
+        assert_eq!(quote!(#item_impl).to_string(), quote!(#expected).to_string());
+        let actual = item_impl_info.methods[1].method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn get_promise() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                #[derive(near_sdk :: serde :: Deserialize)]
+                #[serde(crate = "near_sdk::serde")]
+                #[allow(clippy::all, unused)]
+                struct Input {
+                    k: u64,
+                }
+                let Input { k, }: Input = near_sdk::serde_json::from_slice(
+                    &near_sdk::env::input().expect("Expected input since method has arguments.")
+                )
+                .unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed { serializer: "JSON", error: Some(err.to_string()) }.panic());
+                let contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                match contract.get_promise(k, ) {
+                    near_sdk::PromiseOrValue::Value(result) => {
+                        let result = near_sdk::serde_json::to_vec(&result).expect("Failed to serialize the return value using JSON.");
+                        near_sdk::env::value_return(&result);
+                    }
+                    near_sdk::PromiseOrValue::Promise(promise) => {
+                        promise.as_return();
+                    }
+                }
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn promise_variant_errors() {
+        let mut item_impl: ItemImpl = parse_quote! {
+            impl Hello {
+                #[promise_variant]
+                pub fn set(&mut self, k: u64) { }
+            }
+        };
+        let error = ItemImplInfo::new(&mut item_impl).err().unwrap();
+        assert_eq!(error.to_string(), "`#[promise_variant]` requires the method to return a value.");
+        let mut item_impl: ItemImpl = parse_quote! {
+            impl Getter for Hello {
+                #[promise_variant]
+                fn get(&self) -> u64 { }
+            }
+        };
+        let error = ItemImplInfo::new(&mut item_impl).err().unwrap();
+        assert_eq!(error.to_string(), "`#[promise_variant]` is not supported in trait implementations.");
+    }
+
+    #[test]
+    fn max_storage() {
+        let mut item_impl: ItemImpl = syn::parse_str("impl Hello { pub fn a(&self) { } pub fn b(&mut self) { } }").unwrap();
+        let mut item_impl_info = ItemImplInfo::new(&mut item_impl).unwrap();
+        item_impl_info.attr = syn::parse_str("max_storage = 100000").unwrap();
+        let actual = item_impl_info.wrapper_code();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn a() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                let contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                contract.a();
+            }
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn b() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "b".to_string() }.panic();
+                }
+                let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                contract.b();
+                near_sdk::env::state_write(&contract);
+                near_sdk::assert_storage_usage(100000);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn args_struct() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = syn::parse_str("pub fn set_greeting(&mut self, message: String, r#type: &u8) { }").unwrap();
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.attr_signature_info.args_struct();
+        let expected = quote!(
+            #[doc = " Arguments of `set_greeting`."]
+            #[derive(near_sdk::serde::Serialize)]
+            #[serde(crate = "near_sdk::serde")]
+            #[allow(clippy::all, unused)]
+            pub struct SetGreetingArgs {
+                pub message: String,
+                pub r#type: u8,
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn args_struct_borsh() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = syn::parse_str("pub fn add(&mut self, #[serializer(borsh)] a: u64, #[serializer(borsh)] b: Vec<u8>) { }").unwrap();
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.attr_signature_info.args_struct();
+        let expected = quote!(
+            #[doc = " Arguments of `add`."]
+            #[derive(near_sdk::borsh::BorshSerialize)]
+            #[allow(clippy::all, unused)]
+            pub struct AddArgs {
+                pub a: u64,
+                pub b: Vec<u8>,
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn args_struct_from_input() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = syn::parse_str("pub fn add(&mut self, mut a: u64, #[default = 2] b: u64) { }").unwrap();
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.attr_signature_info.args_struct_from_input();
+        let expected = quote!(
+            #[allow(clippy::all, unused)]
+            impl AddArgs {
+                #[doc = " Deserializes the input of `add` like the method does."]
+                pub fn from_input(input: &[u8]) -> Result<Self, String> {
+                    #[derive(near_sdk :: serde :: Deserialize)]
+                    #[serde(crate = "near_sdk::serde")]
+                    #[allow(clippy::all, unused)]
+                    struct Input {
+                        a: u64,
+                        #[serde(default = "__default_b")]
+                        b: u64,
+                    }
+                    fn __default_b() -> u64 {
+                        2
+                    }
+                    let Input { mut a, b, }: Input = near_sdk::serde_json::from_slice(input).map_err(|err| err.to_string())?;
+                    Ok(Self { a, b, })
+                }
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn args_struct_in_wrapper_code() {
+        let mut item_impl: ItemImpl = syn::parse_str("impl Hello { pub fn a(&self, x: u8) { } pub fn b(&self) { } fn c(&self, y: u8) { } pub fn d(&self, #[serializer(json, single_value)] z: u8) { } }").unwrap();
+        let mut item_impl_info = ItemImplInfo::new(&mut item_impl).unwrap();
+        assert!(!item_impl_info.wrapper_code().to_string().contains("AArgs"));
+        item_impl_info.attr = syn::parse_str("args_structs").unwrap();
+        let actual = item_impl_info.wrapper_code().to_string();
+        assert!(actual.contains("pub struct AArgs"));
+        assert!(actual.contains("impl AArgs"));
+        assert!(!actual.contains("BArgs"));
+        assert!(!actual.contains("CArgs"));
+        assert!(!actual.contains("DArgs"));
+    }
+
+    #[test]
+    fn list_methods() {
+        let mut item_impl: ItemImpl = syn::parse_str("impl Hello { pub fn a(&self) { } fn helper(&self) { } pub fn b(&mut self) { } }").unwrap();
+        let mut item_impl_info = ItemImplInfo::new(&mut item_impl).unwrap();
+        item_impl_info.attr = syn::parse_str("list_methods").unwrap();
+        let actual = item_impl_info.methods_list();
+        let expected = quote!(
+            #[allow(clippy::all, unused)]
+            impl Hello {
+                /// Names of the methods exported by the contract.
+                pub fn __methods() -> Vec<String> {
+                    vec!["a".to_string(), "b".to_string()]
+                }
+            }
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn __methods() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                let result = near_sdk::serde_json::to_vec(&<Hello>::__methods())
+                    .expect("Failed to serialize the return value using JSON.");
+                near_sdk::env::value_return(&result);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+        let marker = quote!(
+            #[doc(hidden)]
+            #[macro_export]
+            macro_rules! __near_bindgen_only_one_impl_can_use_list_methods {
+                () => {};
+            }
+        );
+        assert!(item_impl_info.wrapper_code().to_string().starts_with(&marker.to_string()));
+    }
+
+    #[test]
+    fn list_methods_disabled() {
+        let mut item_impl: ItemImpl = syn::parse_str("impl Hello { pub fn a(&self) { } }").unwrap();
+        let item_impl_info = ItemImplInfo::new(&mut item_impl).unwrap();
+        assert!(!item_impl_info.wrapper_code().to_string().contains("__methods"));
+        assert!(syn::parse_str::<ImplAttr>("list_methods = a").is_err());
+        assert!(syn::parse_str::<ImplAttr>("list_methods, list_methods").is_err());
+    }
+
+    #[test]
+    fn debug_storage() {
+        let mut item_impl: ItemImpl = syn::parse_str("impl Hello { pub fn a(&self) { } }").unwrap();
+        let mut item_impl_info = ItemImplInfo::new(&mut item_impl).unwrap();
+        assert!(!item_impl_info.wrapper_code().to_string().contains("__debug_storage"));
+        item_impl_info.attr = syn::parse_str("debug_storage").unwrap();
+        let actual = item_impl_info.wrapper_code().to_string();
+        let expected = quote!(
+            #[cfg(any(feature = "debug", not(target_arch = "wasm32")))]
+            #[allow(clippy::all, unused)]
+            impl Hello {
+                /// The raw value stored under the key.
+                pub fn __debug_storage(
+                    key: near_sdk::json_types::Base64VecU8,
+                ) -> Option<near_sdk::json_types::Base64VecU8> {
+                    near_sdk::env::storage_read(&key.0).map(near_sdk::json_types::Base64VecU8)
+                }
+            }
+            #[cfg(feature = "debug")]
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn __debug_storage() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                #[derive(near_sdk::serde::Deserialize)]
+                #[serde(crate = "near_sdk::serde")]
+                struct Input {
+                    key: near_sdk::json_types::Base64VecU8,
+                }
+                let Input { key }: Input = near_sdk::serde_json::from_slice(
+                    &near_sdk::env::input().expect("Expected input since method has arguments.")
+                )
+                .unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed { serializer: "JSON", error: Some(err.to_string()) }.panic());
+                let result = near_sdk::serde_json::to_vec(&<Hello>::__debug_storage(key))
+                    .expect("Failed to serialize the return value using JSON.");
+                near_sdk::env::value_return(&result);
+            }
+        );
+        assert!(actual.ends_with(&expected.to_string()));
+        let marker = quote!(
+            #[doc(hidden)]
+            #[macro_export]
+            macro_rules! __near_bindgen_only_one_impl_can_use_debug_storage {
+                () => {};
+            }
+        );
+        assert!(actual.starts_with(&marker.to_string()));
+        assert!(syn::parse_str::<ImplAttr>("debug_storage, debug_storage").is_err());
+    }
+
+    #[test]
+    fn pausable() {
+        let mut item_impl: ItemImpl = syn::parse_str("impl Hello { pub fn get(&self) { } pub fn set(&mut self) { } #[init] pub fn new() -> Self { } #[private] pub fn on_set(&mut self) { } pub fn on_get(&mut self, #[callback] value: u64) { } }").unwrap();
+        let mut item_impl_info = ItemImplInfo::new(&mut item_impl).unwrap();
+        item_impl_info.attr = syn::parse_str("pausable").unwrap();
+        let get = item_impl_info.methods[0].method_wrapper_with_attr(&item_impl_info.attr).to_string();
+        let set = item_impl_info.methods[1].method_wrapper_with_attr(&item_impl_info.attr).to_string();
+        let new = item_impl_info.methods[2].method_wrapper_with_attr(&item_impl_info.attr).to_string();
+        let on_set = item_impl_info.methods[3].method_wrapper_with_attr(&item_impl_info.attr).to_string();
+        let on_get = item_impl_info.methods[4].method_wrapper_with_attr(&item_impl_info.attr).to_string();
+        let check = quote!(near_sdk::assert_not_paused();).to_string();
+        assert!(!get.contains(&check));
+        assert!(set.contains(&check));
+        assert!(!new.contains(&check));
+        // The callbacks run while the contract is paused.
+        assert!(!on_set.contains(&check));
+        assert!(!on_get.contains(&check));
+
+        let actual = item_impl_info.wrapper_code().to_string();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn pause() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                if near_sdk::env::current_account_id() != near_sdk::env::predecessor_account_id() {
+                    near_sdk::env::panic("Method pause is private".as_bytes());
+                }
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "pause".to_string() }.panic();
+                }
+                near_sdk::set_paused(true);
+            }
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn unpause() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                if near_sdk::env::current_account_id() != near_sdk::env::predecessor_account_id() {
+                    near_sdk::env::panic("Method unpause is private".as_bytes());
+                }
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "unpause".to_string() }.panic();
+                }
+                near_sdk::set_paused(false);
+            }
+        );
+        assert!(actual.ends_with(&expected.to_string()));
+        let marker = quote!(
+            #[doc(hidden)]
+            #[macro_export]
+            macro_rules! __near_bindgen_only_one_impl_can_use_pausable {
+                () => {};
+            }
+        );
+        assert!(actual.starts_with(&marker.to_string()));
+        assert!(syn::parse_str::<ImplAttr>("pausable = a").is_err());
+    }
+
+    #[test]
+    fn ownable() {
+        let mut item_impl: ItemImpl = syn::parse_str("impl Hello { pub fn set(&mut self) { } #[init] pub fn new() -> Self { } }").unwrap();
+        let mut item_impl_info = ItemImplInfo::new(&mut item_impl).unwrap();
+        item_impl_info.attr = syn::parse_str("ownable").unwrap();
+        let set = item_impl_info.methods[0].method_wrapper_with_attr(&item_impl_info.attr).to_string();
+        let new = item_impl_info.methods[1].method_wrapper_with_attr(&item_impl_info.attr).to_string();
+        let init = quote!(
+            near_sdk::set_owner_id(Some(near_sdk::env::predecessor_account_id().as_ref()));
+            let contract = Hello::new();
+        ).to_string();
+        assert!(!set.contains("set_owner_id"));
+        assert!(new.contains(&init));
+
+        let actual = item_impl_info.wrapper_code().to_string();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn owner() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                let result = near_sdk::serde_json::to_vec(&near_sdk::owner_id())
+                    .expect("Failed to serialize the return value using JSON.");
+                near_sdk::env::value_return(&result);
+            }
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn transfer_ownership() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "transfer_ownership".to_string() }.panic();
+                }
+                #[derive(near_sdk::serde::Deserialize)]
+                #[serde(crate = "near_sdk::serde")]
+                struct Input {
+                    new_owner_id: near_sdk::json_types::ValidAccountId,
+                }
+                let Input { new_owner_id }: Input = near_sdk::serde_json::from_slice(
+                    &near_sdk::env::input().expect("Expected input since method has arguments.")
+                )
+                .unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed { serializer: "JSON", error: Some(err.to_string()) }.panic());
+                near_sdk::transfer_ownership(new_owner_id.as_ref());
+            }
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn renounce_ownership() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "renounce_ownership".to_string() }.panic();
+                }
+                near_sdk::renounce_ownership();
+            }
+        );
+        assert!(actual.ends_with(&expected.to_string()));
+        let marker = quote!(
+            #[doc(hidden)]
+            #[macro_export]
+            macro_rules! __near_bindgen_only_one_impl_can_use_ownable {
+                () => {};
+            }
+        );
+        assert!(actual.starts_with(&marker.to_string()));
+        assert!(syn::parse_str::<ImplAttr>("ownable, ownable").is_err());
+    }
+
+    #[test]
+    fn verifying_key() {
+        let mut item_impl: ItemImpl = syn::parse_str("impl Hello { pub fn set(&mut self) { } }").unwrap();
+        let mut item_impl_info = ItemImplInfo::new(&mut item_impl).unwrap();
+        item_impl_info.attr = syn::parse_str("ownable, verifying_key").unwrap();
+        let actual = item_impl_info.wrapper_code().to_string();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn set_verifying_key() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "set_verifying_key".to_string() }.panic();
+                }
+                near_sdk::groth16::update_verifying_key(
+                    &near_sdk::env::input().expect("Expected input since method has arguments.")
+                );
+            }
+        );
+        assert!(actual.ends_with(&expected.to_string()));
+        assert!(actual.contains("transfer_ownership"));
+        assert!(syn::parse_str::<ImplAttr>("verifying_key").is_err());
+        assert!(syn::parse_str::<ImplAttr>("ownable, verifying_key, verifying_key").is_err());
+    }
+
+    #[test]
+    fn state_root() {
+        let mut item_impl: ItemImpl = syn::parse_str("impl Hello { pub fn set(&mut self) { } }").unwrap();
+        let mut item_impl_info = ItemImplInfo::new(&mut item_impl).unwrap();
+        item_impl_info.attr = syn::parse_str("state_root = commitments").unwrap();
+        let actual = item_impl_info.wrapper_code().to_string();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn state_root() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                let contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                let result = near_sdk::serde_json::to_vec(&contract.commitments.state_root())
+                    .expect("Failed to serialize the return value using JSON.");
+                near_sdk::env::value_return(&result);
+            }
+        );
+        assert!(actual.ends_with(&expected.to_string()));
+        let marker = quote!(
+            #[doc(hidden)]
+            #[macro_export]
+            macro_rules! __near_bindgen_only_one_impl_can_use_state_root {
+                () => {};
+            }
+        );
+        assert!(actual.starts_with(&marker.to_string()));
+        assert!(syn::parse_str::<ImplAttr>("state_root = pool::commitments").is_err());
+        assert!(syn::parse_str::<ImplAttr>("state_root").is_err());
+        assert!(syn::parse_str::<ImplAttr>("state_root = a, state_root = b").is_err());
+    }
+
+    #[test]
+    fn ownable_pausable() {
+        let mut item_impl: ItemImpl = syn::parse_str("impl Hello { pub fn set(&mut self) { } }").unwrap();
+        let mut item_impl_info = ItemImplInfo::new(&mut item_impl).unwrap();
+        item_impl_info.attr = syn::parse_str("pausable, ownable").unwrap();
+        let set = item_impl_info.methods[0].method_wrapper_with_attr(&item_impl_info.attr).to_string();
+        assert!(set.contains(&quote!(near_sdk::assert_not_paused();).to_string()));
+        let actual = item_impl_info.wrapper_code().to_string();
+        let pause = quote!(
+            pub extern "C" fn pause() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                if near_sdk::env::current_account_id() != near_sdk::env::predecessor_account_id() {
+                    near_sdk::assert_owner();
+                }
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "pause".to_string() }.panic();
+                }
+                near_sdk::set_paused(true);
+            }
+        );
+        assert!(actual.contains(&pause.to_string()));
+        assert!(actual.contains("transfer_ownership"));
+    }
+
+    #[test]
+    fn non_reentrant() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = syn::parse_str("#[non_reentrant] pub fn withdraw(&mut self) -> u64 { }").unwrap();
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn withdraw() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "withdraw".to_string() }.panic();
+                }
+                near_sdk::enter_non_reentrant("withdraw");
+                let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                let result = contract.withdraw();
+                let result = near_sdk::serde_json::to_vec(&result).expect("Failed to serialize the return value using JSON.");
+                near_sdk::env::value_return(&result);
+                near_sdk::env::state_write(&contract);
+                near_sdk::exit_non_reentrant();
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn non_reentrant_promise() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = syn::parse_str("#[non_reentrant] pub fn withdraw(&mut self) -> Promise { }").unwrap();
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn withdraw() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "withdraw".to_string() }.panic();
+                }
+                near_sdk::enter_non_reentrant("withdraw");
+                let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                let result = contract.withdraw();
+                near_sdk::exit_non_reentrant_after(&result, "__exit_non_reentrant_withdraw");
+                let result = near_sdk::serde_json::to_vec(&result).expect("Failed to serialize the return value using JSON.");
+                near_sdk::env::value_return(&result);
+                near_sdk::env::state_write(&contract);
+            }
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn __exit_non_reentrant_withdraw() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                near_sdk::exit_non_reentrant_callback();
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn non_reentrant_promise_export_name() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = syn::parse_str("#[cfg(feature = \"withdrawals\")] #[non_reentrant] #[method_name(\"withdrawAll\")] pub fn withdraw(&mut self) -> Promise { }").unwrap();
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper().to_string();
+        let schedule = quote!(
+            near_sdk::exit_non_reentrant_after(&result, "__exit_non_reentrant_withdrawAll");
+        );
+        assert!(actual.contains(&schedule.to_string()));
+        let expected = quote!(
+            #[cfg(feature = "withdrawals")]
+            #[cfg(target_arch = "wasm32")]
+            #[export_name = "__exit_non_reentrant_withdrawAll"]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn __exit_non_reentrant_withdraw() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                near_sdk::exit_non_reentrant_callback();
+            }
+        );
+        assert!(actual.ends_with(&expected.to_string()));
+    }
+
+    #[test]
+    fn reset_non_reentrant() {
+        let mut item_impl: ItemImpl = syn::parse_str("impl Hello { #[non_reentrant] pub fn withdraw(&mut self) -> Promise { } }").unwrap();
+        let mut item_impl_info = ItemImplInfo::new(&mut item_impl).unwrap();
+        item_impl_info.attr = syn::parse_str("reset_non_reentrant").unwrap();
+        let actual = item_impl_info.wrapper_code().to_string();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn reset_non_reentrant() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                if near_sdk::env::current_account_id() != near_sdk::env::predecessor_account_id() {
+                    near_sdk::env::panic("Method reset_non_reentrant is private".as_bytes());
+                }
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "reset_non_reentrant".to_string() }.panic();
+                }
+                near_sdk::exit_non_reentrant();
+            }
+        );
+        assert!(actual.ends_with(&expected.to_string()));
+        let marker = quote!(
+            #[doc(hidden)]
+            #[macro_export]
+            macro_rules! __near_bindgen_only_one_impl_can_use_reset_non_reentrant {
+                () => {};
+            }
+        );
+        assert!(actual.starts_with(&marker.to_string()));
+        item_impl_info.attr = syn::parse_str("ownable, reset_non_reentrant").unwrap();
+        let actual = item_impl_info.wrapper_code().to_string();
+        let owner_check = quote!(
+            if near_sdk::env::current_account_id() != near_sdk::env::predecessor_account_id() {
+                near_sdk::assert_owner();
+            }
+            if near_sdk::env::attached_deposit() != 0 {
+                near_sdk::SdkError::DepositNotAccepted { method_name: "reset_non_reentrant".to_string() }.panic();
+            }
+            near_sdk::exit_non_reentrant();
+        );
+        assert!(actual.contains(&owner_check.to_string()));
+        assert!(syn::parse_str::<ImplAttr>("reset_non_reentrant = a").is_err());
+    }
+
+    #[test]
+    fn single_value_u64() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn method(&mut self, #[serializer(json, single_value)] value: u64) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "method".to_string() }.panic();
+                }
+                let value: u64 = near_sdk::serde_json::from_slice(
+                    &near_sdk::env::input().expect("Expected input since method has arguments.")
+                )
+                .unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed { serializer: "JSON", error: Some(err.to_string()) }.panic());
+                let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                contract.method(value, );
+                near_sdk::env::state_write(&contract);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn single_value_string() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn method(&mut self, #[serializer(json, single_value)] value: String) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "method".to_string() }.panic();
+                }
+                let value: String = near_sdk::serde_json::from_slice(
+                    &near_sdk::env::input().expect("Expected input since method has arguments.")
+                )
+                .unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed { serializer: "JSON", error: Some(err.to_string()) }.panic());
+                let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                contract.method(value, );
+                near_sdk::env::state_write(&contract);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn single_value_multiple_args() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn method(&mut self, #[serializer(json, single_value)] value: u64, other: u64) { }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type).is_err());
+    }
+
+    #[test]
+    fn args_struct_input() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn method(&mut self, #[args] req: TransferRequest) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "method".to_string() }.panic();
+                }
+                let req: TransferRequest = near_sdk::serde_json::from_slice(
+                    &near_sdk::env::input().expect("Expected input since method has arguments.")
+                )
+                .unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed { serializer: "JSON", error: Some(err.to_string()) }.panic());
+                let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                contract.method(req, );
+                near_sdk::env::state_write(&contract);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn positional_json_input() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn add(&mut self, a: u64, b: u64) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        // The derived `Deserialize` of the struct also accepts a JSON array of the fields in their
+        // order, so the input can be `[1, 2]` as well as `{"a": 1, "b": 2}`.
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn add() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "add".to_string() }.panic();
+                }
+                #[derive(near_sdk :: serde :: Deserialize)]
+                #[serde(crate = "near_sdk::serde")]
+                #[allow(clippy::all, unused)]
+                struct Input {
+                    a: u64,
+                    b: u64,
+                }
+                let Input { a, b, }: Input = near_sdk::serde_json::from_slice(
+                    &near_sdk::env::input().expect("Expected input since method has arguments.")
+                )
+                .unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed { serializer: "JSON", error: Some(err.to_string()) }.panic());
+                let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                contract.add(a, b, );
+                near_sdk::env::state_write(&contract);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn integer_map_keys_input() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn set_names(&mut self, names: HashMap<u64, String>) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        // serde_json parses the string keys of a JSON object into the integer type, so the field
+        // needs no adapter, unlike the byte arrays.
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn set_names() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "set_names".to_string() }.panic();
+                }
+                #[derive(near_sdk :: serde :: Deserialize)]
+                #[serde(crate = "near_sdk::serde")]
+                #[allow(clippy::all, unused)]
+                struct Input {
+                    names: HashMap<u64, String>,
+                }
+                let Input { names, }: Input = near_sdk::serde_json::from_slice(
+                    &near_sdk::env::input().expect("Expected input since method has arguments.")
+                )
+                .unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed { serializer: "JSON", error: Some(err.to_string()) }.panic());
+                let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                contract.set_names(names, );
+                near_sdk::env::state_write(&contract);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn query_input() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn balance(&self, #[serializer(query)] to: String, #[serializer(query)] amount: Option<u64>) -> u64 { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn balance() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                #[derive(near_sdk :: serde :: Deserialize)]
+                #[serde(crate = "near_sdk::serde")]
+                #[allow(clippy::all, unused)]
+                struct Input {
+                    to: String,
+                    amount: Option<u64>,
+                }
+                let Input { to, amount, }: Input = near_sdk::serde_urlencoded::from_bytes(
+                    &near_sdk::env::input().expect("Expected input since method has arguments.")
+                )
+                .unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed { serializer: "the query string", error: Some(err.to_string()) }.panic());
+                let contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                let result = contract.balance(to, amount, );
+                let result = near_sdk::serde_json::to_vec(&result).expect("Failed to serialize the return value using JSON.");
+                near_sdk::env::value_return(&result);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn query_input_errors() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        for method in &[
+            "pub fn method(&self, #[serializer(query)] to: String, #[serializer(query)] amounts: Vec<u64>) { }",
+            "pub fn method(&self, #[serializer(query)] amount: Option<(u64, u64)>) { }",
+        ] {
+            let mut method: ImplItemMethod = syn::parse_str(method).unwrap();
+            let error = ImplItemMethodInfo::new(&mut method, impl_type.clone()).err().unwrap();
+            assert_eq!(
+                error.to_string(),
+                "`query` serializer only supports arguments of scalar types, like integers, strings and booleans."
+            );
+        }
+        let mut method: ImplItemMethod = syn::parse_str(
+            "pub fn method(&self, #[serializer(query)] to: String, amount: u64) { }",
+        ).unwrap();
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type.clone()).is_err());
+        let mut method: ImplItemMethod = syn::parse_str(
+            "#[result_serializer(query)] pub fn method(&self) -> u64 { }",
+        ).unwrap();
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type).is_err());
+    }
+
+    #[test]
+    fn args_tagged_enum_input() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn execute(&mut self, #[args] command: Command) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        // No `Input` struct wraps the enum, so its own serde attributes apply to the whole input.
+        assert!(!actual.to_string().contains("struct Input"));
+        assert!(actual.to_string().contains(&quote!(let command: Command = near_sdk::serde_json::from_slice).to_string()));
+    }
+
+    #[test]
+    fn args_struct_input_with_other_args() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn method(&mut self, #[args] req: TransferRequest, memo: String) { }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type).is_err());
+    }
+
+    #[test]
+    fn default_arg() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn method(&self, from: u64, #[default = 10] limit: u64) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                #[derive(near_sdk :: serde :: Deserialize)]
+                #[serde(crate = "near_sdk::serde")]
+                #[allow(clippy::all, unused)]
+                struct Input {
+                    from: u64,
+                    #[serde(default = "__default_limit")]
+                    limit: u64,
+                }
+                fn __default_limit() -> u64 {
+                    10
+                }
+                let Input { from, limit, }: Input = near_sdk::serde_json::from_slice(
+                    &near_sdk::env::input().expect("Expected input since method has arguments.")
+                )
+                .unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed { serializer: "JSON", error: Some(err.to_string()) }.panic());
+                let contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                contract.method(from, limit, );
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn default_arg_borsh() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn method(&self, #[serializer(borsh)] #[default = 10] limit: u64) { }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type).is_err());
+    }
+
+    #[test]
+    fn log_deprecated() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[deprecated(note = "use transfer_v2")]
+            #[log_deprecated]
+            pub fn transfer(&mut self) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            #[allow(deprecated)]
+            pub extern "C" fn transfer() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                near_sdk::env::log_str("Method transfer is deprecated: use transfer_v2");
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "transfer".to_string() }.panic();
+                }
+                let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                contract.transfer();
+                near_sdk::env::state_write(&contract);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+        // Only `#[deprecated]` is left on the method.
+        let attrs = &method.attrs;
+        assert_eq!(quote!(#(#attrs)*).to_string(), quote!(#[deprecated(note = "use transfer_v2")]).to_string());
+    }
+
+    #[test]
+    fn log_deprecated_suppressed() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[deprecated]
+            pub fn transfer(&mut self) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type.clone()).unwrap();
+        assert!(!method_info.method_wrapper().to_string().contains("log_str"));
+
+        let mut method: ImplItemMethod = parse_quote! {
+            #[log_deprecated]
+            pub fn transfer(&mut self) { }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type).is_err());
+    }
+
+    #[test]
+    fn serde_with_arg() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn method(&mut self, #[serde(with = "iso_timestamp")] start: u64) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                #[derive(near_sdk :: serde :: Deserialize)]
+                #[serde(crate = "near_sdk::serde")]
+                #[allow(clippy::all, unused)]
+                struct Input {
+                    #[serde(with = "iso_timestamp")]
+                    start: u64,
+                }
+                let Input { start, }: Input = near_sdk::serde_json::from_slice(
+                    &near_sdk::env::input().expect("Expected input since method has arguments.")
+                )
+                .unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed { serializer: "JSON", error: Some(err.to_string()) }.panic());
+                let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                contract.method(start, );
+                near_sdk::env::state_write(&contract);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+        assert!(method_info.attr_signature_info.args_struct().to_string().contains(
+            &quote!(#[serde(with = "iso_timestamp")] pub start: u64,).to_string()
+        ));
+        // The attribute is removed from the method argument.
+        assert!(!quote!(#method).to_string().contains("iso_timestamp"));
+    }
+
+    #[test]
+    fn serde_with_arg_errors() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn method(&mut self, #[serializer(borsh)] #[serde(with = "iso_timestamp")] start: u64) { }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type.clone()).is_err());
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn method(&mut self, #[callback] #[serde(with = "iso_timestamp")] start: u64) { }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type).is_err());
+    }
+
+    #[test]
+    fn auto_by_caller_args() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn method(&mut self, #[serializer(auto_by_caller)] k: u64, #[serializer(auto_by_caller)] m: Bar) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "method".to_string() }.panic();
+                }
+                #[derive(near_sdk::serde::Deserialize, near_sdk::borsh::BorshDeserialize)]
+                #[serde(crate = "near_sdk::serde")]
+                #[allow(clippy::all, unused)]
+                struct Input {
+                    k: u64,
+                    m: Bar,
+                }
+                let Input { k, m, }: Input = near_sdk::deserialize_by_caller(
+                    &near_sdk::env::input().expect("Expected input since method has arguments.")
+                )
+                .unwrap_or_else(|| near_sdk::SdkError::DeserializationFailed { serializer: "JSON or Borsh", error: None }.panic());
+                let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                contract.method(k, m, );
+                near_sdk::env::state_write(&contract);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn auto_by_caller_errors() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn method(&mut self, #[serializer(auto_by_caller)] k: u64, m: Bar) { }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type.clone()).is_err());
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn method(&mut self, #[callback] #[serializer(auto_by_caller)] x: u64) { }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type.clone()).is_err());
+        let mut method: ImplItemMethod = parse_quote! {
+            #[result_serializer(auto_by_caller)]
+            pub fn method(&self) -> u64 { 1 }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type).is_err());
+    }
+
+    #[test]
+    fn serde_rename_collision() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn method(&mut self, #[serde(rename = "amount")] value: u64, amount: u64) { }
+        };
+        let err = ImplItemMethodInfo::new(&mut method, impl_type.clone()).err().unwrap();
+        assert_eq!(err.to_string(), "Arguments `value` and `amount` are both deserialized from the JSON key `amount`.");
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn method(&mut self, #[serde(rename(deserialize = "x"))] a: u64, #[serde(rename = "x")] b: u64) { }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type.clone()).is_err());
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn method(&mut self, #[serde(rename = "b")] a: u64, #[serde(rename = "a")] b: u64) { }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type).is_ok());
+    }
+
+    #[test]
+    fn expires_at_arg() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn method(&mut self, amount: u64, #[expires_at] valid_until: u64) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "method".to_string() }.panic();
+                }
+                #[derive(near_sdk :: serde :: Deserialize)]
+                #[serde(crate = "near_sdk::serde")]
+                #[allow(clippy::all, unused)]
+                struct Input {
+                    amount: u64,
+                    valid_until: u64,
+                }
+                let Input { amount, valid_until, }: Input = near_sdk::serde_json::from_slice(
+                    &near_sdk::env::input().expect("Expected input since method has arguments.")
+                )
+                .unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed { serializer: "JSON", error: Some(err.to_string()) }.panic());
+                near_sdk::assert_not_expired(valid_until);
+                let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                contract.method(amount, valid_until, );
+                near_sdk::env::state_write(&contract);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn decrypt_arg() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn method(&mut self, #[decrypt(with = "crypto::decrypt")] note: Note, amount: u64) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        assert!(!method_info.attr_signature_info.has_bounded_input());
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "method".to_string() }.panic();
+                }
+                #[derive(near_sdk :: serde :: Deserialize)]
+                #[serde(crate = "near_sdk::serde")]
+                #[allow(clippy::all, unused)]
+                struct Input {
+                    note: near_sdk::json_types::Base64VecU8,
+                    amount: u64,
+                }
+                let Input { note, amount, }: Input = near_sdk::serde_json::from_slice(
+                    &near_sdk::env::input().expect("Expected input since method has arguments.")
+                )
+                .unwrap_or_else(|err| near_sdk::SdkError::DeserializationFailed { serializer: "JSON", error: Some(err.to_string()) }.panic());
+                let note: Note = match crypto::decrypt(note.into()) {
+                    Ok(value) => value,
+                    Err(err) => near_sdk::env::panic(format!("{}: {}", "Failed to decrypt argument `note`", err).as_bytes()),
+                };
+                let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                contract.method(note, amount, );
+                near_sdk::env::state_write(&contract);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn decrypt_borsh_arg() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn method(&self, #[serializer(borsh)] #[decrypt(with = "decrypt")] note: Note) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.attr_signature_info.input_struct(InputStructType::Deserialization);
+        let expected = quote!(
+            #[derive(near_sdk :: borsh :: BorshDeserialize)]
+            #[allow(clippy::all, unused)]
+            struct Input {
+                note: Vec<u8>,
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn decrypt_unsupported() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let methods: Vec<ImplItemMethod> = vec![
+            parse_quote! {
+                pub fn method(&self, #[callback] #[decrypt(with = "decrypt")] note: Note) { }
+            },
+            parse_quote! {
+                pub fn method(&self, #[serializer(query)] #[decrypt(with = "decrypt")] note: u64) { }
+            },
+            parse_quote! {
+                pub fn method(&self, #[args] #[decrypt(with = "decrypt")] note: Note) { }
+            },
+            parse_quote! {
+                pub fn method(&self, #[decrypt(using = "decrypt")] note: Note) { }
+            },
+        ];
+        for mut method in methods {
+            assert!(ImplItemMethodInfo::new(&mut method, impl_type.clone()).is_err());
+        }
+    }
+
+    #[test]
+    fn expires_at_unsupported() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn method(&self, #[expires_at] valid_until: String) { }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type.clone()).is_err());
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn method(&self, #[callback] #[expires_at] valid_until: u64) { }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type).is_err());
+    }
+
+    #[test]
+    fn only_accounts() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[only("admin.near", self)] #[only("owner.near")] pub fn method(&mut self) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.method_wrapper();
+        let expected = quote!(
+            #[cfg(target_arch = "wasm32")]
+            #[no_mangle]
+            #[allow(clippy::all, unused)]
+            pub extern "C" fn method() {
+                near_sdk::env::setup_panic_hook();
+                near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+                near_sdk::assert_only(&[
+                    "admin.near",
+                    near_sdk::env::current_account_id().as_ref().as_str(),
+                    "owner.near"
+                ]);
+                if near_sdk::env::attached_deposit() != 0 {
+                    near_sdk::SdkError::DepositNotAccepted { method_name: "method".to_string() }.panic();
+                }
+                let mut contract: Hello = near_sdk::env::state_read().unwrap_or_default();
+                contract.method();
+                near_sdk::env::state_write(&contract);
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn only_accounts_invalid() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[only()] pub fn method(&mut self) { }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type.clone()).is_err());
+        let mut method: ImplItemMethod = parse_quote! {
+            #[only(admin)] pub fn method(&mut self) { }
+        };
+        assert!(ImplItemMethodInfo::new(&mut method, impl_type).is_err());
+    }
+
     #[test]
     fn marshall_one_arg() {
         let impl_type: Type = syn::parse_str("Hello").unwrap();
@@ -1,3 +1,4 @@
+use crate::StructAttr;
 use syn::ItemStruct;
 
 #[cfg(not(target_arch = "wasm"))]
@@ -6,7 +7,9 @@ pub fn generate_proxy_struct(input: &ItemStruct) -> proc_macro2::TokenStream {
     let ident = &input.ident;
     let new_name = format_ident!("{}Contract", ident);
     let name = quote! {#new_name};
+    let allow_lints = crate::code_generator::allow_lints();
     quote! {
+         #allow_lints
          pub struct #name {
             pub account_id: near_sdk::AccountId,
           }
@@ -17,3 +20,58 @@ pub fn generate_proxy_struct(input: &ItemStruct) -> proc_macro2::TokenStream {
 pub fn generate_proxy_struct(input: &ItemStruct) {
     quote! {}
 }
+
+/// Generates `__reset_state` that removes the contract state from the storage, so that the unit
+/// tests sharing the mocked blockchain start from a clean state. Only available in tests.
+pub fn generate_reset_state(input: &ItemStruct) -> proc_macro2::TokenStream {
+    use quote::quote;
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let allow_lints = crate::code_generator::allow_lints();
+    quote! {
+        #[cfg(test)]
+        #allow_lints
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Removes the contract state from the storage.
+            pub fn __reset_state() {
+                near_sdk::env::state_remove();
+            }
+        }
+    }
+}
+
+/// Generates `__state_layout_hash` and exports it as the `state_layout_hash` view method if the
+/// struct is decorated with `#[near_bindgen(state_layout_hash)]`. The hash changes whenever the
+/// Borsh layout of the state changes, so an upgrade can be checked against the deployed contract.
+pub fn generate_state_layout_hash(
+    input: &ItemStruct,
+    attr: &StructAttr,
+) -> proc_macro2::TokenStream {
+    use quote::quote;
+    if !attr.state_layout_hash {
+        return quote! {};
+    }
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let allow_lints = crate::code_generator::allow_lints();
+    quote! {
+        #allow_lints
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// The sha256 hash of the Borsh schema of the contract state.
+            pub fn __state_layout_hash() -> Vec<u8> {
+                near_sdk::state_layout_hash::<Self>()
+            }
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        #[no_mangle]
+        #allow_lints
+        pub extern "C" fn state_layout_hash() {
+            near_sdk::env::setup_panic_hook();
+            near_sdk::env::set_blockchain_interface(Box::new(near_blockchain::NearBlockchain {}));
+            let result = near_sdk::json_types::Base64VecU8::from(#ident::__state_layout_hash());
+            let result = near_sdk::serde_json::to_vec(&result).expect("Failed to serialize the return value using JSON.");
+            near_sdk::env::value_return(&result);
+        }
+    }
+}
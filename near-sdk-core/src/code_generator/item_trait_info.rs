@@ -63,6 +63,7 @@ mod tests {
                 ) -> near_sdk::Promise {
                     #[derive(near_sdk :: serde :: Serialize)]
                     #[serde(crate = "near_sdk::serde")]
+                    #[allow(clippy::all, unused)]
                     struct Input {
                         arr: Vec<u8>,
                     }
@@ -89,4 +90,36 @@ mod tests {
         };
         assert_eq!(actual.to_string(), expected.to_string());
     }
+
+    #[test]
+    fn method_name() {
+        let mut t: ItemTrait = syn::parse2(
+            quote!{
+                    pub trait FungibleToken {
+                        #[method_name("ftTransfer")]
+                        fn ft_transfer(&mut self);
+                    }
+            }
+        ).unwrap();
+        let info = ItemTraitInfo::new(&mut t, None).unwrap();
+        let actual = info.wrapped_module();
+
+        let expected = quote! {
+            pub mod fungible_token {
+                use super::*;
+                use near_sdk::{Gas, Balance, AccountId, Promise};
+                use std::string::ToString;
+                pub fn ft_transfer<T: ToString>(__account_id: &T, __balance: near_sdk::Balance, __gas: near_sdk::Gas) -> near_sdk::Promise {
+                    let args = vec![];
+                    near_sdk::Promise::new(__account_id.to_string()).function_call(
+                        b"ftTransfer".to_vec(),
+                        args,
+                        __balance,
+                        __gas,
+                    )
+                }
+            }
+        };
+        assert_eq!(actual.to_string(), expected.to_string());
+    }
 }
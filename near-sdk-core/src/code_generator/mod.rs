@@ -1,3 +1,6 @@
+use quote::quote;
+use syn::export::TokenStream2;
+
 mod attr_sig_info;
 pub use attr_sig_info::*;
 
@@ -15,3 +18,12 @@ pub use item_impl_info::*;
 
 mod item_struct_info;
 pub use item_struct_info::*;
+
+mod item_enum_info;
+pub use item_enum_info::*;
+
+/// Attribute that silences the lints in the generated functions and structs, which users can't edit.
+/// Otherwise crates with `#![deny(warnings)]` might fail to compile because of the generated code.
+pub(crate) fn allow_lints() -> TokenStream2 {
+    quote! { #[allow(clippy::all, unused)] }
+}
@@ -15,6 +15,13 @@ impl TraitItemMethodInfo {
             struct_decl = TokenStream2::new();
             constructor = TokenStream2::new();
             quote! {let args = vec![]; }
+        } else if let Some(arg) = self.attr_sig_info.single_value_arg() {
+            let ident = &arg.ident;
+            struct_decl = TokenStream2::new();
+            constructor = TokenStream2::new();
+            quote! {
+                let args = near_sdk::serde_json::to_vec(&#ident).expect("Failed to serialize the cross contract args using JSON.");
+            }
         } else {
             struct_decl = self.attr_sig_info.input_struct(InputStructType::Serialization);
             let constructor_call = self.attr_sig_info.constructor_expr();
@@ -26,6 +33,12 @@ impl TraitItemMethodInfo {
                 SerializerType::Borsh => quote! {
                     let args = let result = near_sdk::borsh::BorshSerialize::try_to_vec(&args).expect("Failed to serialize the cross contract args using Borsh.");
                 },
+                SerializerType::Query => quote! {
+                    let args = near_sdk::serde_urlencoded::to_string(&args).expect("Failed to serialize the cross contract args into a query string.").into_bytes();
+                },
+                SerializerType::Auto => unreachable!(
+                    "`auto_by_caller` arguments are rejected by `TraitItemMethodInfo::new`"
+                ),
             }
         };
         quote! {
@@ -2,7 +2,12 @@ use crate::info_extractor::serializer_attr::SerializerAttr;
 use crate::info_extractor::SerializerType;
 use quote::ToTokens;
 use syn::export::Span;
-use syn::{Attribute, Error, Ident, Pat, PatType, Token, Type};
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned;
+use syn::{
+    parenthesized, Attribute, Error, Expr, Ident, Lit, LitStr, Meta, NestedMeta, Pat, PatType,
+    Path, Token, Type,
+};
 
 pub enum BindgenArgType {
     /// Argument that we read from `env::input()`.
@@ -33,6 +38,24 @@ pub struct ArgInfo {
     pub bindgen_ty: BindgenArgType,
     /// Type of serializer that we use for this argument.
     pub serializer_ty: SerializerType,
+    /// Whether the whole input is deserialized into this argument, rather than into its field.
+    /// Specified as `#[serializer(json, single_value)]` or `#[args]`.
+    pub single_value: bool,
+    /// Whether the bytes left after the Borsh value are ignored rather than rejected, specified as
+    /// `#[serializer(borsh, allow_trailing)]`.
+    pub allow_trailing: bool,
+    /// `#[serde(...)]` attributes, e.g. `#[serde(with = "module")]`, that are put on the field of the
+    /// argument in the input struct.
+    pub serde_attrs: Vec<Attribute>,
+    /// The value of the argument if it is omitted in the input, specified as `#[default = expr]`.
+    pub default: Option<Expr>,
+    /// Whether the argument is the block height after which the call is rejected,
+    /// specified as `#[expires_at]`.
+    pub expires_at: bool,
+    /// Function that turns the ciphertext in the input into the argument, specified as
+    /// `#[decrypt(with = "path")]`. It has the signature `fn(Vec<u8>) -> Result<T, E>` where `T`
+    /// is the type of the argument and `E: Display`, and the method panics if it returns an error.
+    pub decrypt: Option<Path>,
     /// The original `PatType` of the argument.
     pub original: PatType,
 }
@@ -68,10 +91,20 @@ impl ArgInfo {
         let mut bindgen_ty = BindgenArgType::Regular;
         // In the absence of serialization attributes this is a JSON serialization.
         let mut serializer_ty = SerializerType::JSON;
+        let mut single_value = false;
+        let mut allow_trailing = false;
+        let mut default = None;
+        let mut serde_attrs = vec![];
+        let mut expires_at = false;
+        let mut decrypt = None;
         for attr in &mut original.attrs {
             let attr_str = attr.path.to_token_stream().to_string();
             match attr_str.as_str() {
                 "callback" => {
+                    if !attr.tokens.is_empty() {
+                        // The wrapper always reads the state at entry, so the flag is only checked.
+                        syn::parse2::<CallbackAttr>(attr.tokens.clone())?;
+                    }
                     bindgen_ty = BindgenArgType::CallbackArg;
                 }
                 "callback_vec" => {
@@ -79,7 +112,33 @@ impl ArgInfo {
                 }
                 "serializer" => {
                     let serializer: SerializerAttr = syn::parse2(attr.tokens.clone())?;
+                    if serializer.with.is_some() || serializer.sorted_keys || serializer.gzip {
+                        return Err(Error::new(
+                            Span::call_site(),
+                            "`with`, `sorted_keys` and `gzip` are only supported by \
+                             `result_serializer`.",
+                        ));
+                    }
                     serializer_ty = serializer.serializer_type;
+                    single_value |= serializer.single_value;
+                    allow_trailing |= serializer.allow_trailing;
+                }
+                "args" => {
+                    single_value = true;
+                }
+                "default" => {
+                    let DefaultAttr { expr } = syn::parse2(attr.tokens.clone())?;
+                    default = Some(expr);
+                }
+                "expires_at" => {
+                    expires_at = true;
+                }
+                "decrypt" => {
+                    let DecryptAttr { with } = syn::parse2(attr.tokens.clone())?;
+                    decrypt = Some(with);
+                }
+                "serde" => {
+                    serde_attrs.push((*attr).clone());
                 }
                 _ => {
                     non_bindgen_attrs.push((*attr).clone());
@@ -89,9 +148,93 @@ impl ArgInfo {
 
         original.attrs.retain(|attr| {
             let attr_str = attr.path.to_token_stream().to_string();
-            attr_str != "callback" && attr_str != "callback_vec" && attr_str != "serializer"
+            attr_str != "callback"
+                && attr_str != "callback_vec"
+                && attr_str != "serializer"
+                && attr_str != "args"
+                && attr_str != "default"
+                && attr_str != "expires_at"
+                && attr_str != "decrypt"
+                && attr_str != "serde"
         });
 
+        if let (SerializerType::Query, BindgenArgType::CallbackArg)
+        | (SerializerType::Query, BindgenArgType::CallbackArgVec) = (&serializer_ty, &bindgen_ty)
+        {
+            return Err(Error::new(
+                Span::call_site(),
+                "`query` serializer is only supported by regular arguments.",
+            ));
+        }
+        if let (SerializerType::Auto, BindgenArgType::CallbackArg)
+        | (SerializerType::Auto, BindgenArgType::CallbackArgVec) = (&serializer_ty, &bindgen_ty)
+        {
+            return Err(Error::new(
+                Span::call_site(),
+                "`auto_by_caller` serializer is only supported by regular arguments.",
+            ));
+        }
+
+        if default.is_some() {
+            match (&bindgen_ty, &serializer_ty) {
+                (BindgenArgType::Regular, SerializerType::JSON) => {}
+                _ => {
+                    return Err(Error::new(
+                        Span::call_site(),
+                        "`default` is only supported by regular arguments with JSON serializer.",
+                    ))
+                }
+            }
+        }
+
+        if let Some(attr) = serde_attrs.first() {
+            let is_field = match (&bindgen_ty, &serializer_ty) {
+                (BindgenArgType::Regular, SerializerType::JSON)
+                | (BindgenArgType::Regular, SerializerType::Query) => !single_value,
+                _ => false,
+            };
+            if !is_field {
+                return Err(Error::new(
+                    attr.span(),
+                    "`serde` attributes are only supported by regular arguments with JSON or query serializer.",
+                ));
+            }
+        }
+
+        if expires_at {
+            let is_u64 = match &ty {
+                Type::Path(path) => path.qself.is_none() && path.path.is_ident("u64"),
+                _ => false,
+            };
+            match (&bindgen_ty, is_u64) {
+                (BindgenArgType::Regular, true) => {}
+                _ => {
+                    return Err(Error::new(
+                        Span::call_site(),
+                        "`expires_at` is only supported by regular arguments of type `u64`.",
+                    ))
+                }
+            }
+        }
+
+        if decrypt.is_some() {
+            let is_supported = match (&bindgen_ty, &serializer_ty) {
+                (BindgenArgType::Regular, SerializerType::JSON)
+                | (BindgenArgType::Regular, SerializerType::Borsh)
+                | (BindgenArgType::Regular, SerializerType::Auto) => {
+                    !single_value && default.is_none() && !expires_at
+                }
+                _ => false,
+            };
+            if !is_supported {
+                return Err(Error::new(
+                    Span::call_site(),
+                    "`decrypt` is only supported by regular arguments with JSON, Borsh or \
+                     auto_by_caller serializer, without `single_value`, `default` or `expires_at`.",
+                ));
+            }
+        }
+
         Ok(Self {
             non_bindgen_attrs,
             ident,
@@ -102,7 +245,119 @@ impl ArgInfo {
             ty,
             bindgen_ty,
             serializer_ty,
+            single_value,
+            allow_trailing,
+            serde_attrs,
+            default,
+            expires_at,
+            decrypt,
             original: original.clone(),
         })
     }
+
+    /// The key of the argument in the JSON input, which is the name of the argument unless it is
+    /// changed with `#[serde(rename = "key")]` or `#[serde(rename(deserialize = "key"))]`.
+    pub fn json_name(&self) -> String {
+        let mut name = self.ident.to_string();
+        for attr in &self.serde_attrs {
+            let items = match attr.parse_meta() {
+                Ok(Meta::List(list)) => list.nested,
+                _ => continue,
+            };
+            for item in items {
+                match item {
+                    NestedMeta::Meta(Meta::NameValue(rename)) if rename.path.is_ident("rename") => {
+                        if let Lit::Str(lit) = rename.lit {
+                            name = lit.value();
+                        }
+                    }
+                    NestedMeta::Meta(Meta::List(rename)) if rename.path.is_ident("rename") => {
+                        for item in rename.nested {
+                            match item {
+                                NestedMeta::Meta(Meta::NameValue(value))
+                                    if value.path.is_ident("deserialize") =>
+                                {
+                                    if let Lit::Str(lit) = value.lit {
+                                        name = lit.value();
+                                    }
+                                }
+                                _ => {}
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        name
+    }
+
+    /// The type of the argument in the input. It is `TYPE` unless the argument is decrypted, in which
+    /// case the input holds the ciphertext as `Base64VecU8` under JSON and `Vec<u8>` under Borsh.
+    pub fn input_ty(&self) -> Type {
+        match (&self.decrypt, &self.serializer_ty) {
+            (None, _) => self.ty.clone(),
+            (Some(_), SerializerType::Borsh) => syn::parse_quote! { Vec<u8> },
+            (Some(_), _) => syn::parse_quote! { near_sdk::json_types::Base64VecU8 },
+        }
+    }
+
+    /// If the `TYPE` is a fixed-size byte array `[u8; N]` returns the length expression `N`.
+    pub fn byte_array_len(&self) -> Option<&Expr> {
+        match &self.ty {
+            Type::Array(array) => match array.elem.as_ref() {
+                Type::Path(path) if path.qself.is_none() && path.path.is_ident("u8") => {
+                    Some(&array.len)
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// The `= expr` part of the `#[default = expr]` attribute.
+struct DefaultAttr {
+    expr: Expr,
+}
+
+impl Parse for DefaultAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<Token![=]>()?;
+        Ok(Self { expr: input.parse()? })
+    }
+}
+
+/// The `(reload_state)` part of the `#[callback(reload_state)]` attribute.
+struct CallbackAttr;
+
+impl Parse for CallbackAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        parenthesized!(content in input);
+        let ident: Ident = content.parse()?;
+        if ident != "reload_state" || !content.is_empty() {
+            return Err(Error::new(ident.span(), "Expected `reload_state`."));
+        }
+        Ok(Self)
+    }
+}
+
+/// The `(with = "path")` part of the `#[decrypt(with = "path")]` attribute.
+struct DecryptAttr {
+    with: Path,
+}
+
+impl Parse for DecryptAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        parenthesized!(content in input);
+        let key: Ident = content.parse()?;
+        if key != "with" {
+            return Err(Error::new(key.span(), "Expected `with = \"path\"`."));
+        }
+        content.parse::<Token![=]>()?;
+        let path: LitStr = content.parse()?;
+        Ok(Self { with: path.parse()? })
+    }
 }
@@ -1,15 +1,24 @@
 use crate::info_extractor::arg_info::{ArgInfo, BindgenArgType};
+use crate::info_extractor::expect_args_attr::ExpectArgsAttr;
+use crate::info_extractor::only_attr::{AllowedAccount, OnlyAttr};
 use crate::info_extractor::serializer_attr::SerializerAttr;
 use crate::info_extractor::SerializerType;
 use quote::ToTokens;
 use syn::export::Span;
+use syn::parse::{Parse, ParseStream};
 use syn::spanned::Spanned;
-use syn::{Attribute, Error, FnArg, Ident, Receiver, ReturnType, Signature};
+use syn::{
+    parenthesized, Attribute, Error, FnArg, GenericArgument, Ident, Lit, LitInt, LitStr, Meta,
+    MetaNameValue, NestedMeta, Path, PathArguments, Receiver, ReturnType, Signature, Token, Type,
+};
 
 /// Information extracted from method attributes and signature.
 pub struct AttrSigInfo {
     /// The name of the method.
     pub ident: Ident,
+    /// The name of the method exported by the contract if it differs from `ident`,
+    /// specified as `#[method_name("ftTransfer")]`.
+    pub export_name: Option<LitStr>,
     /// Attributes not related to bindgen.
     pub non_bindgen_attrs: Vec<Attribute>,
     /// All arguments of the method.
@@ -18,14 +27,44 @@ pub struct AttrSigInfo {
     pub is_init: bool,
     /// Whether method accepting $NEAR.
     pub is_payable: bool,
+    /// Whether the method requires exactly 1 yoctoNEAR attached as a confirmation with a full
+    /// access key, specified with `#[assert_one_yocto]`.
+    pub is_assert_one_yocto: bool,
     /// Whether method can accept calls from self (current account)
     pub is_private: bool,
+    /// Accounts allowed to call the method, specified with `#[only(...)]`. Anyone can call it if empty.
+    pub only_accounts: Vec<AllowedAccount>,
+    /// Whether the deserialized input should be logged as JSON before the method is called.
+    pub is_log_input: bool,
+    /// Whether the method panics if it is entered while another non-reentrant method executes.
+    pub is_non_reentrant: bool,
+    /// The maximum length of `env::input()` in bytes, specified as `#[max_input_len = N]`.
+    pub max_input_len: Option<LitInt>,
+    /// The minimum prepaid gas of the method, specified in TGas as `#[min_gas(tgas = N)]`.
+    pub min_gas: Option<u64>,
     /// The serializer that we use for `env::input()`.
     pub input_serializer: SerializerType,
     /// Whether the method doesn't mutate state
     pub is_view: bool,
     /// The serializer that we use for the return type.
     pub result_serializer: SerializerType,
+    /// Module with a custom `serialize` function for the return value, like serde's `with`.
+    pub result_serializer_with: Option<Path>,
+    /// Whether the keys of JSON objects in the return value are serialized in sorted order.
+    pub result_serializer_sorted_keys: bool,
+    /// Whether the serialized JSON of the result is compressed with gzip.
+    pub result_serializer_gzip: bool,
+    /// Whether the returned `Vec<u8>` is passed to `env::value_return` as is, specified as
+    /// `#[result_serializer(raw)]`.
+    pub result_serializer_raw: bool,
+    /// Whether the method returns `Result<T, E>` whose error is reported with `near_sdk::panic_code`,
+    /// specified with `#[handle_result]`.
+    pub is_handle_result: bool,
+    /// The note of `#[deprecated(note = "...")]` on the method, or an empty string if the
+    /// attribute has no note. The attribute itself stays on the method.
+    pub deprecated: Option<String>,
+    /// Whether a deprecated method logs a warning on every call, specified with `#[log_deprecated]`.
+    pub is_log_deprecated: bool,
     /// The receiver, like `mut self`, `self`, `&mut self`, `&self`, or `None`.
     pub receiver: Option<Receiver>,
     /// What this function returns.
@@ -64,11 +103,27 @@ impl AttrSigInfo {
         let mut args = vec![];
         let mut is_init = false;
         let mut is_payable = false;
+        let mut is_assert_one_yocto = false;
         let mut is_private = false;
+        let mut only_accounts = vec![];
+        let mut is_log_input = false;
+        let mut is_non_reentrant = false;
+        let mut handle_result_attr = None;
+        let mut deprecated = None;
+        let mut log_deprecated_attr = None;
         // By the default we serialize the result with JSON.
         let mut result_serializer = SerializerType::JSON;
+        let mut result_serializer_with = None;
+        let mut result_serializer_sorted_keys = false;
+        let mut result_serializer_gzip = false;
+        let mut result_serializer_raw = false;
+        let mut max_input_len = None;
+        let mut min_gas_attr = None;
+        let mut export_name = None;
+        let mut expect_args_attr = None;
 
         let mut payable_attr = None;
+        let mut one_yocto_attr = None;
         for attr in original_attrs.iter() {
             let attr_str = attr.path.to_token_stream().to_string();
             match attr_str.as_str() {
@@ -79,12 +134,84 @@ impl AttrSigInfo {
                     payable_attr = Some(attr);
                     is_payable = true;
                 }
+                "assert_one_yocto" => {
+                    one_yocto_attr = Some(attr);
+                    is_assert_one_yocto = true;
+                }
                 "private" => {
                     is_private = true;
                 }
+                "only" => {
+                    let OnlyAttr { accounts } = syn::parse2(attr.tokens.clone())?;
+                    only_accounts.extend(accounts);
+                }
+                "expect_args" => {
+                    let ExpectArgsAttr { names } = syn::parse2(attr.tokens.clone())?;
+                    expect_args_attr = Some((attr, names));
+                }
+                "method_name" => {
+                    let name: LitStr = attr.parse_args()?;
+                    if name.value().is_empty() {
+                        return Err(Error::new(name.span(), "Method name can't be empty."));
+                    }
+                    export_name = Some(name);
+                }
+                "max_input_len" => {
+                    let MaxInputLenAttr { len } = syn::parse2(attr.tokens.clone())?;
+                    max_input_len = Some(len);
+                }
+                "min_gas" => {
+                    let MinGasAttr { gas } = syn::parse2(attr.tokens.clone())?;
+                    min_gas_attr = Some((attr, gas));
+                }
+                "log_input" => {
+                    is_log_input = true;
+                }
+                "non_reentrant" => {
+                    is_non_reentrant = true;
+                }
+                "handle_result" => {
+                    handle_result_attr = Some(attr);
+                }
+                "deprecated" => {
+                    deprecated = Some(deprecation_note(attr)?);
+                }
+                "log_deprecated" => {
+                    log_deprecated_attr = Some(attr);
+                }
+                "result_serializer" if is_raw_serializer(attr) => {
+                    result_serializer_raw = true;
+                }
                 "result_serializer" => {
                     let serializer: SerializerAttr = syn::parse2(attr.tokens.clone())?;
+                    if serializer.single_value {
+                        return Err(Error::new(
+                            attr.span(),
+                            "`single_value` is only supported by argument serializers.",
+                        ));
+                    }
+                    if serializer.serializer_type == SerializerType::Query {
+                        return Err(Error::new(
+                            attr.span(),
+                            "`query` is only supported by argument serializers.",
+                        ));
+                    }
+                    if serializer.serializer_type == SerializerType::Auto {
+                        return Err(Error::new(
+                            attr.span(),
+                            "`auto_by_caller` is only supported by argument serializers.",
+                        ));
+                    }
+                    if serializer.gzip && !cfg!(feature = "gzip") {
+                        return Err(Error::new(
+                            attr.span(),
+                            "`gzip` requires the `gzip` feature of `near-sdk`.",
+                        ));
+                    }
                     result_serializer = serializer.serializer_type;
+                    result_serializer_with = serializer.with;
+                    result_serializer_sorted_keys = serializer.sorted_keys;
+                    result_serializer_gzip = serializer.gzip;
                 }
                 _ => {
                     non_bindgen_attrs.push((*attr).clone());
@@ -108,6 +235,32 @@ impl AttrSigInfo {
             !is_init
         };
 
+        if let Some((expect_args_attr, names)) = expect_args_attr {
+            let expected: Vec<String> = names.iter().map(LitStr::value).collect();
+            let actual: Vec<String> = args
+                .iter()
+                .filter(|arg| match arg.bindgen_ty {
+                    BindgenArgType::Regular => true,
+                    _ => false,
+                })
+                .map(|arg| arg.ident.to_string())
+                .collect();
+            let missing: Vec<&str> =
+                expected.iter().filter(|name| !actual.contains(name)).map(String::as_str).collect();
+            let unexpected: Vec<&str> =
+                actual.iter().filter(|name| !expected.contains(name)).map(String::as_str).collect();
+            if !missing.is_empty() || !unexpected.is_empty() {
+                return Err(Error::new(
+                    expect_args_attr.span(),
+                    format!(
+                        "Method arguments don't match `expect_args`, missing: [{}], unexpected: [{}]",
+                        missing.join(", "),
+                        unexpected.join(", ")
+                    ),
+                ));
+            }
+        }
+
         if let Some(payable_attr) = payable_attr {
             if is_view {
                 return Err(Error::new(
@@ -117,26 +270,94 @@ impl AttrSigInfo {
             }
         }
 
+        if let Some(one_yocto_attr) = one_yocto_attr {
+            if is_view {
+                return Err(Error::new(
+                    one_yocto_attr.span(),
+                    "Method with `#[assert_one_yocto]` must be mutable (not view)",
+                ));
+            }
+        }
+
+        // The host doesn't provide the prepaid gas to view calls.
+        if let Some((min_gas_attr, _)) = min_gas_attr {
+            if is_view {
+                return Err(Error::new(
+                    min_gas_attr.span(),
+                    "Method with `#[min_gas]` must be mutable (not view)",
+                ));
+            }
+        }
+        let min_gas = min_gas_attr.map(|(_, gas)| gas);
+
+        if let Some(handle_result_attr) = handle_result_attr {
+            if !returns_result(&original_sig.output) {
+                return Err(Error::new(
+                    handle_result_attr.span(),
+                    "`#[handle_result]` requires the method to return `Result<T, E>`.",
+                ));
+            }
+        }
+        let is_handle_result = handle_result_attr.is_some();
+
+        if let (Some(log_deprecated_attr), None) = (log_deprecated_attr, &deprecated) {
+            return Err(Error::new(
+                log_deprecated_attr.span(),
+                "`#[log_deprecated]` requires the method to be `#[deprecated]`.",
+            ));
+        }
+        let is_log_deprecated = log_deprecated_attr.is_some();
+
         original_attrs.retain(|attr| {
             let attr_str = attr.path.to_token_stream().to_string();
             attr_str != "init"
                 && attr_str != "result_serializer"
                 && attr_str != "payable"
+                && attr_str != "assert_one_yocto"
                 && attr_str != "private"
+                && attr_str != "only"
+                && attr_str != "expect_args"
+                && attr_str != "max_input_len"
+                && attr_str != "min_gas"
+                && attr_str != "method_name"
+                && attr_str != "log_input"
+                && attr_str != "non_reentrant"
+                && attr_str != "handle_result"
+                && attr_str != "log_deprecated"
         });
 
         let returns = original_sig.output.clone();
+        if result_serializer_raw && !returns_byte_vec(&returns) {
+            return Err(Error::new(
+                returns.span(),
+                "`#[result_serializer(raw)]` requires the method to return `Vec<u8>`.",
+            ));
+        }
 
         let mut result = Self {
             ident,
+            export_name,
             non_bindgen_attrs,
             args,
             input_serializer: SerializerType::JSON,
             is_init,
             is_payable,
+            is_assert_one_yocto,
             is_private,
+            only_accounts,
+            is_log_input,
+            is_non_reentrant,
+            max_input_len,
+            min_gas,
             is_view,
             result_serializer,
+            result_serializer_with,
+            result_serializer_sorted_keys,
+            result_serializer_gzip,
+            result_serializer_raw,
+            is_handle_result,
+            deprecated,
+            is_log_deprecated,
             receiver,
             returns,
             original_sig: original_sig.clone(),
@@ -147,6 +368,10 @@ impl AttrSigInfo {
                 SerializerType::JSON
             } else if result.input_args().all(|arg| arg.serializer_ty == SerializerType::Borsh) {
                 SerializerType::Borsh
+            } else if result.input_args().all(|arg| arg.serializer_ty == SerializerType::Query) {
+                SerializerType::Query
+            } else if result.input_args().all(|arg| arg.serializer_ty == SerializerType::Auto) {
+                SerializerType::Auto
             } else {
                 return Err(Error::new(
                     Span::call_site(),
@@ -154,9 +379,109 @@ impl AttrSigInfo {
                 ));
             };
         result.input_serializer = input_serializer;
+        if result.input_serializer == SerializerType::Query && result.single_value_arg().is_none() {
+            if let Some(arg) = result.input_args().find(|arg| !is_query_value(&arg.ty)) {
+                return Err(Error::new(
+                    arg.ty.span(),
+                    "`query` serializer only supports arguments of scalar types, like integers, \
+                     strings and booleans.",
+                ));
+            }
+        }
+        if result.input_args().any(|arg| arg.single_value) && result.input_args().count() != 1 {
+            return Err(Error::new(
+                Span::call_site(),
+                "`single_value` and `args` require the method to have exactly one input argument.",
+            ));
+        }
+        // The input is deserialized at once, so `allow_trailing` applies to all of the arguments.
+        if let Some(arg) = result.input_args().filter(|arg| arg.allow_trailing).nth(1) {
+            return Err(Error::new(
+                arg.ident.span(),
+                "`allow_trailing` applies to the whole input, so only one input argument can \
+                 have it.",
+            ));
+        }
+        // Serde would silently fill both arguments from the same key, so the collision is an error.
+        if result.input_serializer != SerializerType::Borsh && result.single_value_arg().is_none() {
+            let mut names: Vec<(String, &Ident)> = vec![];
+            for arg in result.input_args() {
+                let name = arg.json_name();
+                if let Some((_, other)) = names.iter().find(|(other_name, _)| *other_name == name) {
+                    return Err(Error::new(
+                        arg.ident.span(),
+                        format!(
+                            "Arguments `{}` and `{}` are both deserialized from the JSON key `{}`.",
+                            other, arg.ident, name
+                        ),
+                    ));
+                }
+                names.push((name, &arg.ident));
+            }
+        }
         Ok(result)
     }
 
+    /// The name of the method exported by the contract, `ident` unless it is overridden with
+    /// `#[method_name(...)]`.
+    pub fn method_name(&self) -> String {
+        match &self.export_name {
+            Some(name) => name.value(),
+            None => self.ident.to_string(),
+        }
+    }
+
+    /// The only input argument if it is deserialized from the whole input with `single_value`.
+    pub fn single_value_arg(&self) -> Option<&ArgInfo> {
+        self.input_args().find(|arg| arg.single_value)
+    }
+
+    /// Whether the bytes left after the Borsh input are ignored, which is the case if an input
+    /// argument is decorated with `#[serializer(borsh, allow_trailing)]`. At most one can be.
+    pub fn input_allow_trailing(&self) -> bool {
+        self.input_args().any(|arg| arg.allow_trailing)
+    }
+
+    /// Whether every input argument has a bounded size, so that the input of the method can't be
+    /// arbitrarily large. Arguments like `Vec<u8>` or `String` and the types the macro doesn't know,
+    /// e.g. the structs of the contract, make the input unbounded.
+    pub fn has_bounded_input(&self) -> bool {
+        self.input_args().all(|arg| is_bounded_type(&arg.input_ty()))
+    }
+
+    /// Whether the method returns `PromiseOrValue<T>`, in which case the promise is returned
+    /// with `env::promise_return` and only the value is serialized.
+    pub fn returns_promise_or_value(&self) -> bool {
+        self.returns_type_named("PromiseOrValue")
+    }
+
+    /// Whether the method returns `Promise`, which is returned with `env::promise_return`.
+    pub fn returns_promise(&self) -> bool {
+        self.returns_type_named("Promise")
+    }
+
+    fn returns_type_named(&self, name: &str) -> bool {
+        match &self.returns {
+            ReturnType::Type(_, ty) => match ty.as_ref() {
+                Type::Path(path) => {
+                    path.path.segments.last().map(|segment| segment.ident == name).unwrap_or(false)
+                }
+                _ => false,
+            },
+            ReturnType::Default => false,
+        }
+    }
+
+    /// Whether the method is a callback of a promise, i.e. it is `#[private]` or reads the promise
+    /// results with `#[callback]` arguments.
+    pub fn is_callback(&self) -> bool {
+        self.is_private
+            || self.args.iter().any(|arg| match arg.bindgen_ty {
+                BindgenArgType::Regular => false,
+                _ => true,
+            })
+    }
+
     /// Only get args that correspond to `env::input()`.
     pub fn input_args(&self) -> impl Iterator<Item = &ArgInfo> {
         self.args.iter().filter(|arg| match arg.bindgen_ty {
@@ -165,3 +490,148 @@ impl AttrSigInfo {
         })
     }
 }
+
+/// The `= N` part of the `#[max_input_len = N]` attribute.
+struct MaxInputLenAttr {
+    len: LitInt,
+}
+
+impl Parse for MaxInputLenAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<Token![=]>()?;
+        let len: LitInt = input.parse()?;
+        len.base10_parse::<u64>()?;
+        Ok(Self { len })
+    }
+}
+
+/// The `(tgas = N)` part of the `#[min_gas(tgas = N)]` attribute, converted into gas.
+struct MinGasAttr {
+    gas: u64,
+}
+
+impl Parse for MinGasAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        parenthesized!(content in input);
+        let ident: Ident = content.parse()?;
+        if ident != "tgas" {
+            return Err(Error::new(ident.span(), "Expected `tgas = N`."));
+        }
+        content.parse::<Token![=]>()?;
+        let tgas: LitInt = content.parse()?;
+        let gas = tgas
+            .base10_parse::<u64>()?
+            .checked_mul(10u64.pow(12))
+            .ok_or_else(|| Error::new(tgas.span(), "The minimum gas is too large."))?;
+        Ok(Self { gas })
+    }
+}
+
+/// Whether the type can be the value of a `key=value` pair of a query string, which rules out
+/// tuples, arrays and collections.
+fn is_query_value(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => match path.path.segments.last() {
+            Some(segment) => match segment.ident.to_string().as_str() {
+                "Vec" | "VecDeque" | "HashMap" | "HashSet" | "BTreeMap" | "BTreeSet" => false,
+                "Option" => match &segment.arguments {
+                    PathArguments::AngleBracketed(args) => args.args.iter().all(|arg| match arg {
+                        GenericArgument::Type(ty) => is_query_value(ty),
+                        _ => true,
+                    }),
+                    _ => true,
+                },
+                _ => true,
+            },
+            None => true,
+        },
+        Type::Reference(reference) => is_query_value(&reference.elem),
+        Type::Paren(paren) => is_query_value(&paren.elem),
+        Type::Group(group) => is_query_value(&group.elem),
+        _ => false,
+    }
+}
+
+/// Whether the values of the type have a bounded size: scalars, account IDs, which are at most
+/// 64 characters, and options, arrays and tuples of such types.
+fn is_bounded_type(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => match path.path.segments.last() {
+            Some(segment) => match segment.ident.to_string().as_str() {
+                "u8" | "u16" | "u32" | "u64" | "u128" | "i8" | "i16" | "i32" | "i64" | "i128"
+                | "bool" | "char" | "f32" | "f64" | "U64" | "U128" | "I64" | "I128"
+                | "AccountId" | "ValidAccountId" | "Balance" | "Gas" | "BlockHeight" => true,
+                "Option" => match &segment.arguments {
+                    PathArguments::AngleBracketed(args) => args.args.iter().all(|arg| match arg {
+                        GenericArgument::Type(ty) => is_bounded_type(ty),
+                        _ => true,
+                    }),
+                    _ => false,
+                },
+                _ => false,
+            },
+            None => false,
+        },
+        Type::Array(array) => is_bounded_type(&array.elem),
+        Type::Tuple(tuple) => tuple.elems.iter().all(is_bounded_type),
+        Type::Reference(reference) => is_bounded_type(&reference.elem),
+        Type::Paren(paren) => is_bounded_type(&paren.elem),
+        Type::Group(group) => is_bounded_type(&group.elem),
+        _ => false,
+    }
+}
+
+/// Whether the attribute is `#[result_serializer(raw)]`.
+fn is_raw_serializer(attr: &Attribute) -> bool {
+    attr.parse_args::<Ident>().map(|ident| ident == "raw").unwrap_or(false)
+}
+
+/// The note of `#[deprecated]`, `#[deprecated = "..."]` or `#[deprecated(note = "...")]`.
+fn deprecation_note(attr: &Attribute) -> syn::Result<String> {
+    let note = match attr.parse_meta()? {
+        Meta::NameValue(MetaNameValue { lit: Lit::Str(note), .. }) => Some(note),
+        Meta::List(list) => list.nested.into_iter().find_map(|nested| match nested {
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                path, lit: Lit::Str(note), ..
+            })) if path.is_ident("note") => Some(note),
+            _ => None,
+        }),
+        _ => None,
+    };
+    Ok(note.map(|note| note.value()).unwrap_or_default())
+}
+
+/// Whether the return type is `Result<T, E>`.
+fn returns_result(returns: &ReturnType) -> bool {
+    match returns {
+        ReturnType::Type(_, ty) => match ty.as_ref() {
+            Type::Path(path) => {
+                path.path.segments.last().map(|segment| segment.ident == "Result").unwrap_or(false)
+            }
+            _ => false,
+        },
+        ReturnType::Default => false,
+    }
+}
+
+/// Whether the return type is `Vec<u8>`.
+fn returns_byte_vec(returns: &ReturnType) -> bool {
+    let ty = match returns {
+        ReturnType::Type(_, ty) => ty.as_ref(),
+        ReturnType::Default => return false,
+    };
+    let segment = match ty {
+        Type::Path(path) if path.qself.is_none() => path.path.segments.last(),
+        _ => None,
+    };
+    match segment.map(|segment| (&segment.ident, &segment.arguments)) {
+        Some((ident, PathArguments::AngleBracketed(args))) if ident == "Vec" => {
+            match args.args.iter().collect::<Vec<_>>().as_slice() {
+                [GenericArgument::Type(Type::Path(elem))] => elem.path.is_ident("u8"),
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,17 @@
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{parenthesized, LitStr, Token};
+
+/// The `("to", "amount")` part of the `#[expect_args("to", "amount")]` attribute.
+pub struct ExpectArgsAttr {
+    pub names: Vec<LitStr>,
+}
+
+impl Parse for ExpectArgsAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        parenthesized!(content in input);
+        let names = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?;
+        Ok(Self { names: names.into_iter().collect() })
+    }
+}
@@ -0,0 +1,150 @@
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Error, Ident, Lit, LitInt, Path, Token};
+
+/// Arguments of `near_bindgen` on the `impl` section,
+/// e.g. `#[near_bindgen(before_call = path, after_call = path, list_methods, args_structs, max_storage = N, debug_storage, pausable, ownable, verifying_key, state_root = field, reset_non_reentrant)]`.
+#[derive(Default, Clone)]
+pub struct ImplAttr {
+    /// Function called at the start of every generated wrapper, before the arguments are deserialized.
+    pub before_call: Option<Path>,
+    /// Function called at the end of every generated wrapper, after the method has returned and
+    /// the state was written.
+    pub after_call: Option<Path>,
+    /// Whether to generate `__methods` that lists the names of the exported methods.
+    pub list_methods: bool,
+    /// Whether to generate a public struct with the input arguments of every exported method,
+    /// e.g. `SetGreetingArgs` for `set_greeting`.
+    pub args_structs: bool,
+    /// The maximum storage usage of the contract in bytes, checked after every method that
+    /// writes the state.
+    pub max_storage: Option<LitInt>,
+    /// Whether to generate `__debug_storage` that reads raw values from the storage. It is only
+    /// exported when the `debug` feature of the contract is enabled.
+    pub debug_storage: bool,
+    /// Whether the methods that write the state panic while the contract is paused, and `pause` and
+    /// `unpause` methods callable by the contract itself are generated.
+    pub pausable: bool,
+    /// Whether the contract has an owner, which is set by the `#[init]` methods, and `owner`,
+    /// `transfer_ownership` and `renounce_ownership` methods are generated.
+    pub ownable: bool,
+    /// Whether the `set_verifying_key` method callable by the owner is generated, which stores
+    /// a Groth16 verifying key. Requires `ownable`.
+    pub verifying_key: bool,
+    /// The field of the contract with a `MerkleTree`, the root of which is returned by the generated
+    /// `state_root` view method.
+    pub state_root: Option<Ident>,
+    /// Whether the `reset_non_reentrant` method callable by the contract itself, and also by
+    /// the owner if the contract is `ownable`, is generated, which clears the reentrancy flag left
+    /// set by a failed callback of a `#[non_reentrant]` method.
+    pub reset_non_reentrant: bool,
+    /// The arguments that generate exported functions, e.g. `ownable`. Only one `impl` section of
+    /// the contract can have each of them, as the exports would be duplicated otherwise.
+    pub exports: Vec<Ident>,
+}
+
+/// Value of an `ident = value` argument.
+enum ImplAttrValue {
+    Path(Path),
+    Int(LitInt),
+    /// The value of an `ident => path` argument, e.g. `init => new`, which older versions accepted
+    /// and ignored.
+    Legacy,
+}
+
+/// Single argument, either `ident = value` or a flag `ident`.
+struct ImplAttrArg {
+    ident: Ident,
+    value: Option<ImplAttrValue>,
+}
+
+impl Parse for ImplAttrArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+        let value = if input.peek(Token![=>]) {
+            input.parse::<Token![=>]>()?;
+            input.parse::<Path>()?;
+            Some(ImplAttrValue::Legacy)
+        } else if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            if input.peek(Lit) {
+                Some(ImplAttrValue::Int(input.parse()?))
+            } else {
+                Some(ImplAttrValue::Path(input.parse()?))
+            }
+        } else {
+            None
+        };
+        Ok(Self { ident, value })
+    }
+}
+
+impl Parse for ImplAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut result = Self::default();
+        let args = Punctuated::<ImplAttrArg, Token![,]>::parse_terminated(input)?;
+        let mut verifying_key_ident = None;
+        for arg in args {
+            let ImplAttrArg { ident, value } = arg;
+            let duplicate = match (ident.to_string().as_str(), value) {
+                ("before_call", Some(ImplAttrValue::Path(path))) => {
+                    result.before_call.replace(path).is_some()
+                }
+                ("after_call", Some(ImplAttrValue::Path(path))) => {
+                    result.after_call.replace(path).is_some()
+                }
+                ("list_methods", None) => {
+                    result.exports.push(ident.clone());
+                    std::mem::replace(&mut result.list_methods, true)
+                }
+                ("args_structs", None) => std::mem::replace(&mut result.args_structs, true),
+                ("debug_storage", None) => {
+                    result.exports.push(ident.clone());
+                    std::mem::replace(&mut result.debug_storage, true)
+                }
+                ("pausable", None) => {
+                    result.exports.push(ident.clone());
+                    std::mem::replace(&mut result.pausable, true)
+                }
+                ("ownable", None) => {
+                    result.exports.push(ident.clone());
+                    std::mem::replace(&mut result.ownable, true)
+                }
+                ("reset_non_reentrant", None) => {
+                    result.exports.push(ident.clone());
+                    std::mem::replace(&mut result.reset_non_reentrant, true)
+                }
+                ("verifying_key", None) => {
+                    verifying_key_ident = Some(ident.clone());
+                    result.exports.push(ident.clone());
+                    std::mem::replace(&mut result.verifying_key, true)
+                }
+                ("state_root", Some(ImplAttrValue::Path(path))) => match path.get_ident() {
+                    Some(field) => {
+                        result.exports.push(ident.clone());
+                        result.state_root.replace(field.clone()).is_some()
+                    }
+                    None => {
+                        return Err(Error::new(
+                            ident.span(),
+                            "`state_root` must be the name of a field.",
+                        ))
+                    }
+                },
+                ("max_storage", Some(ImplAttrValue::Int(len))) => {
+                    len.base10_parse::<u64>()?;
+                    result.max_storage.replace(len).is_some()
+                }
+                (_, Some(ImplAttrValue::Legacy)) => false,
+                _ => return Err(Error::new(ident.span(), "Unsupported near_bindgen argument.")),
+            };
+            if duplicate {
+                return Err(Error::new(ident.span(), "Duplicate near_bindgen argument."));
+            }
+        }
+        if let (Some(ident), false) = (verifying_key_ident, result.ownable) {
+            return Err(Error::new(ident.span(), "`verifying_key` requires `ownable`."));
+        }
+        Ok(result)
+    }
+}
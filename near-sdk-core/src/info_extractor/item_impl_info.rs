@@ -1,6 +1,11 @@
-use crate::ImplItemMethodInfo;
+use crate::{ImplAttr, ImplItemMethodInfo};
+use quote::{format_ident, quote};
+use syn::fold::Fold;
 use syn::spanned::Spanned;
-use syn::{Error, ImplItem, ItemImpl, Type};
+use syn::{
+    parse_quote, Error, FnArg, Ident, ImplItem, ImplItemMethod, ItemImpl, LitStr, Pat, ReturnType,
+    Type,
+};
 
 /// Information extracted from `impl` section.
 pub struct ItemImplInfo {
@@ -10,6 +15,8 @@ pub struct ItemImplInfo {
     pub ty: Type,
     /// Info extracted for each method.
     pub methods: Vec<ImplItemMethodInfo>,
+    /// Arguments of `near_bindgen` applied to this `impl` section.
+    pub attr: ImplAttr,
 }
 
 impl ItemImplInfo {
@@ -23,13 +30,140 @@ impl ItemImplInfo {
         let is_trait_impl = original.trait_.is_some();
         let ty = (*original.self_ty.as_ref()).clone();
 
+        let mut associated_types = AssociatedTypes(vec![]);
+        for subitem in &original.items {
+            if let ImplItem::Type(t) = subitem {
+                associated_types.0.push((t.ident.clone(), t.ty.clone()));
+            }
+        }
+
         let mut methods = vec![];
+        let mut promise_variants = vec![];
         for subitem in &mut original.items {
             if let ImplItem::Method(m) = subitem {
-                let method_info = ImplItemMethodInfo::new(m, ty.clone())?;
+                if let Some(variant) = promise_variant(m, is_trait_impl)? {
+                    promise_variants.push(variant);
+                }
+                let mut method_info = ImplItemMethodInfo::new(m, ty.clone())?;
+                associated_types.resolve(&mut method_info);
                 methods.push(method_info);
             }
         }
-        Ok(Self { is_trait_impl, ty, methods })
+        for mut variant in promise_variants {
+            let mut method_info = ImplItemMethodInfo::new(&mut variant, ty.clone())?;
+            associated_types.resolve(&mut method_info);
+            methods.push(method_info);
+            original.items.push(ImplItem::Method(variant));
+        }
+        Ok(Self { is_trait_impl, ty, methods, attr: Default::default() })
+    }
+}
+
+/// Takes `#[promise_variant]` off the method and generates `<name>_promise` that returns the value
+/// of the method as `PromiseOrValue::Value`. The generated method is added to the `impl` section
+/// and exported like the other methods.
+fn promise_variant(
+    method: &mut ImplItemMethod,
+    is_trait_impl: bool,
+) -> syn::Result<Option<ImplItemMethod>> {
+    let attr = match method.attrs.iter().position(|attr| attr.path.is_ident("promise_variant")) {
+        Some(position) => method.attrs.remove(position),
+        None => return Ok(None),
+    };
+    if is_trait_impl {
+        return Err(Error::new(
+            attr.span(),
+            "`#[promise_variant]` is not supported in trait implementations.",
+        ));
+    }
+    if method.attrs.iter().any(|attr| attr.path.is_ident("init")) {
+        return Err(Error::new(
+            attr.span(),
+            "`#[promise_variant]` is not supported on initialization methods.",
+        ));
+    }
+    let ty = match &method.sig.output {
+        ReturnType::Type(_, ty) => ty.clone(),
+        ReturnType::Default => {
+            return Err(Error::new(
+                attr.span(),
+                "`#[promise_variant]` requires the method to return a value.",
+            ))
+        }
+    };
+
+    let mut variant = method.clone();
+    let mut has_receiver = false;
+    let mut args = vec![];
+    for fn_arg in &mut variant.sig.inputs {
+        match fn_arg {
+            FnArg::Receiver(_) => has_receiver = true,
+            FnArg::Typed(pat_typed) => match pat_typed.pat.as_mut() {
+                Pat::Ident(pat_ident) => {
+                    // The argument is only passed on, so it doesn't need to be mutable.
+                    pat_ident.mutability = None;
+                    args.push(pat_ident.ident.clone());
+                }
+                pat => {
+                    return Err(Error::new(
+                        pat.span(),
+                        "`#[promise_variant]` requires the arguments to be identifiers.",
+                    ))
+                }
+            },
+        }
+    }
+    let ident = &method.sig.ident;
+    let invocation = if has_receiver {
+        quote! { self.#ident(#(#args),*) }
+    } else {
+        quote! { Self::#ident(#(#args),*) }
+    };
+    variant.sig.ident = format_ident!("{}_promise", ident);
+    variant.sig.output = parse_quote! { -> near_sdk::PromiseOrValue<#ty> };
+    variant.block = parse_quote! {{ near_sdk::PromiseOrValue::Value(#invocation) }};
+    for attr in &mut variant.attrs {
+        if attr.path.is_ident("method_name") {
+            let name: LitStr = attr.parse_args()?;
+            let name = LitStr::new(&format!("{}_promise", name.value()), name.span());
+            *attr = parse_quote! { #[method_name(#name)] };
+        }
+    }
+    Ok(Some(variant))
+}
+
+/// Associated types of the trait implementation, like `type Balance = U128;`. The generated code
+/// lives outside of the `impl` section, so `Self::Balance` in the signatures of the methods is
+/// replaced with the concrete type.
+struct AssociatedTypes(Vec<(Ident, Type)>);
+
+impl AssociatedTypes {
+    fn resolve(&mut self, method_info: &mut ImplItemMethodInfo) {
+        if self.0.is_empty() {
+            return;
+        }
+        let sig = &mut method_info.attr_signature_info;
+        for arg in &mut sig.args {
+            arg.ty = self.fold_type(arg.ty.clone());
+            arg.original.ty = Box::new(self.fold_type(*arg.original.ty.clone()));
+        }
+        if let ReturnType::Type(_, ty) = &mut sig.returns {
+            *ty = Box::new(self.fold_type(*ty.clone()));
+        }
+    }
+}
+
+impl Fold for AssociatedTypes {
+    fn fold_type(&mut self, ty: Type) -> Type {
+        if let Type::Path(path) = &ty {
+            let segments = &path.path.segments;
+            if path.qself.is_none() && segments.len() == 2 && segments[0].ident == "Self" {
+                let name = &segments[1].ident;
+                if let Some((_, resolved)) = self.0.iter().find(|(ident, _)| ident == name) {
+                    return resolved.clone();
+                }
+            }
+        }
+        syn::fold::fold_type(self, ty)
     }
 }
@@ -1,6 +1,17 @@
 mod serializer_attr;
 pub use serializer_attr::SerializerAttr;
 
+mod impl_attr;
+pub use impl_attr::ImplAttr;
+
+mod struct_attr;
+pub use struct_attr::StructAttr;
+
+mod only_attr;
+pub use only_attr::AllowedAccount;
+
+mod expect_args_attr;
+
 mod arg_info;
 pub use arg_info::{ArgInfo, BindgenArgType};
 
@@ -24,6 +35,11 @@ pub use item_impl_info::ItemImplInfo;
 pub enum SerializerType {
     JSON,
     Borsh,
+    /// URL query string like `to=alice&amount=5`, only supported for the input of the methods.
+    Query,
+    /// JSON or Borsh depending on whether the method is called by a user or by a contract, see
+    /// `near_sdk::deserialize_by_caller`. Only supported for the input of the methods.
+    Auto,
 }
 
 /// Whether the input struct is used for serialization or deserialization.
@@ -0,0 +1,42 @@
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{parenthesized, Error, LitStr, Token};
+
+/// Account that is allowed to call the method decorated with `#[only(...)]`.
+#[derive(Clone)]
+pub enum AllowedAccount {
+    /// The contract itself, specified as `self`.
+    Current,
+    /// The account with the given ID, specified as a string literal.
+    Account(LitStr),
+}
+
+impl Parse for AllowedAccount {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![self]) {
+            input.parse::<Token![self]>()?;
+            Ok(AllowedAccount::Current)
+        } else if input.peek(LitStr) {
+            Ok(AllowedAccount::Account(input.parse()?))
+        } else {
+            Err(Error::new(input.span(), "Expected `self` or an account ID string literal."))
+        }
+    }
+}
+
+/// The `("admin.near", self)` part of the `#[only("admin.near", self)]` attribute.
+pub struct OnlyAttr {
+    pub accounts: Vec<AllowedAccount>,
+}
+
+impl Parse for OnlyAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        parenthesized!(content in input);
+        let accounts = Punctuated::<AllowedAccount, Token![,]>::parse_terminated(&content)?;
+        if accounts.is_empty() {
+            return Err(Error::new(content.span(), "Expected at least one account."));
+        }
+        Ok(Self { accounts: accounts.into_iter().collect() })
+    }
+}
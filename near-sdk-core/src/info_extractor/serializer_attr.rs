@@ -1,12 +1,22 @@
 use crate::info_extractor::SerializerType;
 use proc_macro2::Ident;
 use syn::parse::{Parse, ParseStream};
-use syn::{parenthesized, Error};
+use syn::{parenthesized, Error, LitStr, Path, Token};
 
 pub struct SerializerAttr {
     #[allow(dead_code)]
     paren_token: syn::token::Paren,
     pub serializer_type: SerializerType,
+    /// Module with a custom `serialize` function, specified as `with = "path"`.
+    pub with: Option<Path>,
+    /// Whether the whole input is the JSON value of the argument, specified as `single_value`.
+    pub single_value: bool,
+    /// Whether the keys of JSON objects are serialized in sorted order, specified as `sorted_keys`.
+    pub sorted_keys: bool,
+    /// Whether the bytes left after the Borsh value are ignored, specified as `allow_trailing`.
+    pub allow_trailing: bool,
+    /// Whether the serialized JSON is compressed with gzip, specified as `gzip`.
+    pub gzip: bool,
 }
 
 impl Parse for SerializerAttr {
@@ -17,8 +27,59 @@ impl Parse for SerializerAttr {
         let serializer_type = match ident.to_string().as_str() {
             "borsh" => SerializerType::Borsh,
             "json" => SerializerType::JSON,
+            "query" => SerializerType::Query,
+            "auto_by_caller" => SerializerType::Auto,
             _ => return Err(Error::new(input.span(), "Unsupported serializer type.")),
         };
-        Ok(Self { paren_token, serializer_type })
+        let mut with = None;
+        let mut single_value = false;
+        let mut sorted_keys = false;
+        let mut allow_trailing = false;
+        let mut gzip = false;
+        while content.parse::<Option<Token![,]>>()?.is_some() {
+            let key: Ident = content.parse()?;
+            if key == "allow_trailing" {
+                if serializer_type != SerializerType::Borsh {
+                    return Err(Error::new(
+                        key.span(),
+                        "`allow_trailing` is only supported by Borsh serializer.",
+                    ));
+                }
+                allow_trailing = true;
+                continue;
+            }
+            if serializer_type != SerializerType::JSON {
+                return Err(Error::new(
+                    key.span(),
+                    format!("`{}` is only supported by JSON serializer.", key),
+                ));
+            }
+            match key.to_string().as_str() {
+                "with" => {
+                    content.parse::<Token![=]>()?;
+                    let path: LitStr = content.parse()?;
+                    with = Some(path.parse()?);
+                }
+                "single_value" => single_value = true,
+                "sorted_keys" => sorted_keys = true,
+                "gzip" => gzip = true,
+                _ => return Err(Error::new(key.span(), "Unsupported serializer argument.")),
+            }
+        }
+        if with.is_some() && sorted_keys {
+            return Err(Error::new(
+                input.span(),
+                "`with` can't be used together with `sorted_keys`.",
+            ));
+        }
+        Ok(Self {
+            paren_token,
+            serializer_type,
+            with,
+            single_value,
+            sorted_keys,
+            allow_trailing,
+            gzip,
+        })
     }
 }
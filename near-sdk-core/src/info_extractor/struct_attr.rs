@@ -0,0 +1,28 @@
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Error, Ident, Token};
+
+/// Arguments of `near_bindgen` on the contract struct, e.g. `#[near_bindgen(state_layout_hash)]`.
+#[derive(Default, Clone)]
+pub struct StructAttr {
+    /// Whether to export `state_layout_hash` that returns the hash of the Borsh schema of the
+    /// contract state.
+    pub state_layout_hash: bool,
+}
+
+impl Parse for StructAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut result = Self::default();
+        let args = Punctuated::<Ident, Token![,]>::parse_terminated(input)?;
+        for ident in args {
+            let duplicate = match ident.to_string().as_str() {
+                "state_layout_hash" => std::mem::replace(&mut result.state_layout_hash, true),
+                _ => return Err(Error::new(ident.span(), "Unsupported near_bindgen argument.")),
+            };
+            if duplicate {
+                return Err(Error::new(ident.span(), "Duplicate near_bindgen argument."));
+            }
+        }
+        Ok(result)
+    }
+}
@@ -1,4 +1,4 @@
-use crate::info_extractor::AttrSigInfo;
+use crate::info_extractor::{AttrSigInfo, SerializerType};
 use syn::export::Span;
 use syn::spanned::Spanned;
 use syn::{Error, LitByteStr, TraitItemMethod};
@@ -27,9 +27,23 @@ impl TraitItemMethodInfo {
         let TraitItemMethod { attrs, sig, .. } = original;
 
         let attr_sig_info = AttrSigInfo::new(attrs, sig)?;
+        if attr_sig_info.input_args().any(|arg| arg.decrypt.is_some()) {
+            return Err(Error::new(
+                original.span(),
+                "`decrypt` is only supported by the methods of the contract.",
+            ));
+        }
+        if let Some(arg) =
+            attr_sig_info.input_args().find(|arg| arg.serializer_ty == SerializerType::Auto)
+        {
+            return Err(Error::new(
+                arg.ident.span(),
+                "`auto_by_caller` is only supported by the methods of the contract.",
+            ));
+        }
 
         let ident_byte_str =
-            LitByteStr::new(attr_sig_info.ident.to_string().as_bytes(), Span::call_site());
+            LitByteStr::new(attr_sig_info.method_name().as_bytes(), Span::call_site());
 
         Ok(Self { attr_sig_info, original: original.clone(), ident_byte_str })
     }
@@ -29,24 +29,34 @@ impl ImplItemMethodInfo {
     ///     },
     ///     callbacks: vec![],
     ///     callbacks_vec: None,
-    ///     result: Some(Result < IsOk, Error > ::schema_container())
+    ///     result: Some(<Result < IsOk, Error >>::schema_container()),
+    ///     deprecated: None,
+    ///     bounded_input: false
     /// }
     /// ```
     /// If args are serialized with Borsh it will not include `#[derive(borsh::BorshSchema)]`.
+    /// With the `abi` feature it also includes `args_json_schema`, see `args_json_schema`.
+    /// `#[deprecated(note = "use f4")]` on the method produces `deprecated: Some("use f4".to_string())`.
+    /// `bounded_input` is `false` because the size of `FancyStruct` is unknown.
     pub fn metadata_struct(&self) -> TokenStream2 {
-        let method_name_str = self.attr_signature_info.ident.to_string();
+        let method_name_str = self.attr_signature_info.method_name();
         let is_view = match &self.attr_signature_info.receiver {
             None => true,
             Some(rec) => rec.mutability.is_none(),
         };
         let is_init = self.attr_signature_info.is_init;
-        let args = if self.attr_signature_info.input_args().next().is_some() {
+        let args = if let Some(arg) = self.attr_signature_info.single_value_arg() {
+            let ty = &arg.ty;
+            quote! {
+                Some(<#ty>::schema_container())
+            }
+        } else if self.attr_signature_info.input_args().next().is_some() {
             let input_struct =
                 self.attr_signature_info.input_struct(InputStructType::Deserialization);
             // If input args are JSON then we need to additionally specify schema for them.
             let additional_schema = match &self.attr_signature_info.input_serializer {
                 SerializerType::Borsh => TokenStream2::new(),
-                SerializerType::JSON => quote! {
+                SerializerType::JSON | SerializerType::Query | SerializerType::Auto => quote! {
                     #[derive(borsh::BorshSchema)]
                 },
             };
@@ -62,6 +72,14 @@ impl ImplItemMethodInfo {
                  None
             }
         };
+        let args_json_schema = if cfg!(feature = "abi") {
+            let schema = self.args_json_schema();
+            quote! {
+                args_json_schema: #schema,
+            }
+        } else {
+            TokenStream2::new()
+        };
         let callbacks: Vec<_> = self
             .attr_signature_info
             .args
@@ -73,7 +91,7 @@ impl ImplItemMethodInfo {
             .map(|arg| {
                 let ty = &arg.ty;
                 quote! {
-                    #ty::schema_container()
+                    <#ty>::schema_container()
                 }
             })
             .collect();
@@ -95,7 +113,7 @@ impl ImplItemMethodInfo {
             Some(arg) => {
                 let ty = &arg.ty;
                 quote! {
-                    Some(#ty::schema_container())
+                    Some(<#ty>::schema_container())
                 }
             }
         };
@@ -107,10 +125,20 @@ impl ImplItemMethodInfo {
             }
             ReturnType::Type(_, ty) => {
                 quote! {
-                    Some(#ty::schema_container())
+                    Some(<#ty>::schema_container())
                 }
             }
         };
+        let deprecated = match &self.attr_signature_info.deprecated {
+            Some(note) => quote! {
+                Some(#note.to_string())
+            },
+            None => quote! {
+                None
+            },
+        };
+
+        let bounded_input = self.attr_signature_info.has_bounded_input();
 
         quote! {
              near_sdk::MethodMetadata {
@@ -118,10 +146,148 @@ impl ImplItemMethodInfo {
                  is_view: #is_view,
                  is_init: #is_init,
                  args: #args,
+                 #args_json_schema
                  callbacks: vec![#(#callbacks),*],
                  callbacks_vec: #callbacks_vec,
-                 result: #result
+                 result: #result,
+                 deprecated: #deprecated,
+                 bounded_input: #bounded_input
              }
         }
     }
+
+    /// Generates the JSON Schema of the arguments for the `args_json_schema` field of the metadata,
+    /// or `None` if the arguments are not serialized with JSON.
+    pub fn args_json_schema(&self) -> TokenStream2 {
+        let attr_signature_info = &self.attr_signature_info;
+        let is_json = match &attr_signature_info.input_serializer {
+            SerializerType::JSON | SerializerType::Auto => true,
+            SerializerType::Borsh | SerializerType::Query => false,
+        };
+        if !is_json || attr_signature_info.input_args().next().is_none() {
+            return quote! {
+                None
+            };
+        }
+        if let Some(arg) = attr_signature_info.single_value_arg() {
+            let ty = &arg.ty;
+            return quote! {
+                Some(near_sdk::json_schema_string::<#ty>())
+            };
+        }
+        let input_struct = attr_signature_info.input_struct(InputStructType::Deserialization);
+        quote! {
+            {
+                #[derive(near_sdk::schemars::JsonSchema)]
+                #[schemars(crate = "near_sdk::schemars")]
+                #input_struct
+                Some(near_sdk::json_schema_string::<Input>())
+            }
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::ImplItemMethodInfo;
+    use quote::quote;
+    use syn::{parse_quote, ImplItemMethod, Type};
+
+    #[test]
+    fn args_json_schema() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn transfer(&mut self, amount: u64, receiver_id: String) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        let actual = method_info.args_json_schema();
+        let expected = quote!(
+            {
+                #[derive(near_sdk::schemars::JsonSchema)]
+                #[schemars(crate = "near_sdk::schemars")]
+                #[derive(near_sdk :: serde :: Deserialize)]
+                #[serde(crate = "near_sdk::serde")]
+                #[allow(clippy::all, unused)]
+                struct Input {
+                    amount: u64,
+                    receiver_id: String,
+                }
+                Some(near_sdk::json_schema_string::<Input>())
+            }
+        );
+        assert_eq!(expected.to_string(), actual.to_string());
+    }
+
+    #[test]
+    fn args_json_schema_borsh() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn transfer(&mut self, #[serializer(borsh)] amount: u64) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        assert_eq!(method_info.args_json_schema().to_string(), quote!(None).to_string());
+    }
+
+    #[test]
+    fn bounded_input() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn upload(&mut self, id: u64, data: Vec<u8>) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type.clone()).unwrap();
+        assert!(!method_info.attr_signature_info.has_bounded_input());
+        assert!(method_info.metadata_struct().to_string().contains(
+            &quote!(bounded_input: false).to_string()
+        ));
+
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn transfer(&mut self, receiver_id: ValidAccountId, amount: U128, memo: Option<u64>, key: [u8; 32], pair: (u32, bool)) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type.clone()).unwrap();
+        assert!(method_info.attr_signature_info.has_bounded_input());
+        assert!(method_info.metadata_struct().to_string().contains(
+            &quote!(bounded_input: true).to_string()
+        ));
+
+        for ty in &["String", "Option<String>", "HashMap<u64, u64>", "Base64VecU8", "FancyStruct"] {
+            let ty: Type = syn::parse_str(ty).unwrap();
+            let mut method: ImplItemMethod = parse_quote! {
+                pub fn method(&mut self, x: #ty) { }
+            };
+            let method_info = ImplItemMethodInfo::new(&mut method, impl_type.clone()).unwrap();
+            assert!(!method_info.attr_signature_info.has_bounded_input());
+        }
+    }
+
+    #[test]
+    fn deprecated_method() {
+        let impl_type: Type = syn::parse_str("Hello").unwrap();
+        let mut method: ImplItemMethod = parse_quote! {
+            #[deprecated(since = "1.2.0", note = "use transfer_v2")]
+            pub fn transfer(&mut self) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type.clone()).unwrap();
+        assert_eq!(method_info.attr_signature_info.deprecated, Some("use transfer_v2".to_string()));
+        assert!(method_info.metadata_struct().to_string().contains(
+            &quote!(deprecated: Some("use transfer_v2".to_string())).to_string()
+        ));
+        // The attribute is kept on the method.
+        assert_eq!(method.attrs.len(), 1);
+
+        let mut method: ImplItemMethod = parse_quote! {
+            #[deprecated]
+            pub fn transfer(&mut self) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type.clone()).unwrap();
+        assert_eq!(method_info.attr_signature_info.deprecated, Some(String::new()));
+
+        let mut method: ImplItemMethod = parse_quote! {
+            pub fn transfer(&mut self) { }
+        };
+        let method_info = ImplItemMethodInfo::new(&mut method, impl_type).unwrap();
+        assert!(method_info.metadata_struct().to_string().contains(
+            &quote!(deprecated: None).to_string()
+        ));
+    }
 }
@@ -98,6 +98,28 @@ mod tests {
         visitor.visit_file(&file);
 
         let actual = visitor.generate_metadata_method().unwrap();
+        // With the `abi` feature the metadata also includes `args_json_schema`.
+        let (f1_json_schema, f2_json_schema) = if cfg!(feature = "abi") {
+            (
+                quote!(args_json_schema: None,),
+                quote!(
+                    args_json_schema: {
+                        #[derive(near_sdk::schemars::JsonSchema)]
+                        #[schemars(crate = "near_sdk::schemars")]
+                        #[derive(near_sdk :: serde :: Deserialize)]
+                        #[serde(crate = "near_sdk::serde")]
+                        #[allow(clippy::all, unused)]
+                        struct Input {
+                            arg0: FancyStruct,
+                            arg1: u64,
+                        }
+                        Some(near_sdk::json_schema_string::<Input>())
+                    },
+                ),
+            )
+        } else {
+            (quote!(), quote!())
+        };
         let expected = quote!(
             #[cfg(target_arch = "wasm32")]
             #[no_mangle]
@@ -111,9 +133,12 @@ mod tests {
                         is_view: true,
                         is_init: false,
                         args: None,
+                        #f1_json_schema
                         callbacks: vec![],
                         callbacks_vec: None,
-                        result: None
+                        result: None,
+                        deprecated: None,
+                        bounded_input: true
                     },
                     near_sdk::MethodMetadata {
                         name: "f2".to_string(),
@@ -123,15 +148,19 @@ mod tests {
                             #[derive(borsh::BorshSchema)]
                             #[derive(near_sdk :: serde :: Deserialize)]
                             #[serde(crate = "near_sdk::serde")]
+                            #[allow(clippy::all, unused)]
                             struct Input {
                                 arg0: FancyStruct,
                                 arg1: u64,
                             }
                             Some(Input::schema_container())
                         },
+                        #f2_json_schema
                         callbacks: vec![],
                         callbacks_vec: None,
-                        result: None
+                        result: None,
+                        deprecated: None,
+                        bounded_input: false
                     },
                     near_sdk::MethodMetadata {
                         name: "f3".to_string(),
@@ -141,15 +170,19 @@ mod tests {
                             #[derive(borsh::BorshSchema)]
                             #[derive(near_sdk :: serde :: Deserialize)]
                             #[serde(crate = "near_sdk::serde")]
+                            #[allow(clippy::all, unused)]
                             struct Input {
                                 arg0: FancyStruct,
                                 arg1: u64,
                             }
                             Some(Input::schema_container())
                         },
+                        #f2_json_schema
                         callbacks: vec![],
                         callbacks_vec: None,
-                        result: Some(Result < IsOk, Error > ::schema_container())
+                        result: Some(<Result < IsOk, Error >>::schema_container()),
+                        deprecated: None,
+                        bounded_input: false
                     }
                 ]);
                 let data = near_sdk::borsh::BorshSerialize::try_to_vec(&metadata)
@@ -85,6 +85,31 @@ pub mod near_blockchain {
             sys::keccak512(value_len, value_ptr, register_id)
         }
 
+        unsafe fn alt_bn128_g1_multiexp(&self, value_len: u64, value_ptr: u64, register_id: u64) {
+            sys::alt_bn128_g1_multiexp(value_len, value_ptr, register_id)
+        }
+
+        unsafe fn alt_bn128_g1_sum(&self, value_len: u64, value_ptr: u64, register_id: u64) {
+            sys::alt_bn128_g1_sum(value_len, value_ptr, register_id)
+        }
+
+        unsafe fn alt_bn128_pairing_check(&self, value_len: u64, value_ptr: u64) -> u64 {
+            sys::alt_bn128_pairing_check(value_len, value_ptr)
+        }
+
+        unsafe fn ecrecover(
+            &self,
+            hash_len: u64,
+            hash_ptr: u64,
+            sig_len: u64,
+            sig_ptr: u64,
+            v: u64,
+            malleability_flag: u64,
+            register_id: u64,
+        ) -> u64 {
+            sys::ecrecover(hash_len, hash_ptr, sig_len, sig_ptr, v, malleability_flag, register_id)
+        }
+
         unsafe fn value_return(&self, value_len: u64, value_ptr: u64) {
             sys::value_return(value_len, value_ptr)
         }
@@ -204,6 +229,29 @@ pub mod near_blockchain {
             )
         }
 
+        unsafe fn promise_batch_action_function_call_weight(
+            &self,
+            promise_index: u64,
+            method_name_len: u64,
+            method_name_ptr: u64,
+            arguments_len: u64,
+            arguments_ptr: u64,
+            amount_ptr: u64,
+            gas: u64,
+            gas_weight: u64,
+        ) {
+            sys::promise_batch_action_function_call_weight(
+                promise_index,
+                method_name_len,
+                method_name_ptr,
+                arguments_len,
+                arguments_ptr,
+                amount_ptr,
+                gas,
+                gas_weight,
+            )
+        }
+
         unsafe fn promise_batch_action_transfer(&self, promise_index: u64, amount_ptr: u64) {
             sys::promise_batch_action_transfer(promise_index, amount_ptr)
         }
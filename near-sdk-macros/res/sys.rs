@@ -33,6 +33,18 @@ pub mod sys {
         pub fn sha256(value_len: u64, value_ptr: u64, register_id: u64);
         pub fn keccak256(value_len: u64, value_ptr: u64, register_id: u64);
         pub fn keccak512(value_len: u64, value_ptr: u64, register_id: u64);
+        pub fn alt_bn128_g1_multiexp(value_len: u64, value_ptr: u64, register_id: u64);
+        pub fn alt_bn128_g1_sum(value_len: u64, value_ptr: u64, register_id: u64);
+        pub fn alt_bn128_pairing_check(value_len: u64, value_ptr: u64) -> u64;
+        pub fn ecrecover(
+            hash_len: u64,
+            hash_ptr: u64,
+            sig_len: u64,
+            sig_ptr: u64,
+            v: u64,
+            malleability_flag: u64,
+            register_id: u64,
+        ) -> u64;
         // #####################
         // # Miscellaneous API #
         // #####################
@@ -90,6 +102,16 @@ pub mod sys {
             amount_ptr: u64,
             gas: u64,
         );
+        pub fn promise_batch_action_function_call_weight(
+            promise_index: u64,
+            method_name_len: u64,
+            method_name_ptr: u64,
+            arguments_len: u64,
+            arguments_ptr: u64,
+            amount_ptr: u64,
+            gas: u64,
+            gas_weight: u64,
+        );
         pub fn promise_batch_action_transfer(promise_index: u64, amount_ptr: u64);
         pub fn promise_batch_action_stake(
             promise_index: u64,
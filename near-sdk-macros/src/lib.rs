@@ -7,27 +7,55 @@ use near_sdk_core::*;
 use proc_macro2::Span;
 use quote::quote;
 use syn::visit::Visit;
-use syn::{File, ItemImpl, ItemStruct, ItemTrait};
+use syn::{File, ItemEnum, ItemImpl, ItemStruct, ItemTrait};
 
 #[proc_macro_attribute]
-pub fn near_bindgen(_attr: TokenStream, item: TokenStream) -> TokenStream {
+pub fn near_bindgen(attr: TokenStream, item: TokenStream) -> TokenStream {
     if let Ok(input) = syn::parse::<ItemStruct>(item.clone()) {
+        let struct_attr: StructAttr = match syn::parse(attr) {
+            Ok(x) => x,
+            Err(err) => {
+                return err.to_compile_error().into();
+            }
+        };
         let sys_file = rust_file(include_bytes!("../res/sys.rs"));
         let near_environment = rust_file(include_bytes!("../res/near_blockchain.rs"));
         let struct_proxy = generate_proxy_struct(&input);
+        let reset_state = generate_reset_state(&input);
+        let state_layout_hash = generate_state_layout_hash(&input, &struct_attr);
         TokenStream::from(quote! {
             #input
             #struct_proxy
+            #reset_state
+            #state_layout_hash
             #sys_file
             #near_environment
         })
+    } else if let Ok(input) = syn::parse::<ItemEnum>(item.clone()) {
+        if !attr.is_empty() {
+            return TokenStream::from(
+                syn::Error::new(Span::call_site(), "near_bindgen on enums takes no arguments.")
+                    .to_compile_error(),
+            );
+        }
+        if let Err(err) = check_enum_serializers(&input) {
+            return err.to_compile_error().into();
+        }
+        TokenStream::from(quote! { #input })
     } else if let Ok(mut input) = syn::parse::<ItemImpl>(item) {
-        let item_impl_info = match ItemImplInfo::new(&mut input) {
+        let impl_attr: ImplAttr = match syn::parse(attr) {
             Ok(x) => x,
             Err(err) => {
                 return err.to_compile_error().into();
             }
         };
+        let mut item_impl_info = match ItemImplInfo::new(&mut input) {
+            Ok(x) => x,
+            Err(err) => {
+                return err.to_compile_error().into();
+            }
+        };
+        item_impl_info.attr = impl_attr;
         let generated_code = item_impl_info.wrapper_code();
         // Add helper type for simulation testing only if not wasm32
         let marshalled_code = item_impl_info.marshall_code();
@@ -87,6 +115,15 @@ pub fn ext_contract(attr: TokenStream, item: TokenStream) -> TokenStream {
 // The below attributes a marker-attributes and therefore they are no-op.
 
 /// `callback` is a marker attribute it does not generate code by itself.
+/// The `i`-th argument decorated with `#[callback]` is deserialized from the `i`-th promise result,
+/// so the results of calls returning different types can be bound to arguments of different types.
+///
+/// The callback is a separate function call that executes in a later block, and its wrapper reads
+/// the state of the contract from the storage like the wrapper of any other method. So the callback
+/// sees every change made after the promise was scheduled, including those made by other calls in
+/// between, and must not assume that the state is still the one the calling method left.
+/// `#[callback(reload_state)]` states this explicitly: the state is read at the entry of the
+/// callback, which the wrapper of every method already does, so the generated code is the same.
 #[proc_macro_attribute]
 pub fn callback(_attr: TokenStream, item: TokenStream) -> TokenStream {
     item
@@ -99,12 +136,32 @@ pub fn callback_vec(_attr: TokenStream, item: TokenStream) -> TokenStream {
 }
 
 /// `serializer` is a marker attribute it does not generate code by itself.
+/// Borsh ignores serde attributes of the argument types, so an enum with `#[serde(untagged)]`
+/// is still encoded with the variant index when the argument uses `#[serializer(borsh)]`.
+/// `#[near_bindgen]` on such an enum rejects its Borsh derives.
+///
+/// The JSON input of a method with several arguments can be either an object with a field per
+/// argument, `{"a": 1, "b": 2}`, or an array of the arguments in the order of the signature,
+/// `[1, 2]`, as serde accepts both for the generated input struct. The two shapes can't be
+/// confused since the input is always parsed according to its first character. An array can
+/// leave out the trailing arguments with `#[default = expr]`, while the arguments with a default
+/// that are followed by other arguments have to be given in it, and an array that is too short or
+/// too long is rejected.
+///
+/// With `#[serializer(auto_by_caller)]` on every argument the method accepts both JSON from
+/// frontends and Borsh from contracts. The format tried first is guessed from whether the
+/// predecessor signed the transaction, and the other one is tried if it fails, see
+/// `near_sdk::deserialize_by_caller` for the limits of the guess.
 #[proc_macro_attribute]
 pub fn serializer(_attr: TokenStream, item: TokenStream) -> TokenStream {
     item
 }
 
 /// `result_serializer` is a marker attribute it does not generate code by itself.
+///
+/// `#[result_serializer(json, gzip)]` compresses the JSON of the result with gzip, which needs the
+/// `gzip` feature of `near-sdk`. It shrinks large results at the cost of the gas spent on
+/// the compression inside the contract, see `near_sdk::gzip`.
 #[proc_macro_attribute]
 pub fn result_serializer(_attr: TokenStream, item: TokenStream) -> TokenStream {
     item
@@ -168,3 +225,21 @@ pub fn derive_no_default(item: TokenStream) -> TokenStream {
         )
     }
 }
+
+/// `PanicCode` implements `near_sdk::PanicCode` for an enum of errors, mapping each variant to the
+/// stable code and the message of its `#[panic_code("E101", "Insufficient balance")]` attribute.
+/// The errors returned by the methods with `#[handle_result]` are reported as `E101: Insufficient balance`.
+#[proc_macro_derive(PanicCode, attributes(panic_code))]
+pub fn derive_panic_code(item: TokenStream) -> TokenStream {
+    if let Ok(input) = syn::parse::<ItemEnum>(item) {
+        match generate_panic_code(&input) {
+            Ok(x) => TokenStream::from(x),
+            Err(err) => TokenStream::from(err.to_compile_error()),
+        }
+    } else {
+        TokenStream::from(
+            syn::Error::new(Span::call_site(), "PanicCode can only be used on enums.")
+                .to_compile_error(),
+        )
+    }
+}
@@ -16,4 +16,36 @@ fn compilation_tests() {
     t.pass("compilation_tests/lifetime_method.rs");
     t.pass("compilation_tests/cond_compilation.rs");
     t.compile_fail("compilation_tests/payable_view.rs");
+    t.pass("compilation_tests/log_input.rs");
+    t.pass("compilation_tests/call_hooks.rs");
+    t.pass("compilation_tests/heterogeneous_callbacks.rs");
+    t.pass("compilation_tests/callback_reload_state.rs");
+    t.pass("compilation_tests/result_serializer_with.rs");
+    t.pass("compilation_tests/default_args.rs");
+    t.pass("compilation_tests/list_methods.rs");
+    t.pass("compilation_tests/args_struct.rs");
+    t.pass("compilation_tests/deny_warnings.rs");
+    t.pass("compilation_tests/only.rs");
+    t.pass("compilation_tests/expect_args.rs");
+    t.compile_fail("compilation_tests/expect_args_mismatch.rs");
+    t.pass("compilation_tests/untagged_enum_arg.rs");
+    t.compile_fail("compilation_tests/untagged_enum_borsh.rs");
+    t.pass("compilation_tests/args_attr.rs");
+    t.pass("compilation_tests/tagged_enum_args.rs");
+    t.pass("compilation_tests/promise_variant.rs");
+    t.pass("compilation_tests/state_layout_hash.rs");
+    t.pass("compilation_tests/query_args.rs");
+    t.compile_fail("compilation_tests/query_args_nested.rs");
+    t.pass("compilation_tests/panic_code.rs");
+    t.pass("compilation_tests/debug_storage.rs");
+    t.pass("compilation_tests/serde_with.rs");
+    t.pass("compilation_tests/integer_map_keys.rs");
+    t.pass("compilation_tests/positional_args.rs");
+    t.pass("compilation_tests/auto_by_caller.rs");
+    t.pass("compilation_tests/ownable.rs");
+    t.compile_fail("compilation_tests/ownable_twice.rs");
+    t.pass("compilation_tests/decrypt_args.rs");
+    t.pass("compilation_tests/withdraw_request.rs");
+    t.compile_fail("compilation_tests/arg_name_collision.rs");
+    t.compile_fail("compilation_tests/ext_auto_by_caller.rs");
 }
@@ -0,0 +1,15 @@
+//! Two arguments deserialized from the same JSON key are not valid.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::near_bindgen;
+
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+struct Test {}
+
+#[near_bindgen]
+impl Test {
+    pub fn transfer(&mut self, #[serde(rename = "amount")] value: u64, amount: u64) {}
+}
+
+fn main() {}
@@ -0,0 +1,47 @@
+//! Method that receives the whole input as a single struct marked with `#[args]`.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::near_bindgen;
+use near_sdk::serde_json::{self, json};
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, PartialEq)]
+pub struct TransferRequest {
+    pub to: String,
+    pub amount: u64,
+    pub memo: Option<String>,
+}
+
+#[derive(BorshDeserialize, Debug, PartialEq)]
+pub struct RawTransfer {
+    pub to: String,
+    pub amount: u64,
+}
+
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+struct Ledger {
+    total: u64,
+}
+
+#[near_bindgen]
+impl Ledger {
+    pub fn transfer(&mut self, #[args] req: TransferRequest) {
+        self.total += req.amount;
+    }
+
+    pub fn transfer_raw(&mut self, #[args] #[serializer(borsh)] req: RawTransfer) {
+        self.total += req.amount;
+    }
+}
+
+fn main() {
+    let req: TransferRequest =
+        serde_json::from_value(json!({"to": "bob.near", "amount": 5})).unwrap();
+    assert_eq!(req, TransferRequest { to: "bob.near".to_string(), amount: 5, memo: None });
+
+    let mut ledger = Ledger::default();
+    ledger.transfer(req);
+    ledger.transfer_raw(RawTransfer { to: "bob.near".to_string(), amount: 2 });
+    assert_eq!(ledger.total, 7);
+}
@@ -0,0 +1,30 @@
+//! Arguments of the methods encoded with the args structs generated by `args_structs`.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::near_bindgen;
+use near_sdk::serde_json::json;
+
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+struct Storage {
+    values: Vec<(String, u64)>,
+}
+
+#[near_bindgen(args_structs)]
+impl Storage {
+    pub fn set_value(&mut self, key: String, value: u64) {
+        self.values.push((key, value));
+    }
+
+    pub fn push_raw(&mut self, #[serializer(borsh)] key: String, #[serializer(borsh)] value: u64) {
+        self.values.push((key, value));
+    }
+}
+
+fn main() {
+    let args = SetValueArgs { key: "a".to_string(), value: 1 };
+    assert_eq!(near_sdk::serde_json::to_value(&args).unwrap(), json!({"key": "a", "value": 1}));
+
+    let args = PushRawArgs { key: "b".to_string(), value: 2 };
+    assert_eq!(args.try_to_vec().unwrap(), ("b".to_string(), 2u64).try_to_vec().unwrap());
+}
@@ -0,0 +1,32 @@
+//! Method that accepts both JSON and Borsh input.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::near_bindgen;
+use near_sdk::serde::Deserialize;
+
+#[derive(Deserialize, BorshDeserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Order {
+    pub price: u64,
+    pub amount: u64,
+}
+
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+struct Exchange {
+    volume: u64,
+}
+
+#[near_bindgen]
+impl Exchange {
+    pub fn place(
+        &mut self,
+        #[serializer(auto_by_caller)] order: Order,
+        #[serializer(auto_by_caller)] memo: String,
+    ) {
+        self.volume += order.price * order.amount;
+        near_sdk::env::log(memo.as_bytes());
+    }
+}
+
+fn main() {}
@@ -0,0 +1,33 @@
+//! Smart contract that calls hooks before and after every method.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::{env, near_bindgen};
+
+fn log_call() {
+    env::log(b"Method called");
+}
+
+mod metrics {
+    pub fn record() {
+        near_sdk::env::log(b"Method finished");
+    }
+}
+
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+struct Incrementer {
+    value: u32,
+}
+
+#[near_bindgen(before_call = log_call, after_call = metrics::record)]
+impl Incrementer {
+    pub fn inc(&mut self, by: u32) {
+        self.value += by;
+    }
+
+    pub fn get(&self) -> u32 {
+        self.value
+    }
+}
+
+fn main() {}
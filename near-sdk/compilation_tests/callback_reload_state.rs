@@ -0,0 +1,47 @@
+//! Smart contract with a callback that sees the changes made to the state after the promise was
+//! scheduled.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::{env, ext_contract, near_bindgen, Promise};
+
+#[ext_contract(ext_self)]
+pub trait CounterCallbacks {
+    fn on_value(&self, #[callback(reload_state)] value: u64) -> u64;
+}
+
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+struct Counter {
+    total: u64,
+}
+
+#[near_bindgen]
+impl Counter {
+    pub fn add(&mut self, value: u64) {
+        self.total += value;
+    }
+
+    pub fn schedule(&self) -> Promise {
+        ext_self::on_value(&env::current_account_id(), 0, 10_000_000_000_000)
+    }
+
+    #[private]
+    pub fn on_value(&self, #[callback(reload_state)] value: u64) -> u64 {
+        self.total + value
+    }
+}
+
+fn main() {
+    near_sdk::test_utils::test_env::setup();
+    // The calls read and write the state like their wrappers do.
+    let mut contract: Counter = env::state_read().unwrap_or_default();
+    contract.add(1);
+    let _ = contract.schedule();
+    env::state_write(&contract);
+    // Another call changes the state before the callback runs.
+    let mut other: Counter = env::state_read().unwrap();
+    other.add(10);
+    env::state_write(&other);
+    let callback: Counter = env::state_read().unwrap();
+    assert_eq!(callback.on_value(5), 16);
+}
@@ -0,0 +1,30 @@
+//! Smart contract that can read its raw storage when built with the `debug` feature, and always in
+//! the unit tests.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::near_bindgen;
+
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+struct Incrementer {
+    value: u32,
+}
+
+#[near_bindgen(debug_storage)]
+impl Incrementer {
+    pub fn inc(&mut self, by: u32) {
+        self.value += by;
+    }
+}
+
+fn main() {
+    use near_sdk::json_types::Base64VecU8;
+
+    near_sdk::test_utils::test_env::setup();
+    near_sdk::env::storage_write(b"STATE", &[1, 0, 0, 0]);
+    assert_eq!(
+        Incrementer::__debug_storage(Base64VecU8(b"STATE".to_vec())),
+        Some(Base64VecU8(vec![1, 0, 0, 0]))
+    );
+    assert_eq!(Incrementer::__debug_storage(Base64VecU8(b"missing".to_vec())), None);
+}
@@ -0,0 +1,47 @@
+//! Method with an argument that is passed encrypted and decrypted before the method is called.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::near_bindgen;
+
+const KEY: u8 = 0x5a;
+
+/// Trivial XOR cipher, which is its own inverse.
+fn xor(data: Vec<u8>) -> Vec<u8> {
+    data.into_iter().map(|byte| byte ^ KEY).collect()
+}
+
+fn decrypt_memo(ciphertext: Vec<u8>) -> Result<String, std::string::FromUtf8Error> {
+    String::from_utf8(xor(ciphertext))
+}
+
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+struct Vault {
+    memos: Vec<String>,
+}
+
+#[near_bindgen(args_structs)]
+impl Vault {
+    pub fn deposit(&mut self, #[decrypt(with = "decrypt_memo")] memo: String, amount: u64) {
+        near_sdk::env::log(format!("{}: {}", memo, amount).as_bytes());
+        self.memos.push(memo);
+    }
+
+    pub fn deposit_borsh(
+        &mut self,
+        #[serializer(borsh)]
+        #[decrypt(with = "decrypt_memo")]
+        memo: String,
+    ) {
+        self.memos.push(memo);
+    }
+}
+
+fn main() {
+    let ciphertext = xor(b"secret".to_vec());
+    assert_ne!(ciphertext, b"secret".to_vec());
+    let args = DepositArgs { memo: ciphertext.clone().into(), amount: 1 };
+    assert_eq!(decrypt_memo(args.memo.into()).unwrap(), "secret");
+    let args = DepositBorshArgs { memo: ciphertext };
+    assert_eq!(decrypt_memo(args.memo).unwrap(), "secret");
+}
@@ -0,0 +1,21 @@
+//! Smart contract with arguments that can be omitted in the input.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::near_bindgen;
+
+const DEFAULT_LIMIT: u64 = 10;
+
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+struct Storage {
+    values: Vec<u64>,
+}
+
+#[near_bindgen]
+impl Storage {
+    pub fn get_values(&self, #[default = 0] from: u64, #[default = DEFAULT_LIMIT] limit: u64) -> Vec<u64> {
+        self.values.iter().skip(from as usize).take(limit as usize).cloned().collect()
+    }
+}
+
+fn main() {}
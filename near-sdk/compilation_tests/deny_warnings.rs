@@ -0,0 +1,40 @@
+//! Generated code compiles in crates that deny warnings.
+#![deny(warnings)]
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::near_bindgen;
+
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+struct Incrementer {
+    value: u32,
+}
+
+#[near_bindgen]
+impl Incrementer {
+    pub fn inc(&mut self, mut by: u32) {
+        by += 1;
+        self.value += by;
+    }
+
+    #[payable]
+    pub fn set(&mut self, #[serializer(borsh)] value: u32) {
+        self.value = value;
+    }
+
+    pub fn on_value(&mut self, #[callback] value: u32) {
+        self.value = value;
+    }
+
+    pub fn get(&self) -> u32 {
+        self.value
+    }
+}
+
+fn main() {
+    let mut contract = Incrementer::default();
+    contract.inc(1);
+    contract.set(2);
+    contract.on_value(3);
+    assert_eq!(contract.get(), 3);
+}
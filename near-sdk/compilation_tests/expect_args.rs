@@ -0,0 +1,31 @@
+//! Method arguments that match the names expected by the frontend.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::near_bindgen;
+
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+struct Token {
+    total: u128,
+}
+
+#[near_bindgen]
+impl Token {
+    #[expect_args("to", "amount")]
+    pub fn transfer(&mut self, to: String, amount: u64) {
+        let _ = to;
+        self.total += amount as u128;
+    }
+
+    #[expect_args("amount", "to")]
+    pub fn transfer_call(&mut self, amount: u64, to: String, #[callback] _fee: u64) {
+        self.transfer(to, amount);
+    }
+
+    #[expect_args()]
+    pub fn total(&self) -> u128 {
+        self.total
+    }
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+//! Method arguments that were renamed without updating `expect_args`.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::near_bindgen;
+
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+struct Token {
+    total: u128,
+}
+
+#[near_bindgen]
+impl Token {
+    #[expect_args("to", "amount")]
+    pub fn transfer(&mut self, receiver_id: String, amount: u64) {
+        let _ = receiver_id;
+        self.total += amount as u128;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+//! The arguments of the methods of external contracts can't be `auto_by_caller`.
+
+use near_sdk::ext_contract;
+
+#[ext_contract(ext_exchange)]
+pub trait Exchange {
+    fn place(&mut self, #[serializer(auto_by_caller)] amount: u64);
+}
+
+fn main() {}
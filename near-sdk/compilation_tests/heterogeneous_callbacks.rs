@@ -0,0 +1,37 @@
+//! Smart contract that fans out to two methods returning different types and combines the results
+//! in a callback. Each `#[callback]` argument is deserialized from the promise result with the same
+//! index as the position of the argument among the callback arguments.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::{env, ext_contract, near_bindgen, Promise};
+
+#[ext_contract(ext_oracle)]
+pub trait Oracle {
+    fn get_price(&self) -> u64;
+    fn get_symbol(&self) -> String;
+}
+
+#[ext_contract(ext_self)]
+pub trait Combiner {
+    fn on_results(&self, #[callback] price: u64, #[callback] symbol: String) -> String;
+}
+
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+struct Quotes {}
+
+#[near_bindgen]
+impl Quotes {
+    pub fn quote(&self, oracle_id: String) -> Promise {
+        ext_oracle::get_price(&oracle_id, 0, 10_000_000_000_000)
+            .and(ext_oracle::get_symbol(&oracle_id, 0, 10_000_000_000_000))
+            .then(ext_self::on_results(&env::current_account_id(), 0, 10_000_000_000_000))
+    }
+
+    #[private]
+    pub fn on_results(&self, #[callback] price: u64, #[callback] symbol: String) -> String {
+        format!("{} {}", price, symbol)
+    }
+}
+
+fn main() {}
@@ -0,0 +1,39 @@
+//! Map arguments with integer keys, which are strings in JSON objects.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::near_bindgen;
+use near_sdk::serde_json::{self, json};
+use std::collections::HashMap;
+
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+struct Registry {
+    names: Vec<(u64, String)>,
+}
+
+#[near_bindgen(args_structs)]
+impl Registry {
+    pub fn set_names(&mut self, names: HashMap<u64, String>) {
+        self.names.extend(names);
+    }
+
+    pub fn set_names_raw(&mut self, #[serializer(borsh)] names: HashMap<u64, String>) {
+        self.names.extend(names);
+    }
+}
+
+fn main() {
+    let expected: HashMap<u64, String> =
+        vec![(1, "a".to_string()), (2, "b".to_string())].into_iter().collect();
+    let args = SetNamesArgs { names: expected.clone() };
+    let value = serde_json::to_value(&args).unwrap();
+    assert_eq!(value, json!({"names": {"1": "a", "2": "b"}}));
+    let args = SetNamesArgs::from_input(br#"{"names": {"1": "a", "2": "b"}}"#).unwrap();
+    assert_eq!(args.names, expected);
+    assert!(SetNamesArgs::from_input(br#"{"names": {"one": "a"}}"#).is_err());
+
+    let args = SetNamesRawArgs { names: expected.clone() };
+    assert_eq!(args.try_to_vec().unwrap(), expected.try_to_vec().unwrap());
+    let args = SetNamesRawArgs::from_input(&expected.try_to_vec().unwrap()).unwrap();
+    assert_eq!(args.names, expected);
+}
@@ -0,0 +1,29 @@
+//! Smart contract that lists its exported methods.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::near_bindgen;
+
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+struct Incrementer {
+    value: u32,
+}
+
+#[near_bindgen(list_methods)]
+impl Incrementer {
+    pub fn inc(&mut self, by: u32) {
+        self.value += by;
+    }
+
+    pub fn get(&self) -> u32 {
+        self.value
+    }
+
+    fn helper(&self) -> u32 {
+        self.value
+    }
+}
+
+fn main() {
+    assert_eq!(Incrementer::__methods(), vec!["inc".to_string(), "get".to_string()]);
+}
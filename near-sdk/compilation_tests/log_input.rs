@@ -0,0 +1,21 @@
+//! Smart contract that logs the input of a method.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::near_bindgen;
+
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+struct Incrementer {
+    value: u32,
+}
+
+#[near_bindgen]
+impl Incrementer {
+    #[log_input]
+    pub fn inc(&mut self, by: u32, memo: String) {
+        self.value += by;
+        let _ = memo;
+    }
+}
+
+fn main() {}
@@ -12,6 +12,10 @@ impl Incrementer {
     pub fn inc(&mut self, by: u32) {
         self.value += by;
     }
+
+    pub fn value_and_label(&self) -> (u32, String) {
+        (self.value, "incrementer".to_string())
+    }
 }
 }
 
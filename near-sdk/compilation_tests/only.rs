@@ -0,0 +1,26 @@
+//! Smart contract with methods that can only be called by the given accounts.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::near_bindgen;
+
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+struct Incrementer {
+    value: u32,
+}
+
+#[near_bindgen]
+impl Incrementer {
+    #[only("admin.near")]
+    pub fn reset(&mut self) {
+        self.value = 0;
+    }
+
+    #[only(self, "admin.near")]
+    #[only("operator.near")]
+    pub fn inc(&mut self, by: u32) {
+        self.value += by;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+//! Contract with an owner that can pause it.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::near_bindgen;
+
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+struct Counter {
+    value: u64,
+}
+
+#[near_bindgen(ownable, pausable)]
+impl Counter {
+    #[init]
+    pub fn new(owner_id: String) -> Self {
+        near_sdk::set_owner_id(Some(&owner_id));
+        Self { value: 0 }
+    }
+
+    pub fn increment(&mut self) {
+        self.value += 1;
+    }
+
+    pub fn reset(&mut self) {
+        near_sdk::assert_owner();
+        self.value = 0;
+    }
+}
+
+fn main() {}
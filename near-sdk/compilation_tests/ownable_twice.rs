@@ -0,0 +1,26 @@
+//! Only one `impl` section can generate the ownership methods.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::near_bindgen;
+
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+struct Counter {
+    value: u64,
+}
+
+#[near_bindgen(ownable)]
+impl Counter {
+    pub fn increment(&mut self) {
+        self.value += 1;
+    }
+}
+
+#[near_bindgen(ownable)]
+impl Counter {
+    pub fn reset(&mut self) {
+        self.value = 0;
+    }
+}
+
+fn main() {}
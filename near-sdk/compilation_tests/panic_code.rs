@@ -0,0 +1,40 @@
+//! Smart contract that reports its errors with stable codes.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::{near_bindgen, PanicCode};
+
+#[derive(PanicCode)]
+enum TokenError {
+    #[panic_code("E101", "Insufficient balance")]
+    InsufficientBalance,
+    #[panic_code("E102", "Unknown account")]
+    UnknownAccount(String),
+}
+
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+struct Token {
+    balance: u64,
+}
+
+#[near_bindgen]
+impl Token {
+    #[handle_result]
+    pub fn withdraw(&mut self, amount: u64) -> Result<u64, TokenError> {
+        if amount > self.balance {
+            return Err(TokenError::InsufficientBalance);
+        }
+        self.balance -= amount;
+        Ok(self.balance)
+    }
+}
+
+fn main() {
+    near_sdk::test_utils::test_env::setup();
+    let error = Token::default().withdraw(5).err().unwrap();
+    assert_eq!(error.panic_message(), "E101: Insufficient balance");
+    assert_eq!(TokenError::UnknownAccount("bob.near".to_string()).code(), "E102");
+    let panic = std::panic::catch_unwind(|| near_sdk::panic_code(&error)).unwrap_err();
+    let message = panic.downcast_ref::<String>().unwrap();
+    assert!(message.contains("E101: Insufficient balance"), "{}", message);
+}
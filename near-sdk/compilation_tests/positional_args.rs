@@ -0,0 +1,40 @@
+//! The JSON input of a method with several arguments can be an object or an array of the arguments.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::near_bindgen;
+
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+struct Calculator {
+    result: u64,
+}
+
+#[near_bindgen(args_structs)]
+impl Calculator {
+    pub fn add(&mut self, a: u64, b: u64) {
+        self.result = a + b;
+    }
+
+    pub fn scale(&mut self, #[default = 1] offset: u64, a: u64, #[default = 2] factor: u64) {
+        self.result = (a + offset) * factor;
+    }
+}
+
+fn main() {
+    let named = AddArgs::from_input(br#"{"a": 1, "b": 2}"#).unwrap();
+    let positional = AddArgs::from_input(b"[1, 2]").unwrap();
+    let mut first = Calculator::default();
+    first.add(named.a, named.b);
+    let mut second = Calculator::default();
+    second.add(positional.a, positional.b);
+    assert_eq!((first.result, second.result), (3, 3));
+    assert!(AddArgs::from_input(b"[1]").is_err());
+    assert!(AddArgs::from_input(b"[1, 2, 3]").is_err());
+
+    // Only the trailing arguments with a default can be left out of the array.
+    let args = ScaleArgs::from_input(b"[0, 5]").unwrap();
+    assert_eq!((args.offset, args.a, args.factor), (0, 5, 2));
+    let args = ScaleArgs::from_input(br#"{"a": 5}"#).unwrap();
+    assert_eq!((args.offset, args.a, args.factor), (1, 5, 2));
+    assert!(ScaleArgs::from_input(b"[5]").is_err());
+}
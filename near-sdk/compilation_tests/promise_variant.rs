@@ -0,0 +1,36 @@
+//! Smart contract that exports a getter along with its `PromiseOrValue` variant.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::{near_bindgen, PromiseOrValue};
+
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+struct Incrementer {
+    value: u32,
+}
+
+#[near_bindgen(list_methods)]
+impl Incrementer {
+    pub fn inc(&mut self, by: u32) {
+        self.value += by;
+    }
+
+    #[promise_variant]
+    pub fn get_plus(&self, mut extra: u32) -> u32 {
+        extra += self.value;
+        extra
+    }
+}
+
+fn main() {
+    assert_eq!(
+        Incrementer::__methods(),
+        vec!["inc".to_string(), "get_plus".to_string(), "get_plus_promise".to_string()]
+    );
+    let mut contract = Incrementer::default();
+    contract.inc(5);
+    match contract.get_plus_promise(2) {
+        PromiseOrValue::Value(value) => assert_eq!(value, contract.get_plus(2)),
+        PromiseOrValue::Promise(_) => panic!("Expected the value"),
+    }
+}
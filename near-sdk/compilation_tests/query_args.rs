@@ -0,0 +1,29 @@
+//! Smart contract with a view method that takes its arguments as a query string.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::near_bindgen;
+
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+struct Token {
+    total: u64,
+}
+
+#[near_bindgen(args_structs)]
+impl Token {
+    pub fn can_transfer(
+        &self,
+        #[serializer(query)] to: String,
+        #[serializer(query)] amount: u64,
+    ) -> bool {
+        !to.is_empty() && amount <= self.total
+    }
+}
+
+fn main() {
+    let args = CanTransferArgs { to: "alice".to_string(), amount: 5 };
+    assert_eq!(near_sdk::serde_urlencoded::to_string(&args).unwrap(), "to=alice&amount=5");
+    let args = CanTransferArgs::from_input(b"to=alice&amount=5").unwrap();
+    assert_eq!((args.to.as_str(), args.amount), ("alice", 5));
+    assert!(CanTransferArgs::from_input(b"to=alice&amount=five").is_err());
+}
@@ -0,0 +1,17 @@
+//! Arguments of nested types can't be encoded in a query string.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::near_bindgen;
+
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+struct Token {}
+
+#[near_bindgen]
+impl Token {
+    pub fn can_transfer(&self, #[serializer(query)] to: Vec<String>) -> bool {
+        to.is_empty()
+    }
+}
+
+fn main() {}
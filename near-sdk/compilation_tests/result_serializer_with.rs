@@ -0,0 +1,29 @@
+//! Smart contract that returns bytes encoded with a custom serializer.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::near_bindgen;
+
+mod hex_bytes {
+    use near_sdk::serde::Serializer;
+
+    pub fn serialize<S: Serializer>(bytes: &Vec<u8>, serializer: S) -> Result<S::Ok, S::Error> {
+        let hex: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+        serializer.serialize_str(&hex)
+    }
+}
+
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+struct Storage {
+    data: Vec<u8>,
+}
+
+#[near_bindgen]
+impl Storage {
+    #[result_serializer(json, with = "hex_bytes")]
+    pub fn get_data(&self) -> Vec<u8> {
+        self.data.clone()
+    }
+}
+
+fn main() {}
@@ -0,0 +1,93 @@
+//! Method with an argument that is deserialized with a custom serde module.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::near_bindgen;
+use near_sdk::serde_json;
+
+/// Nanosecond timestamps serialized as ISO 8601 strings with whole seconds in UTC,
+/// e.g. `"2021-03-01T12:00:00Z"`.
+mod iso_timestamp {
+    use near_sdk::serde::{de, Deserialize, Deserializer, Serializer};
+
+    const NANOS_PER_SEC: u64 = 1_000_000_000;
+
+    /// Days since 1970-01-01 of the date in the proleptic Gregorian calendar.
+    fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+        let year = if month <= 2 { year - 1 } else { year };
+        let era = year / 400;
+        let yoe = year - era * 400;
+        let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146097 + doe - 719468
+    }
+
+    /// The date of the given day since 1970-01-01.
+    fn civil_from_days(days: i64) -> (i64, i64, i64) {
+        let days = days + 719468;
+        let era = days / 146097;
+        let doe = days - era * 146097;
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+        (year, month, day)
+    }
+
+    pub fn serialize<S: Serializer>(nanos: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+        let secs = (nanos / NANOS_PER_SEC) as i64;
+        let (year, month, day) = civil_from_days(secs / 86400);
+        let time = secs % 86400;
+        serializer.serialize_str(&format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+            year,
+            month,
+            day,
+            time / 3600,
+            time / 60 % 60,
+            time % 60
+        ))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        let invalid = || -> D::Error { de::Error::custom(format!("Invalid timestamp: {}", s)) };
+        let bytes = s.as_bytes();
+        if !s.is_ascii() || bytes.len() != 20 || bytes[4] != b'-' || bytes[7] != b'-' {
+            return Err(invalid());
+        }
+        if bytes[10] != b'T' || bytes[13] != b':' || bytes[16] != b':' || bytes[19] != b'Z' {
+            return Err(invalid());
+        }
+        let num = |range: std::ops::Range<usize>| s[range].parse::<i64>().map_err(|_| invalid());
+        let days = days_from_civil(num(0..4)?, num(5..7)?, num(8..10)?);
+        let secs = days * 86400 + num(11..13)? * 3600 + num(14..16)? * 60 + num(17..19)?;
+        Ok(secs as u64 * NANOS_PER_SEC)
+    }
+}
+
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+struct Auction {
+    start: u64,
+}
+
+#[near_bindgen(args_structs)]
+impl Auction {
+    pub fn set_start(&mut self, #[serde(with = "iso_timestamp")] start: u64) {
+        self.start = start;
+    }
+}
+
+fn main() {
+    let nanos = 1_614_600_000_000_000_000;
+    let args = SetStartArgs { start: nanos };
+    assert_eq!(serde_json::to_string(&args).unwrap(), r#"{"start":"2021-03-01T12:00:00Z"}"#);
+    let mut deserializer = serde_json::Deserializer::from_str(r#""2021-03-01T12:00:00Z""#);
+    assert_eq!(iso_timestamp::deserialize(&mut deserializer).unwrap(), nanos);
+
+    let mut auction = Auction::default();
+    auction.set_start(nanos);
+    assert_eq!(auction.start, nanos);
+}
@@ -0,0 +1,22 @@
+//! Smart contract that exports the hash of the Borsh layout of its state.
+
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use near_sdk::near_bindgen;
+
+#[near_bindgen(state_layout_hash)]
+#[derive(Default, BorshDeserialize, BorshSerialize, BorshSchema)]
+struct Incrementer {
+    value: u32,
+}
+
+#[near_bindgen]
+impl Incrementer {
+    pub fn inc(&mut self, by: u32) {
+        self.value += by;
+    }
+}
+
+fn main() {
+    near_sdk::test_utils::test_env::setup();
+    assert_eq!(Incrementer::__state_layout_hash().len(), 32);
+}
@@ -0,0 +1,47 @@
+//! Method that takes the whole input as an internally tagged command enum.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::near_bindgen;
+use near_sdk::serde_json::{self, json};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Command {
+    Mint { amount: u64 },
+    Transfer { to: String, amount: u64 },
+}
+
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+struct Token {
+    total: u64,
+}
+
+#[near_bindgen]
+impl Token {
+    pub fn execute(&mut self, #[args] command: Command) {
+        match command {
+            Command::Mint { amount } => self.total += amount,
+            Command::Transfer { .. } => {}
+        }
+    }
+}
+
+fn main() {
+    // The input is deserialized into the command itself, as the wrapper does with `#[args]`.
+    let input = json!({"type": "mint", "amount": 5}).to_string();
+    let command: Command = serde_json::from_slice(input.as_bytes()).unwrap();
+    assert_eq!(command, Command::Mint { amount: 5 });
+
+    let input = json!({"type": "transfer", "to": "bob.near", "amount": 2}).to_string();
+    let command: Command = serde_json::from_slice(input.as_bytes()).unwrap();
+    assert_eq!(command, Command::Transfer { to: "bob.near".to_string(), amount: 2 });
+
+    let input = json!({"type": "burn", "amount": 2}).to_string();
+    assert!(serde_json::from_slice::<Command>(input.as_bytes()).is_err());
+
+    let mut token = Token::default();
+    token.execute(Command::Mint { amount: 5 });
+    assert_eq!(token.total, 5);
+}
@@ -0,0 +1,50 @@
+//! Method argument that is an untagged enum, so its JSON is one of several shapes.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::near_bindgen;
+use near_sdk::serde_json::{self, json};
+use serde::{Deserialize, Serialize};
+
+#[near_bindgen]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum Command {
+    Transfer { to: String, amount: u64 },
+    Burn { amount: u64 },
+    Reset,
+}
+
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+struct Ledger {
+    total: u64,
+}
+
+#[near_bindgen(args_structs)]
+impl Ledger {
+    pub fn execute(&mut self, command: Command) {
+        match command {
+            Command::Transfer { amount, .. } => self.total += amount,
+            Command::Burn { amount } => self.total -= amount,
+            Command::Reset => self.total = 0,
+        }
+    }
+}
+
+fn main() {
+    let args = ExecuteArgs { command: Command::Transfer { to: "bob.near".to_string(), amount: 5 } };
+    assert_eq!(
+        serde_json::to_value(&args).unwrap(),
+        json!({"command": {"to": "bob.near", "amount": 5}})
+    );
+
+    let command: Command = serde_json::from_value(json!({"amount": 3})).unwrap();
+    assert_eq!(command, Command::Burn { amount: 3 });
+    let command: Command = serde_json::from_value(json!(null)).unwrap();
+    assert_eq!(command, Command::Reset);
+
+    let mut ledger = Ledger::default();
+    ledger.execute(Command::Transfer { to: "bob.near".to_string(), amount: 5 });
+    ledger.execute(Command::Burn { amount: 3 });
+    assert_eq!(ledger.total, 2);
+}
@@ -0,0 +1,15 @@
+//! Untagged enum that derives Borsh, which ignores `#[serde(untagged)]`.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::near_bindgen;
+use serde::{Deserialize, Serialize};
+
+#[near_bindgen]
+#[derive(Serialize, Deserialize, BorshDeserialize, BorshSerialize)]
+#[serde(untagged)]
+pub enum Command {
+    Transfer { to: String, amount: u64 },
+    Reset,
+}
+
+fn main() {}
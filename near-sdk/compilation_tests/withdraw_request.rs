@@ -0,0 +1,83 @@
+//! Method of a shielded pool with a structured argument that mixes the JSON helper types.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::{deserialize_byte_array, Base64VecU8, ValidAccountId, U128};
+use near_sdk::serde::{Deserialize, Deserializer, Serialize};
+use near_sdk::{near_bindgen, serde_json};
+use std::convert::TryInto;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct WithdrawRequest {
+    pub proof: Base64VecU8,
+    #[serde(deserialize_with = "deserialize_nullifier")]
+    pub nullifier: [u8; 32],
+    pub recipient: ValidAccountId,
+    pub amount: U128,
+    pub fee: U128,
+}
+
+/// Fixed-size arrays nested in a struct are not accepted as hex or base64 by default, unlike the
+/// `[u8; N]` arguments of the methods.
+fn deserialize_nullifier<'de, D>(deserializer: D) -> Result<[u8; 32], D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let bytes = deserialize_byte_array(deserializer, 32)?;
+    let mut result = [0u8; 32];
+    result.copy_from_slice(&bytes);
+    Ok(result)
+}
+
+#[near_bindgen]
+#[derive(Default, BorshDeserialize, BorshSerialize)]
+struct Pool {
+    withdrawn: u128,
+}
+
+#[near_bindgen(args_structs)]
+impl Pool {
+    pub fn withdraw(&mut self, request: WithdrawRequest) {
+        assert!(request.fee.0 <= request.amount.0);
+        self.withdrawn += request.amount.0;
+    }
+}
+
+fn main() {
+    let body = r#"{
+        "request": {
+            "proof": "AQIDBA==",
+            "nullifier": "0x0101010101010101010101010101010101010101010101010101010101010101",
+            "recipient": "alice.near",
+            "amount": "340282366920938463463374607431768211455",
+            "fee": "1000"
+        }
+    }"#;
+    let request: serde_json::Value = serde_json::from_str(body).unwrap();
+    let request: WithdrawRequest = serde_json::from_value(request["request"].clone()).unwrap();
+    assert_eq!(
+        request,
+        WithdrawRequest {
+            proof: vec![1, 2, 3, 4].into(),
+            nullifier: [1; 32],
+            recipient: "alice.near".to_string().try_into().unwrap(),
+            amount: U128(u128::max_value()),
+            fee: U128(1000),
+        }
+    );
+
+    // The recipient is validated while the input is deserialized.
+    let invalid = body.replace("alice.near", "Alice");
+    let invalid: serde_json::Value = serde_json::from_str(&invalid).unwrap();
+    assert!(serde_json::from_value::<WithdrawRequest>(invalid["request"].clone()).is_err());
+
+    // The amounts stay strings when the request is encoded for a cross-contract call.
+    let args = serde_json::to_value(&WithdrawArgs { request }).unwrap();
+    assert_eq!(args["request"]["amount"], "340282366920938463463374607431768211455");
+    assert_eq!(args["request"]["proof"], "AQIDBA==");
+
+    let request: WithdrawRequest = serde_json::from_value(args["request"].clone()).unwrap();
+    let mut pool = Pool::default();
+    pool.withdraw(request);
+    assert_eq!(pool.withdrawn, u128::max_value());
+}
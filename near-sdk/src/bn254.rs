@@ -0,0 +1,184 @@
+//! Arithmetic of the prime fields of the alt_bn128 curve, also known as BN254, on 256-bit numbers
+//! in little-endian 64-bit limbs. It is shared by `FieldElement`, the Poseidon hash, which works in
+//! the scalar field, and the point validation of `groth16`, which works in the base field of the
+//! coordinates.
+
+/// Little-endian 64-bit limbs of a 256-bit number.
+pub(crate) type Limbs = [u64; 4];
+
+/// Prime field whose elements are kept in Montgomery form, i.e. `x * 2^256 mod modulus`.
+pub(crate) struct Field {
+    pub(crate) modulus: Limbs,
+    /// `-modulus^-1 mod 2^64`.
+    inv: u64,
+    /// `2^512 mod modulus`, which converts numbers into Montgomery form.
+    r2: Limbs,
+}
+
+/// The scalar field, whose modulus is the order of the alt_bn128 groups.
+pub(crate) const SCALAR_FIELD: Field = Field {
+    modulus: [0x43e1f593f0000001, 0x2833e84879b97091, 0xb85045b68181585d, 0x30644e72e131a029],
+    inv: 0xc2e1f593efffffff,
+    r2: [0x1bb8e645ae216da7, 0x53fe3ab1e35c59e3, 0x8c49833d53bb8085, 0x0216d0b17f4e44a5],
+};
+
+/// The base field of the coordinates of the points.
+pub(crate) const BASE_FIELD: Field = Field {
+    modulus: [0x3c208c16d87cfd47, 0x97816a916871ca8d, 0xb85045b68181585d, 0x30644e72e131a029],
+    inv: 0x87d20782e4866389,
+    r2: [0xf32cfc5b538afa89, 0xb5e71911d44501fb, 0x47ab1eff0a417ff6, 0x06d89f71cab8351f],
+};
+
+impl Field {
+    /// Converts the number that is less than the modulus into Montgomery form.
+    pub(crate) fn from_limbs(&self, limbs: Limbs) -> Limbs {
+        self.mul(&limbs, &self.r2)
+    }
+
+    /// Converts the little-endian number into Montgomery form. Returns `None` if the number is not
+    /// less than the modulus.
+    pub(crate) fn from_le_bytes(&self, bytes: &[u8; 32]) -> Option<Limbs> {
+        let limbs = to_limbs(bytes);
+        if less_than(&limbs, &self.modulus) {
+            Some(self.from_limbs(limbs))
+        } else {
+            None
+        }
+    }
+
+    /// Converts the element in Montgomery form into a little-endian number.
+    #[cfg_attr(not(feature = "poseidon"), allow(dead_code))]
+    pub(crate) fn to_le_bytes(&self, value: &Limbs) -> [u8; 32] {
+        to_bytes(&self.mul(value, &[1, 0, 0, 0]))
+    }
+
+    pub(crate) fn add(&self, a: &Limbs, b: &Limbs) -> Limbs {
+        let mut result = [0u64; 4];
+        let mut carry = 0;
+        for ((r, a), b) in result.iter_mut().zip(a.iter()).zip(b.iter()) {
+            let sum = *a as u128 + *b as u128 + carry;
+            *r = sum as u64;
+            carry = sum >> 64;
+        }
+        // The sum of two elements is less than `2^255`, so there is no carry out of the last limb.
+        self.reduce(result)
+    }
+
+    pub(crate) fn sub(&self, a: &Limbs, b: &Limbs) -> Limbs {
+        if less_than(a, b) {
+            // `a + modulus - b`, computed so that the intermediate value fits.
+            sub_limbs(&self.modulus, &sub_limbs(b, a))
+        } else {
+            sub_limbs(a, b)
+        }
+    }
+
+    /// Montgomery multiplication, i.e. `a * b * 2^-256 mod modulus`.
+    #[allow(clippy::needless_range_loop)]
+    pub(crate) fn mul(&self, a: &Limbs, b: &Limbs) -> Limbs {
+        let mut t = [0u64; 6];
+        for i in 0..4 {
+            let mut carry = 0;
+            for j in 0..4 {
+                let (lo, hi) = mac(t[j], a[j], b[i], carry);
+                t[j] = lo;
+                carry = hi;
+            }
+            let (sum, overflow) = t[4].overflowing_add(carry);
+            t[4] = sum;
+            t[5] = overflow as u64;
+
+            let m = t[0].wrapping_mul(self.inv);
+            let (_, mut carry) = mac(t[0], m, self.modulus[0], 0);
+            for j in 1..4 {
+                let (lo, hi) = mac(t[j], m, self.modulus[j], carry);
+                t[j - 1] = lo;
+                carry = hi;
+            }
+            let (sum, overflow) = t[4].overflowing_add(carry);
+            t[3] = sum;
+            t[4] = t[5] + overflow as u64;
+        }
+        // The result is less than `2 * modulus < 2^256`, so `t[4]` is zero.
+        self.reduce([t[0], t[1], t[2], t[3]])
+    }
+
+    /// Subtracts the modulus from the number that is less than `2 * modulus` if it is not less
+    /// than the modulus.
+    fn reduce(&self, value: Limbs) -> Limbs {
+        if less_than(&value, &self.modulus) {
+            value
+        } else {
+            sub_limbs(&value, &self.modulus)
+        }
+    }
+}
+
+pub(crate) fn to_limbs(bytes: &[u8; 32]) -> Limbs {
+    let mut limbs = [0u64; 4];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks(8)) {
+        let mut limb_bytes = [0u8; 8];
+        limb_bytes.copy_from_slice(chunk);
+        *limb = u64::from_le_bytes(limb_bytes);
+    }
+    limbs
+}
+
+pub(crate) fn to_bytes(limbs: &Limbs) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    for (chunk, limb) in bytes.chunks_mut(8).zip(limbs.iter()) {
+        chunk.copy_from_slice(&limb.to_le_bytes());
+    }
+    bytes
+}
+
+pub(crate) fn less_than(a: &Limbs, b: &Limbs) -> bool {
+    a.iter().rev().lt(b.iter().rev())
+}
+
+/// `a + b * c + carry` as the low and high 64 bits.
+fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let result = a as u128 + b as u128 * c as u128 + carry as u128;
+    (result as u64, (result >> 64) as u64)
+}
+
+/// `a - b` for `a >= b`.
+fn sub_limbs(a: &Limbs, b: &Limbs) -> Limbs {
+    let mut result = [0u64; 4];
+    let mut borrow = false;
+    for ((r, a), b) in result.iter_mut().zip(a.iter()).zip(b.iter()) {
+        let (diff, borrow1) = a.overflowing_sub(*b);
+        let (diff, borrow2) = diff.overflowing_sub(borrow as u64);
+        *r = diff;
+        borrow = borrow1 || borrow2;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn one() -> [u8; 32] {
+        let mut result = [0u8; 32];
+        result[0] = 1;
+        result
+    }
+
+    #[test]
+    fn test_field_arithmetic() {
+        for field in &[SCALAR_FIELD, BASE_FIELD] {
+            let mut max = to_bytes(&field.modulus);
+            assert!(field.from_le_bytes(&max).is_none());
+            max[0] -= 1;
+            let minus_one = field.from_le_bytes(&max).unwrap();
+            let one = field.from_le_bytes(&one()).unwrap();
+            assert_eq!(field.to_le_bytes(&minus_one), max);
+            // `(-1) * (-1) = 1`, `-1 + 1 = 0` and `0 - 1 = -1`.
+            assert_eq!(field.mul(&minus_one, &minus_one), one);
+            assert_eq!(field.add(&minus_one, &one), [0; 4]);
+            assert_eq!(field.sub(&[0; 4], &one), minus_one);
+            assert_eq!(field.sub(&minus_one, &minus_one), [0; 4]);
+        }
+    }
+}
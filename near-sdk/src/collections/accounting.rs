@@ -0,0 +1,122 @@
+//! Balances deposited by the accounts, e.g. into a pool, stored on the trie.
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::collections::{IntoStorageKey, LookupMap};
+use crate::{env, AccountId, Balance, SdkError};
+
+const ERR_BALANCE_OVERFLOW: &[u8] = b"Balance overflow";
+
+/// Per-account balances backed by a `LookupMap<AccountId, Balance>`. Accounts without an entry
+/// have zero balance, and the entry is removed once the balance drops to zero.
+///
+/// ```
+/// # near_sdk::test_utils::test_env::setup();
+/// use near_sdk::collections::Accounting;
+///
+/// let mut deposits = Accounting::new(b"d".to_vec());
+/// let alice = "alice.near".to_string();
+/// deposits.credit(&alice, 100);
+/// deposits.debit(&alice, 30);
+/// assert_eq!(deposits.balance_of(&alice), 70);
+/// ```
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct Accounting {
+    balances: LookupMap<AccountId, Balance>,
+}
+
+impl Accounting {
+    /// Create new balances. Use `prefix` as a unique prefix for trie keys.
+    pub fn new<S>(prefix: S) -> Self
+    where
+        S: IntoStorageKey,
+    {
+        Self { balances: LookupMap::new(prefix) }
+    }
+
+    /// Returns the balance of the account, which is zero for unknown accounts.
+    pub fn balance_of(&self, account_id: &AccountId) -> Balance {
+        self.balances.get(account_id).unwrap_or(0)
+    }
+
+    /// Adds `amount` to the balance of the account and returns the new balance.
+    /// Panics if the balance overflows.
+    pub fn credit(&mut self, account_id: &AccountId, amount: Balance) -> Balance {
+        let balance = match self.balance_of(account_id).checked_add(amount) {
+            Some(balance) => balance,
+            None => env::panic(ERR_BALANCE_OVERFLOW),
+        };
+        self.set_balance(account_id, balance);
+        balance
+    }
+
+    /// Subtracts `amount` from the balance of the account and returns the new balance.
+    /// Panics with "Insufficient balance" if the balance is less than `amount`.
+    pub fn debit(&mut self, account_id: &AccountId, amount: Balance) -> Balance {
+        let balance = match self.balance_of(account_id).checked_sub(amount) {
+            Some(balance) => balance,
+            None => SdkError::InsufficientBalance.panic(),
+        };
+        self.set_balance(account_id, balance);
+        balance
+    }
+
+    fn set_balance(&mut self, account_id: &AccountId, balance: Balance) {
+        if balance == 0 {
+            self.balances.remove(account_id);
+        } else {
+            self.balances.insert(account_id, &balance);
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(test)]
+mod tests {
+    use crate::collections::Accounting;
+    use crate::test_utils::test_env;
+
+    #[test]
+    pub fn test_credit() {
+        test_env::setup();
+        let mut accounting = Accounting::new(b"a".to_vec());
+        assert_eq!(accounting.credit(&test_env::alice(), 10), 10);
+        assert_eq!(accounting.credit(&test_env::alice(), 5), 15);
+        assert_eq!(accounting.balance_of(&test_env::alice()), 15);
+    }
+
+    #[test]
+    pub fn test_debit() {
+        test_env::setup();
+        let mut accounting = Accounting::new(b"a".to_vec());
+        accounting.credit(&test_env::alice(), 10);
+        assert_eq!(accounting.debit(&test_env::alice(), 4), 6);
+        assert_eq!(accounting.debit(&test_env::alice(), 6), 0);
+        assert_eq!(accounting.balance_of(&test_env::alice()), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Insufficient balance")]
+    pub fn test_debit_underflow() {
+        test_env::setup();
+        let mut accounting = Accounting::new(b"a".to_vec());
+        accounting.credit(&test_env::alice(), 10);
+        accounting.debit(&test_env::alice(), 11);
+    }
+
+    #[test]
+    #[should_panic(expected = "Balance overflow")]
+    pub fn test_credit_overflow() {
+        test_env::setup();
+        let mut accounting = Accounting::new(b"a".to_vec());
+        accounting.credit(&test_env::alice(), u128::max_value());
+        accounting.credit(&test_env::alice(), 1);
+    }
+
+    #[test]
+    pub fn test_unknown_account() {
+        test_env::setup();
+        let mut accounting = Accounting::new(b"a".to_vec());
+        accounting.credit(&test_env::alice(), 10);
+        assert_eq!(accounting.balance_of(&test_env::bob()), 0);
+    }
+}
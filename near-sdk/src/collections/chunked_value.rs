@@ -0,0 +1,217 @@
+//! A large byte value, e.g. a blob of a few megabytes, that is stored in chunks under consecutive
+//! keys. `storage_read` returns the whole value at once, and the host can't read a part of a
+//! register, so a call that reads a large value from a single key holds it in memory twice: in
+//! the register and in the returned `Vec<u8>`. The chunks are read one at a time into a reused
+//! buffer instead, so the memory used by the reader is bounded by the chunk length.
+use std::io::Read;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::collections::{append_slice, IntoStorageKey};
+use crate::env;
+
+const ERR_INCONSISTENT_STATE: &[u8] = b"The collection is an inconsistent state. Did previous smart contract execution terminate unexpectedly?";
+const ERR_ZERO_CHUNK_LEN: &[u8] = b"The length of the chunks can't be zero";
+
+/// The register that the chunks are read into, picked apart from the registers of `env`.
+const CHUNK_REGISTER: u64 = std::u64::MAX - 2;
+
+/// A byte value that is stored in chunks of `chunk_len` bytes. Uses the following map:
+/// index -> chunk, where the indices are `0..chunks()` and only the last chunk can be shorter.
+///
+/// ```
+/// # near_sdk::test_utils::test_env::setup();
+/// use std::io::Read;
+/// use near_sdk::collections::ChunkedValue;
+///
+/// let mut blob = ChunkedValue::new(b"b".to_vec(), 4);
+/// blob.set(b"0123456789");
+/// assert_eq!(blob.chunks(), 3);
+///
+/// let mut buf = [0u8; 5];
+/// let mut reader = blob.reader();
+/// reader.read_exact(&mut buf).unwrap();
+/// assert_eq!(&buf, b"01234");
+/// ```
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct ChunkedValue {
+    len: u64,
+    chunk_len: u32,
+    prefix: Vec<u8>,
+}
+
+impl ChunkedValue {
+    /// Create a new empty value. Use `prefix` as a unique prefix for trie keys.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_len` is zero.
+    pub fn new<S>(prefix: S, chunk_len: u32) -> Self
+    where
+        S: IntoStorageKey,
+    {
+        if chunk_len == 0 {
+            env::panic(ERR_ZERO_CHUNK_LEN)
+        }
+        Self { len: 0, chunk_len, prefix: prefix.into_storage_key() }
+    }
+
+    /// Returns the length of the value in bytes.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns `true` if the value is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the maximum length of a chunk.
+    pub fn chunk_len(&self) -> u32 {
+        self.chunk_len
+    }
+
+    /// Returns the number of the stored chunks.
+    pub fn chunks(&self) -> u64 {
+        (self.len + self.chunk_len as u64 - 1) / self.chunk_len as u64
+    }
+
+    fn index_to_lookup_key(&self, index: u64) -> Vec<u8> {
+        append_slice(&self.prefix, &index.to_le_bytes()[..])
+    }
+
+    /// Replaces the value, writing it chunk by chunk.
+    pub fn set(&mut self, value: &[u8]) {
+        self.clear();
+        for (index, chunk) in value.chunks(self.chunk_len as usize).enumerate() {
+            env::storage_write(&self.index_to_lookup_key(index as u64), chunk);
+        }
+        self.len = value.len() as u64;
+    }
+
+    /// Removes the chunks from the storage.
+    pub fn clear(&mut self) {
+        for index in 0..self.chunks() {
+            env::storage_remove(&self.index_to_lookup_key(index));
+        }
+        self.len = 0;
+    }
+
+    /// Reads the chunk with the given index into `buf`, reusing its allocation. Returns `false`,
+    /// leaving `buf` untouched, if the index is not less than `chunks()`.
+    pub fn read_chunk_into(&self, index: u64, buf: &mut Vec<u8>) -> bool {
+        if index >= self.chunks() {
+            return false;
+        }
+        if !env::storage_read_to_register(&self.index_to_lookup_key(index), CHUNK_REGISTER) {
+            env::panic(ERR_INCONSISTENT_STATE)
+        }
+        env::read_register_into(CHUNK_REGISTER, buf)
+    }
+
+    /// Returns a reader of the value that holds one chunk at a time.
+    pub fn reader(&self) -> ChunkedReader<'_> {
+        ChunkedReader { value: self, next_chunk: 0, buf: Vec::new(), offset: 0 }
+    }
+
+    /// Reads the whole value at once.
+    pub fn to_vec(&self) -> Vec<u8> {
+        let mut result = Vec::with_capacity(self.len as usize);
+        // Reading from the storage can only fail by panicking.
+        self.reader().read_to_end(&mut result).unwrap();
+        result
+    }
+}
+
+/// A reader of a `ChunkedValue`, created with `ChunkedValue::reader`.
+pub struct ChunkedReader<'a> {
+    value: &'a ChunkedValue,
+    next_chunk: u64,
+    buf: Vec<u8>,
+    offset: usize,
+}
+
+impl<'a> Read for ChunkedReader<'a> {
+    fn read(&mut self, out: &mut [u8]) -> std::io::Result<usize> {
+        if self.offset == self.buf.len() {
+            if !self.value.read_chunk_into(self.next_chunk, &mut self.buf) {
+                return Ok(0);
+            }
+            self.next_chunk += 1;
+            self.offset = 0;
+        }
+        let len = std::cmp::min(out.len(), self.buf.len() - self.offset);
+        out[..len].copy_from_slice(&self.buf[self.offset..self.offset + len]);
+        self.offset += len;
+        Ok(len)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use borsh::{BorshDeserialize, BorshSerialize};
+
+    use crate::collections::ChunkedValue;
+    use crate::env;
+    use crate::test_utils::test_env;
+
+    #[test]
+    fn test_set_and_read() {
+        test_env::setup();
+        let mut blob = ChunkedValue::new(b"b".to_vec(), 3);
+        assert!(blob.is_empty());
+        assert_eq!(blob.to_vec(), Vec::<u8>::new());
+        let value: Vec<u8> = (0..10).collect();
+        blob.set(&value);
+        assert_eq!((blob.len(), blob.chunks()), (10, 4));
+        assert_eq!(blob.to_vec(), value);
+
+        let mut buf = Vec::new();
+        assert!(blob.read_chunk_into(3, &mut buf));
+        assert_eq!(buf, vec![9]);
+        assert!(!blob.read_chunk_into(4, &mut buf));
+        assert_eq!(buf, vec![9]);
+
+        // The reader serves reads that cross the chunks.
+        let mut reader = blob.reader();
+        let mut out = [0u8; 4];
+        reader.read_exact(&mut out).unwrap();
+        assert_eq!(out, [0, 1, 2, 3]);
+        let mut rest = Vec::new();
+        reader.read_to_end(&mut rest).unwrap();
+        assert_eq!(rest, value[4..].to_vec());
+    }
+
+    #[test]
+    fn test_set_removes_old_chunks() {
+        test_env::setup();
+        let mut blob = ChunkedValue::new(b"b".to_vec(), 2);
+        blob.set(&[1; 7]);
+        blob.set(&[2; 3]);
+        assert_eq!(blob.to_vec(), vec![2; 3]);
+        assert!(!env::storage_has_key(&blob.index_to_lookup_key(2)));
+        assert!(!env::storage_has_key(&blob.index_to_lookup_key(3)));
+        blob.clear();
+        assert!(blob.is_empty());
+        assert!(!env::storage_has_key(&blob.index_to_lookup_key(0)));
+    }
+
+    #[test]
+    fn test_state_holds_only_length() {
+        test_env::setup();
+        let mut blob = ChunkedValue::new(b"b".to_vec(), 2);
+        blob.set(&[5; 5]);
+        let blob = ChunkedValue::try_from_slice(&blob.try_to_vec().unwrap()).unwrap();
+        assert_eq!(blob.to_vec(), vec![5; 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "The length of the chunks can't be zero")]
+    fn test_zero_chunk_len() {
+        test_env::setup();
+        ChunkedValue::new(b"b".to_vec(), 0);
+    }
+}
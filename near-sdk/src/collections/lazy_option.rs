@@ -0,0 +1,164 @@
+//! A persistent optional value. Unlike `Option<T>` in the contract state, which is read and
+//! deserialized together with the state on every call, the value is read from the storage only
+//! when it is accessed, which saves gas for large values that are used by few calls.
+use std::marker::PhantomData;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::collections::IntoStorageKey;
+use crate::env;
+
+const ERR_VALUE_SERIALIZATION: &[u8] = b"Cannot serialize value with Borsh";
+const ERR_VALUE_DESERIALIZATION: &[u8] = b"Cannot deserialize value with Borsh";
+
+/// An optional value that is stored under its own key on the trie. The contract state only holds
+/// the storage key, so deserializing the state doesn't read the value.
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct LazyOption<T> {
+    storage_key: Vec<u8>,
+    #[borsh_skip]
+    el: PhantomData<T>,
+}
+
+impl<T> LazyOption<T> {
+    /// Returns `true` if the value is present in the storage.
+    pub fn is_some(&self) -> bool {
+        env::storage_has_key(&self.storage_key)
+    }
+
+    /// Returns `true` if the value is not present in the storage.
+    pub fn is_none(&self) -> bool {
+        !self.is_some()
+    }
+
+    /// Reads the serialized value from the storage.
+    fn get_raw(&self) -> Option<Vec<u8>> {
+        env::storage_read(&self.storage_key)
+    }
+
+    /// Removes the value from the storage, returning the serialized value if it was present.
+    fn take_raw(&mut self) -> Option<Vec<u8>> {
+        if env::storage_remove(&self.storage_key) {
+            Some(env::storage_get_evicted().unwrap())
+        } else {
+            None
+        }
+    }
+
+    /// Writes the serialized value to the storage, returning the serialized previous value if it
+    /// was present.
+    fn replace_raw(&mut self, value_raw: &[u8]) -> Option<Vec<u8>> {
+        env::storage_write_evicted(&self.storage_key, value_raw)
+    }
+}
+
+impl<T> LazyOption<T>
+where
+    T: BorshSerialize + BorshDeserialize,
+{
+    /// Create a new optional value. Use `storage_key` as the unique key of the value on the trie.
+    /// Writes the `value` if it is `Some`, and keeps the stored value otherwise.
+    pub fn new<S>(storage_key: S, value: Option<&T>) -> Self
+    where
+        S: IntoStorageKey,
+    {
+        let mut this = Self { storage_key: storage_key.into_storage_key(), el: PhantomData };
+        if let Some(value) = value {
+            this.set(value);
+        }
+        this
+    }
+
+    fn serialize_value(value: &T) -> Vec<u8> {
+        match value.try_to_vec() {
+            Ok(x) => x,
+            Err(_) => env::panic(ERR_VALUE_SERIALIZATION),
+        }
+    }
+
+    fn deserialize_value(raw_value: &[u8]) -> T {
+        match T::try_from_slice(&raw_value) {
+            Ok(x) => x,
+            Err(_) => env::panic(ERR_VALUE_DESERIALIZATION),
+        }
+    }
+
+    /// Reads and deserializes the value, or returns `None` if it is not present.
+    pub fn get(&self) -> Option<T> {
+        self.get_raw().map(|value_raw| Self::deserialize_value(&value_raw))
+    }
+
+    /// Writes the value. Returns `true` if there was a value before.
+    pub fn set(&mut self, value: &T) -> bool {
+        self.replace_raw(&Self::serialize_value(value)).is_some()
+    }
+
+    /// Writes the value, returning the previous value if it was present.
+    pub fn replace(&mut self, value: &T) -> Option<T> {
+        self.replace_raw(&Self::serialize_value(value))
+            .map(|value_raw| Self::deserialize_value(&value_raw))
+    }
+
+    /// Removes the value, returning it if it was present.
+    pub fn take(&mut self) -> Option<T> {
+        self.take_raw().map(|value_raw| Self::deserialize_value(&value_raw))
+    }
+
+    /// Removes the value. Returns `true` if there was a value.
+    pub fn remove(&mut self) -> bool {
+        self.take_raw().is_some()
+    }
+}
+
+impl<T> std::fmt::Debug for LazyOption<T>
+where
+    T: std::fmt::Debug + BorshSerialize + BorshDeserialize,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LazyOption")
+            .field("storage_key", &self.storage_key)
+            .field("value", &self.get())
+            .finish()
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(test)]
+mod tests {
+    use crate::collections::LazyOption;
+    use crate::test_utils::test_env;
+    use borsh::{BorshDeserialize, BorshSerialize};
+
+    #[test]
+    pub fn test_set_and_take() {
+        test_env::setup();
+        let mut value = LazyOption::new(b"o".to_vec(), None);
+        assert!(value.is_none());
+        assert_eq!(value.get(), None);
+        assert!(!value.set(&"first".to_string()));
+        assert!(value.is_some());
+        assert_eq!(value.replace(&"second".to_string()), Some("first".to_string()));
+        assert_eq!(value.get(), Some("second".to_string()));
+        assert_eq!(value.take(), Some("second".to_string()));
+        assert!(value.is_none());
+        assert!(!value.remove());
+    }
+
+    #[test]
+    pub fn test_new_keeps_stored_value() {
+        test_env::setup();
+        LazyOption::new(b"o".to_vec(), Some(&5u64));
+        let value = LazyOption::<u64>::new(b"o".to_vec(), None);
+        assert_eq!(value.get(), Some(5));
+    }
+
+    #[test]
+    pub fn test_state_holds_only_key() {
+        test_env::setup();
+        let value = LazyOption::new(b"o".to_vec(), Some(&vec![7u8; 1000]));
+        let state = value.try_to_vec().unwrap();
+        assert_eq!(state, vec![1, 0, 0, 0, b'o']);
+        let value = LazyOption::<Vec<u8>>::try_from_slice(&state).unwrap();
+        assert_eq!(value.get(), Some(vec![7u8; 1000]));
+    }
+}
@@ -5,7 +5,7 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use std::ops::Bound;
 
 use crate::collections::UnorderedMap;
-use crate::collections::{append, Vector};
+use crate::collections::{append, IntoStorageKey, Vector};
 
 /// TreeMap based on AVL-tree
 ///
@@ -46,7 +46,11 @@ where
     K: Ord + Clone + BorshSerialize + BorshDeserialize,
     V: BorshSerialize + BorshDeserialize,
 {
-    pub fn new(id: Vec<u8>) -> Self {
+    pub fn new<S>(id: S) -> Self
+    where
+        S: IntoStorageKey,
+    {
+        let id = id.into_storage_key();
         Self {
             root: 0,
             val: UnorderedMap::new(append(&id, b'v')),
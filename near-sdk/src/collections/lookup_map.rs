@@ -5,7 +5,7 @@ use std::marker::PhantomData;
 
 use borsh::{BorshDeserialize, BorshSerialize};
 
-use crate::collections::append_slice;
+use crate::collections::{append_slice, BorshKey, IntoStorageKey, ToKey};
 use crate::env;
 
 const ERR_KEY_SERIALIZATION: &[u8] = b"Cannot serialize key with Borsh";
@@ -14,20 +14,37 @@ const ERR_VALUE_SERIALIZATION: &[u8] = b"Cannot serialize value with Borsh";
 
 /// An non-iterable implementation of a map that stores its content directly on the trie.
 #[derive(BorshSerialize, BorshDeserialize)]
-pub struct LookupMap<K, V> {
+pub struct LookupMap<K, V, H = BorshKey> {
     key_prefix: Vec<u8>,
     #[borsh_skip]
-    el: PhantomData<(K, V)>,
+    el: PhantomData<(K, V, H)>,
 }
 
 impl<K, V> LookupMap<K, V> {
     /// Create a new map. Use `key_prefix` as a unique prefix for keys.
-    pub fn new(key_prefix: Vec<u8>) -> Self {
-        Self { key_prefix, el: PhantomData }
+    pub fn new<S>(key_prefix: S) -> Self
+    where
+        S: IntoStorageKey,
+    {
+        Self::new_with_to_key(key_prefix)
+    }
+}
+
+impl<K, V, H> LookupMap<K, V, H>
+where
+    H: ToKey,
+{
+    /// Create a new map that computes the storage keys of the entries with `H`.
+    /// Use `key_prefix` as a unique prefix for keys.
+    pub fn new_with_to_key<S>(key_prefix: S) -> Self
+    where
+        S: IntoStorageKey,
+    {
+        Self { key_prefix: key_prefix.into_storage_key(), el: PhantomData }
     }
 
     fn raw_key_to_storage_key(&self, raw_key: &[u8]) -> Vec<u8> {
-        append_slice(&self.key_prefix, raw_key)
+        append_slice(&self.key_prefix, &H::to_key(raw_key))
     }
 
     /// Returns `true` if the serialized key is present in the map.
@@ -48,11 +65,7 @@ impl<K, V> LookupMap<K, V> {
     /// the implementation.
     pub fn insert_raw(&mut self, key_raw: &[u8], value_raw: &[u8]) -> Option<Vec<u8>> {
         let storage_key = self.raw_key_to_storage_key(key_raw);
-        if env::storage_write(&storage_key, value_raw) {
-            Some(env::storage_get_evicted().unwrap())
-        } else {
-            None
-        }
+        env::storage_write_evicted(&storage_key, value_raw)
     }
 
     /// Removes a serialized key from the map, returning the serialized value at the key if the key
@@ -67,10 +80,11 @@ impl<K, V> LookupMap<K, V> {
     }
 }
 
-impl<K, V> LookupMap<K, V>
+impl<K, V, H> LookupMap<K, V, H>
 where
     K: BorshSerialize,
     V: BorshSerialize + BorshDeserialize,
+    H: ToKey,
 {
     fn serialize_key(key: &K) -> Vec<u8> {
         match key.try_to_vec() {
@@ -282,4 +296,37 @@ mod tests {
             assert_eq!(map.get(&key).unwrap(), value);
         }
     }
+
+    #[test]
+    pub fn test_custom_to_key() {
+        use crate::collections::ToKey;
+        use borsh::BorshSerialize;
+
+        struct Keccak256Key;
+
+        impl ToKey for Keccak256Key {
+            fn to_key(key_raw: &[u8]) -> Vec<u8> {
+                crate::env::keccak256(key_raw)
+            }
+        }
+
+        test_env::setup();
+        let mut map: LookupMap<String, u64, Keccak256Key> =
+            LookupMap::new_with_to_key(b"m".to_vec());
+        let long_key = "a".repeat(1000);
+        map.insert(&long_key, &1);
+        map.insert(&"b".to_string(), &2);
+        assert_eq!(map.get(&long_key), Some(1));
+        assert_eq!(map.get(&"b".to_string()), Some(2));
+        assert!(!map.contains_key(&"c".to_string()));
+
+        let storage_key =
+            [&b"m"[..], &crate::env::keccak256(&long_key.try_to_vec().unwrap())].concat();
+        assert_eq!(storage_key.len(), 33);
+        assert!(crate::env::storage_has_key(&storage_key));
+
+        assert_eq!(map.remove(&long_key), Some(1));
+        assert!(!map.contains_key(&long_key));
+        assert!(!crate::env::storage_has_key(&storage_key));
+    }
 }
@@ -5,7 +5,7 @@ use std::marker::PhantomData;
 
 use borsh::{BorshDeserialize, BorshSerialize};
 
-use crate::collections::append_slice;
+use crate::collections::{append_slice, IntoStorageKey};
 use crate::env;
 
 const ERR_ELEMENT_SERIALIZATION: &[u8] = b"Cannot serialize element with Borsh";
@@ -20,8 +20,11 @@ pub struct LookupSet<T> {
 
 impl<T> LookupSet<T> {
     /// Create a new map. Use `element_prefix` as a unique prefix for trie keys.
-    pub fn new(element_prefix: Vec<u8>) -> Self {
-        Self { element_prefix, el: PhantomData }
+    pub fn new<S>(element_prefix: S) -> Self
+    where
+        S: IntoStorageKey,
+    {
+        Self { element_prefix: element_prefix.into_storage_key(), el: PhantomData }
     }
 
     fn raw_element_to_storage_key(&self, element_raw: &[u8]) -> Vec<u8> {
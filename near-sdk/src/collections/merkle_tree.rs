@@ -0,0 +1,362 @@
+//! An incremental Merkle tree of a fixed depth that stores its leaves and internal nodes on the
+//! trie, e.g. the commitments of a shielded pool. Leaves can only be appended, and the missing
+//! leaves are zeros, i.e. `[0; 32]`. `append_commitment` also rejects the leaves that were
+//! already appended, so that a re-submitted commitment is not stored twice.
+use std::marker::PhantomData;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{Deserialize, Serialize};
+
+use crate::collections::{append_slice, IntoStorageKey, LookupMap};
+use crate::env;
+use crate::json_types::{Base64VecU8, U64};
+
+const ERR_INCONSISTENT_STATE: &[u8] = b"The collection is an inconsistent state. Did previous smart contract execution terminate unexpectedly?";
+const ERR_TREE_FULL: &[u8] = b"The Merkle tree is full";
+const ERR_DUPLICATE_COMMITMENT: &[u8] = b"The commitment has already been appended";
+
+/// The byte after the prefix of the keys of the commitment indices, which is not a level, as the
+/// depth is less than 64.
+const COMMITMENTS_KEY: u8 = 0xff;
+
+/// Computes the parent node of the Merkle tree from its children. `MerkleTree` uses
+/// `Sha256Hasher` by default, and another hash, e.g. the one the circuits of the contract use, can
+/// be set with `new_with_hasher`.
+pub trait MerkleHasher {
+    fn hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32];
+}
+
+/// Uses the sha256 hash of the concatenation of the children.
+pub struct Sha256Hasher;
+
+impl MerkleHasher for Sha256Hasher {
+    fn hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        let mut result = [0u8; 32];
+        result.copy_from_slice(&env::sha256(&[&left[..], &right[..]].concat()));
+        result
+    }
+}
+
+/// Uses the Poseidon hash of the children, which matches the Merkle trees of the circuits. The
+/// leaves have to be elements of the alt_bn128 scalar field. Only available with the `poseidon`
+/// feature.
+#[cfg(feature = "poseidon")]
+pub struct PoseidonHasher;
+
+#[cfg(feature = "poseidon")]
+impl MerkleHasher for PoseidonHasher {
+    fn hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        env::poseidon(&[*left, *right])
+    }
+}
+
+/// The root of a `MerkleTree` with the number of its leaves, which is the version of the root,
+/// as the root only changes when a leaf is appended. Light clients compare it with the root that
+/// their inclusion proofs lead to. It is serialized in JSON as
+/// `{"root": "<base64>", "version": "<decimal>"}`, and in Borsh as the length-prefixed bytes of
+/// the root followed by the version.
+#[derive(Debug, Clone, PartialEq, BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
+pub struct StateRoot {
+    pub root: Base64VecU8,
+    pub version: U64,
+}
+
+/// Merkle tree with `2^depth` leaves.
+/// Uses the following map: (level, index) -> node, where the leaves are on level `0` and the root
+/// is on level `depth`. The leaves appended with `append_commitment` are also in a map:
+/// leaf -> index.
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct MerkleTree<H = Sha256Hasher> {
+    len: u64,
+    depth: u8,
+    prefix: Vec<u8>,
+    #[borsh_skip]
+    hasher: PhantomData<H>,
+}
+
+impl MerkleTree {
+    /// Create a new empty tree of the given depth. Use `prefix` as a unique prefix for trie keys.
+    pub fn new<S>(prefix: S, depth: u8) -> Self
+    where
+        S: IntoStorageKey,
+    {
+        Self::new_with_hasher(prefix, depth)
+    }
+}
+
+impl<H> MerkleTree<H>
+where
+    H: MerkleHasher,
+{
+    /// Create a new empty tree of the given depth that hashes the nodes with `H`.
+    /// Use `prefix` as a unique prefix for trie keys.
+    pub fn new_with_hasher<S>(prefix: S, depth: u8) -> Self
+    where
+        S: IntoStorageKey,
+    {
+        assert!(depth < 64, "The depth of the Merkle tree must be less than 64");
+        Self { len: 0, depth, prefix: prefix.into_storage_key(), hasher: PhantomData }
+    }
+
+    /// Returns the number of appended leaves.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns `true` if no leaves were appended.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the depth of the tree, i.e. the number of levels above the leaves.
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    fn node_key(&self, level: u8, index: u64) -> Vec<u8> {
+        append_slice(&self.prefix, &[&[level][..], &index.to_le_bytes()[..]].concat())
+    }
+
+    fn read_node(&self, level: u8, index: u64) -> [u8; 32] {
+        match env::storage_read(&self.node_key(level, index)) {
+            Some(node) if node.len() == 32 => {
+                let mut result = [0u8; 32];
+                result.copy_from_slice(&node);
+                result
+            }
+            _ => env::panic(ERR_INCONSISTENT_STATE),
+        }
+    }
+
+    /// Returns the leaf by index or `None` if it was not appended.
+    pub fn leaf(&self, index: u64) -> Option<[u8; 32]> {
+        if index >= self.len {
+            return None;
+        }
+        Some(self.read_node(0, index))
+    }
+
+    /// Returns the root of the tree. The root of the empty tree is the root of the tree of zeros.
+    pub fn root(&self) -> [u8; 32] {
+        if self.is_empty() {
+            let mut zero = [0u8; 32];
+            for _ in 0..self.depth {
+                zero = H::hash(&zero, &zero);
+            }
+            zero
+        } else {
+            self.read_node(self.depth, 0)
+        }
+    }
+
+    fn commitments(&self) -> LookupMap<[u8; 32], u64> {
+        LookupMap::new(append_slice(&self.prefix, &[COMMITMENTS_KEY]))
+    }
+
+    /// Returns the index of the leaf appended with `append_commitment`, or `None` if it was not.
+    pub fn commitment_index(&self, commitment: &[u8; 32]) -> Option<u64> {
+        self.commitments().get(commitment)
+    }
+
+    /// Returns the root with its version. The `state_root` view method of
+    /// a `#[near_bindgen(state_root = field)]` contract returns it for the tree in `field`.
+    pub fn state_root(&self) -> StateRoot {
+        StateRoot { root: Base64VecU8(self.root().to_vec()), version: U64(self.len) }
+    }
+
+    /// Appends the commitment and returns its index, which is the number of the leaves before it.
+    /// Panics before changing the tree if the commitment was already appended with this method,
+    /// so the tree should only be appended through this method to reject all duplicates.
+    pub fn append_commitment(&mut self, commitment: &[u8; 32]) -> u64 {
+        let index = self.len;
+        let mut commitments = self.commitments();
+        // Checked before the insert, which would overwrite the stored index.
+        if commitments.contains_key(commitment) {
+            env::panic(ERR_DUPLICATE_COMMITMENT);
+        }
+        commitments.insert(commitment, &index);
+        self.append(commitment);
+        index
+    }
+
+    /// Appends the leaf and returns the new root. Updates the `depth` nodes on the path to the
+    /// root, the siblings of which are either stored or zero subtrees. Panics if the tree is full.
+    pub fn append(&mut self, leaf: &[u8; 32]) -> [u8; 32] {
+        if self.len == 1u64 << self.depth {
+            env::panic(ERR_TREE_FULL);
+        }
+        let mut index = self.len;
+        let mut node = *leaf;
+        // The root of the subtree of zeros on the current level.
+        let mut zero = [0u8; 32];
+        env::storage_write(&self.node_key(0, index), &node);
+        for level in 0..self.depth {
+            node = if index % 2 == 0 {
+                H::hash(&node, &zero)
+            } else {
+                H::hash(&self.read_node(level, index - 1), &node)
+            };
+            zero = H::hash(&zero, &zero);
+            index /= 2;
+            env::storage_write(&self.node_key(level + 1, index), &node);
+        }
+        self.len += 1;
+        node
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(test)]
+mod tests {
+    use crate::collections::merkle_tree::{MerkleHasher, Sha256Hasher, StateRoot};
+    use crate::collections::MerkleTree;
+    use crate::test_utils::test_env;
+    use borsh::BorshSerialize;
+
+    /// Computes the root from all the leaves of the tree.
+    fn reference_root(leaves: &[[u8; 32]], depth: u8) -> [u8; 32] {
+        let mut level = leaves.to_vec();
+        level.resize(1 << depth, [0; 32]);
+        while level.len() > 1 {
+            level = level.chunks(2).map(|pair| Sha256Hasher::hash(&pair[0], &pair[1])).collect();
+        }
+        level[0]
+    }
+
+    #[test]
+    pub fn test_append() {
+        test_env::setup();
+        let mut tree = MerkleTree::new(b"m".to_vec(), 4);
+        assert_eq!(tree.root(), reference_root(&[], 4));
+        let mut leaves = vec![];
+        for i in 0..16u8 {
+            let leaf = [i + 1; 32];
+            leaves.push(leaf);
+            let root = tree.append(&leaf);
+            assert_eq!(root, reference_root(&leaves, 4));
+            assert_eq!(tree.root(), root);
+            assert_eq!(tree.len(), i as u64 + 1);
+        }
+        for (i, leaf) in leaves.iter().enumerate() {
+            assert_eq!(tree.leaf(i as u64), Some(*leaf));
+        }
+        assert_eq!(tree.leaf(16), None);
+    }
+
+    #[test]
+    pub fn test_single_leaf() {
+        test_env::setup();
+        let mut tree = MerkleTree::new(b"m".to_vec(), 0);
+        assert_eq!(tree.root(), [0; 32]);
+        assert_eq!(tree.append(&[7; 32]), [7; 32]);
+    }
+
+    #[test]
+    #[should_panic(expected = "The Merkle tree is full")]
+    pub fn test_append_full() {
+        test_env::setup();
+        let mut tree = MerkleTree::new(b"m".to_vec(), 2);
+        for i in 0..5u8 {
+            tree.append(&[i; 32]);
+        }
+    }
+
+    #[test]
+    pub fn test_custom_hasher() {
+        struct XorHasher;
+
+        impl MerkleHasher for XorHasher {
+            fn hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+                let mut result = *left;
+                for (byte, right) in result.iter_mut().zip(right.iter()) {
+                    *byte ^= right.rotate_left(1);
+                }
+                result
+            }
+        }
+
+        test_env::setup();
+        let mut tree: MerkleTree<XorHasher> = MerkleTree::new_with_hasher(b"m".to_vec(), 2);
+        tree.append(&[1; 32]);
+        // `(1 ^ rol(2)) ^ rol(0)`, where `0` is the root of the empty right subtree.
+        assert_eq!(tree.append(&[2; 32]), [5; 32]);
+    }
+
+    #[test]
+    pub fn test_append_commitment() {
+        test_env::setup();
+        let mut tree = MerkleTree::new(b"m".to_vec(), 4);
+        for i in 0..3u8 {
+            assert_eq!(tree.append_commitment(&[i + 1; 32]), i as u64);
+        }
+        assert_eq!(tree.commitment_index(&[2; 32]), Some(1));
+        assert_eq!(tree.commitment_index(&[4; 32]), None);
+        assert_eq!(tree.root(), reference_root(&[[1; 32], [2; 32], [3; 32]], 4));
+    }
+
+    #[test]
+    pub fn test_append_duplicate_commitment() {
+        test_env::setup();
+        let mut tree = MerkleTree::new(b"m".to_vec(), 4);
+        tree.append_commitment(&[1; 32]);
+        tree.append_commitment(&[2; 32]);
+        let root = tree.root();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            tree.append_commitment(&[1; 32]);
+        }));
+        let payload = result.unwrap_err();
+        let message = payload.downcast_ref::<String>().unwrap();
+        assert!(message.contains("The commitment has already been appended"), "{}", message);
+        // Neither the index counter nor the tree changed.
+        assert_eq!(tree.len(), 2);
+        assert_eq!(tree.root(), root);
+        assert_eq!(tree.commitment_index(&[1; 32]), Some(0));
+        assert_eq!(tree.append_commitment(&[3; 32]), 2);
+    }
+
+    #[test]
+    pub fn test_state_root() {
+        test_env::setup();
+        let mut tree = MerkleTree::new(b"m".to_vec(), 4);
+        let empty = tree.state_root();
+        assert_eq!(empty.root.0, reference_root(&[], 4).to_vec());
+        assert_eq!(empty.version.0, 0);
+        let mut leaves = vec![];
+        for i in 0..5u8 {
+            leaves.push([i + 1; 32]);
+            tree.append_commitment(&[i + 1; 32]);
+        }
+        let state_root = tree.state_root();
+        assert_eq!(state_root.root.0, tree.root().to_vec());
+        assert_eq!(state_root.root.0, reference_root(&leaves, 4).to_vec());
+        assert_eq!(state_root.version.0, 5);
+
+        tree.append_commitment(&[6; 32]);
+        let next = tree.state_root();
+        assert_ne!(next.root, state_root.root);
+        assert_eq!(next.version.0, 6);
+
+        let json = serde_json::to_string(&next).unwrap();
+        assert_eq!(
+            json,
+            format!(r#"{{"root":{},"version":"6"}}"#, serde_json::to_string(&next.root).unwrap())
+        );
+        assert_eq!(serde_json::from_str::<StateRoot>(&json).unwrap(), next);
+        let mut borsh = tree.root().to_vec();
+        borsh.extend(&6u64.to_le_bytes());
+        assert_eq!(next.try_to_vec().unwrap()[4..], borsh[..]);
+    }
+
+    #[cfg(feature = "poseidon")]
+    #[test]
+    pub fn test_poseidon_hasher() {
+        use crate::collections::PoseidonHasher;
+        use crate::env;
+
+        test_env::setup();
+        let mut one = [0u8; 32];
+        one[0] = 1;
+        let mut tree: MerkleTree<PoseidonHasher> = MerkleTree::new_with_hasher(b"m".to_vec(), 1);
+        assert_eq!(tree.append(&one), env::poseidon(&[one, [0; 32]]));
+    }
+}
@@ -34,6 +34,20 @@
 //!
 //! The efficiency of `LookupMap` comes at the cost, since it has fewer methods than `HashMap` and is not
 //! that seemlessly integrated with the rest of the Rust standard library.
+//!
+//! The storage can't be enumerated by a key prefix: the `storage_iter_*` host functions are
+//! deprecated by the protocol, because the cost of walking the trie can't be bounded, so the SDK
+//! doesn't expose them. That is why the iterable collections, like `UnorderedMap`, keep their keys
+//! or values in a `Vector` next to the lookup entries.
+
+mod accounting;
+pub use accounting::Accounting;
+
+mod chunked_value;
+pub use chunked_value::{ChunkedReader, ChunkedValue};
+
+mod lazy_option;
+pub use lazy_option::LazyOption;
 
 mod legacy_tree_map;
 pub use legacy_tree_map::LegacyTreeMap;
@@ -44,6 +58,17 @@ pub use lookup_map::LookupMap;
 mod lookup_set;
 pub use lookup_set::LookupSet;
 
+mod merkle_tree;
+#[cfg(feature = "poseidon")]
+pub use merkle_tree::PoseidonHasher;
+pub use merkle_tree::{MerkleHasher, MerkleTree, Sha256Hasher, StateRoot};
+
+mod nullifier_set;
+pub use nullifier_set::NullifierSet;
+
+mod queue;
+pub use queue::Queue;
+
 mod vector;
 pub use vector::Vector;
 
@@ -56,6 +81,9 @@ pub use unordered_set::UnorderedSet;
 mod tree_map;
 pub use tree_map::TreeMap;
 
+mod storage_key;
+pub use storage_key::{BorshIntoStorageKey, BorshKey, IntoStorageKey, Sha256Key, ToKey};
+
 pub const ERR_INCONSISTENT_STATE: &[u8] = b"The collection is an inconsistent state. Did previous smart contract execution terminate unexpectedly?";
 pub const ERR_ELEMENT_SERIALIZATION: &[u8] = b"Cannot serialize element with Borsh.";
 pub const ERR_ELEMENT_DESERIALIZATION: &[u8] = b"Cannot deserialize element with Borsh.";
@@ -0,0 +1,81 @@
+//! A persistent set of the nullifiers of spent notes, which guards the methods of shielded pools
+//! against double spending.
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::collections::{IntoStorageKey, LookupMap};
+use crate::env;
+
+const ERR_NULLIFIER_USED: &[u8] = b"The nullifier has already been used";
+
+/// A set of used nullifiers backed by a `LookupMap` from the nullifier to `()`.
+///
+/// ```
+/// # near_sdk::test_utils::test_env::setup();
+/// use near_sdk::collections::NullifierSet;
+///
+/// let mut nullifiers: NullifierSet<[u8; 32]> = NullifierSet::new(b"n".to_vec());
+/// nullifiers.insert_or_panic(&[1; 32]);
+/// assert!(nullifiers.contains(&[1; 32]));
+/// assert!(!nullifiers.contains(&[2; 32]));
+/// ```
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct NullifierSet<T> {
+    map: LookupMap<T, ()>,
+}
+
+impl<T> NullifierSet<T> {
+    /// Create a new set. Use `prefix` as a unique prefix for trie keys.
+    pub fn new<S>(prefix: S) -> Self
+    where
+        S: IntoStorageKey,
+    {
+        Self { map: LookupMap::new(prefix) }
+    }
+}
+
+impl<T> NullifierSet<T>
+where
+    T: BorshSerialize,
+{
+    /// Returns `true` if the nullifier has been used.
+    pub fn contains(&self, nullifier: &T) -> bool {
+        self.map.contains_key(nullifier)
+    }
+
+    /// Records the nullifier, panicking if it has already been used. The check and the write are a
+    /// single storage write, and the panic reverts every other change of the call, so a note can't
+    /// be spent twice even within one transaction.
+    pub fn insert_or_panic(&mut self, nullifier: &T) {
+        if self.map.insert(nullifier, &()).is_some() {
+            env::panic(ERR_NULLIFIER_USED);
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(test)]
+mod tests {
+    use crate::collections::NullifierSet;
+    use crate::test_utils::test_env;
+
+    #[test]
+    pub fn test_insert_fresh() {
+        test_env::setup();
+        let mut set: NullifierSet<[u8; 32]> = NullifierSet::new(b"n".to_vec());
+        for i in 0..10u8 {
+            assert!(!set.contains(&[i; 32]));
+            set.insert_or_panic(&[i; 32]);
+            assert!(set.contains(&[i; 32]));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "The nullifier has already been used")]
+    pub fn test_insert_repeated() {
+        test_env::setup();
+        let mut set: NullifierSet<[u8; 32]> = NullifierSet::new(b"n".to_vec());
+        set.insert_or_panic(&[1; 32]);
+        set.insert_or_panic(&[2; 32]);
+        set.insert_or_panic(&[1; 32]);
+    }
+}
@@ -0,0 +1,246 @@
+//! A FIFO queue implemented on a trie, e.g. for the withdrawals that a pool processes later. Both
+//! ends are `O(1)`: the elements are stored by index between the head and the tail counters.
+use std::marker::PhantomData;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::collections::{append_slice, IntoStorageKey};
+use crate::env;
+
+const ERR_INCONSISTENT_STATE: &[u8] = b"The collection is an inconsistent state. Did previous smart contract execution terminate unexpectedly?";
+const ERR_ELEMENT_DESERIALIZATION: &[u8] = b"Cannot deserialize element";
+const ERR_ELEMENT_SERIALIZATION: &[u8] = b"Cannot serialize element";
+const ERR_QUEUE_FULL: &[u8] = b"The queue is full";
+
+/// A queue that stores its content on the trie. Uses the following map: index -> element, where
+/// the indices of the elements are `head..tail`. The counters wrap around `u64::MAX`, so the head
+/// can't catch up with the tail and an index is only reused after its element has been popped.
+#[derive(BorshSerialize, BorshDeserialize)]
+#[cfg_attr(not(feature = "expensive-debug"), derive(Debug))]
+pub struct Queue<T> {
+    head: u64,
+    tail: u64,
+    prefix: Vec<u8>,
+    #[borsh_skip]
+    el: PhantomData<T>,
+}
+
+impl<T> Queue<T> {
+    /// Returns the number of elements in the queue.
+    pub fn len(&self) -> u64 {
+        self.tail.wrapping_sub(self.head)
+    }
+
+    /// Returns `true` if the queue contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.head == self.tail
+    }
+
+    /// Create new queue with zero elements. Use `id` as a unique identifier on the trie.
+    pub fn new<S>(id: S) -> Self
+    where
+        S: IntoStorageKey,
+    {
+        Self { head: 0, tail: 0, prefix: id.into_storage_key(), el: PhantomData }
+    }
+
+    fn index_to_lookup_key(&self, index: u64) -> Vec<u8> {
+        append_slice(&self.prefix, &index.to_le_bytes()[..])
+    }
+
+    /// Appends a serialized element to the back of the queue.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the queue already has `u64::MAX` elements.
+    pub fn push_back_raw(&mut self, raw_element: &[u8]) {
+        if self.len() == u64::max_value() {
+            env::panic(ERR_QUEUE_FULL)
+        }
+        let lookup_key = self.index_to_lookup_key(self.tail);
+        self.tail = self.tail.wrapping_add(1);
+        env::storage_write(&lookup_key, raw_element);
+    }
+
+    /// Removes the first element from the queue and returns it without deserializing, or `None`
+    /// if it is empty.
+    pub fn pop_front_raw(&mut self) -> Option<Vec<u8>> {
+        if self.is_empty() {
+            return None;
+        }
+        let lookup_key = self.index_to_lookup_key(self.head);
+        self.head = self.head.wrapping_add(1);
+        if env::storage_remove(&lookup_key) {
+            match env::storage_get_evicted() {
+                Some(x) => Some(x),
+                None => env::panic(ERR_INCONSISTENT_STATE),
+            }
+        } else {
+            env::panic(ERR_INCONSISTENT_STATE)
+        }
+    }
+
+    /// Returns the first serialized element without removing it, or `None` if the queue is empty.
+    pub fn peek_raw(&self) -> Option<Vec<u8>> {
+        if self.is_empty() {
+            return None;
+        }
+        match env::storage_read(&self.index_to_lookup_key(self.head)) {
+            Some(raw_element) => Some(raw_element),
+            None => env::panic(ERR_INCONSISTENT_STATE),
+        }
+    }
+}
+
+impl<T> Queue<T>
+where
+    T: BorshSerialize,
+{
+    fn serialize_element(element: &T) -> Vec<u8> {
+        match element.try_to_vec() {
+            Ok(x) => x,
+            Err(_) => env::panic(ERR_ELEMENT_SERIALIZATION),
+        }
+    }
+
+    /// Appends an element to the back of the queue.
+    pub fn push_back(&mut self, element: &T) {
+        self.push_back_raw(&Self::serialize_element(element));
+    }
+}
+
+impl<T> Queue<T>
+where
+    T: BorshDeserialize,
+{
+    fn deserialize_element(raw_element: &[u8]) -> T {
+        match T::try_from_slice(&raw_element) {
+            Ok(x) => x,
+            Err(_) => env::panic(ERR_ELEMENT_DESERIALIZATION),
+        }
+    }
+
+    /// Removes the first element from the queue and returns it, or `None` if it is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.pop_front_raw().map(|x| Self::deserialize_element(&x))
+    }
+
+    /// Returns the first element without removing it, or `None` if the queue is empty.
+    pub fn peek(&self) -> Option<T> {
+        self.peek_raw().map(|x| Self::deserialize_element(&x))
+    }
+}
+
+#[cfg(feature = "expensive-debug")]
+impl<T: std::fmt::Debug + BorshDeserialize> std::fmt::Debug for Queue<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let elements: Vec<T> = (0..self.len())
+            .map(|i| {
+                let lookup_key = self.index_to_lookup_key(self.head.wrapping_add(i));
+                match env::storage_read(&lookup_key) {
+                    Some(raw_element) => Self::deserialize_element(&raw_element),
+                    None => env::panic(ERR_INCONSISTENT_STATE),
+                }
+            })
+            .collect();
+        std::fmt::Debug::fmt(&elements, f)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use borsh::{BorshDeserialize, BorshSerialize};
+    use rand::{Rng, SeedableRng};
+
+    use crate::collections::Queue;
+    use crate::test_utils::test_env;
+
+    #[test]
+    fn test_fifo_order() {
+        test_env::setup();
+        let mut queue = Queue::new(b"q".to_vec());
+        for value in 0..10u64 {
+            queue.push_back(&value);
+        }
+        assert_eq!(queue.len(), 10);
+        assert_eq!(queue.peek(), Some(0));
+        for value in 0..10u64 {
+            assert_eq!(queue.pop_front(), Some(value));
+        }
+    }
+
+    #[test]
+    fn test_empty() {
+        test_env::setup();
+        let mut queue = Queue::<u64>::new(b"q".to_vec());
+        assert!(queue.is_empty());
+        assert_eq!(queue.pop_front(), None);
+        assert_eq!(queue.peek(), None);
+        queue.push_back(&1);
+        queue.push_back(&2);
+        assert_eq!(queue.pop_front(), Some(1));
+        assert_eq!(queue.pop_front(), Some(2));
+        assert!(queue.is_empty());
+        assert_eq!(queue.len(), 0);
+        assert_eq!(queue.pop_front(), None);
+        // The popped elements are removed from the storage.
+        assert!(!crate::env::storage_has_key(&queue.index_to_lookup_key(0)));
+        assert!(!crate::env::storage_has_key(&queue.index_to_lookup_key(1)));
+        // The indices are not reused after emptying.
+        queue.push_back(&3);
+        assert_eq!((queue.head, queue.tail), (2, 3));
+        assert_eq!(queue.peek(), Some(3));
+    }
+
+    #[test]
+    fn test_interleaved() {
+        test_env::setup();
+        let mut rng = rand_xorshift::XorShiftRng::seed_from_u64(1);
+        let mut queue = Queue::new(b"q".to_vec());
+        let mut baseline = VecDeque::new();
+        for _ in 0..500 {
+            if rng.gen::<bool>() {
+                let value = rng.gen::<u64>();
+                queue.push_back(&value);
+                baseline.push_back(value);
+            } else {
+                assert_eq!(queue.pop_front(), baseline.pop_front());
+            }
+            assert_eq!(queue.len(), baseline.len() as u64);
+            assert_eq!(queue.peek(), baseline.front().cloned());
+        }
+    }
+
+    #[test]
+    fn test_wraparound() {
+        test_env::setup();
+        let mut queue = Queue::new(b"q".to_vec());
+        queue.head = u64::max_value() - 1;
+        queue.tail = u64::max_value() - 1;
+        for value in 0..4u64 {
+            queue.push_back(&value);
+        }
+        assert_eq!(queue.tail, 2);
+        assert_eq!(queue.len(), 4);
+        // The state is restored with the counters.
+        let mut queue = Queue::<u64>::try_from_slice(&queue.try_to_vec().unwrap()).unwrap();
+        for value in 0..4u64 {
+            assert_eq!(queue.pop_front(), Some(value));
+        }
+        assert!(queue.is_empty());
+        assert_eq!(queue.head, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "The queue is full")]
+    fn test_full() {
+        test_env::setup();
+        let mut queue = Queue::new(b"q".to_vec());
+        queue.head = 1;
+        queue.tail = 0;
+        queue.push_back(&0u64);
+    }
+}
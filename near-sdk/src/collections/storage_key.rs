@@ -0,0 +1,151 @@
+use borsh::BorshSerialize;
+
+/// Converts the value into the prefix of the storage keys of a collection.
+/// Every collection of the contract needs a distinct prefix, otherwise their elements overwrite
+/// each other.
+pub trait IntoStorageKey {
+    fn into_storage_key(self) -> Vec<u8>;
+}
+
+impl IntoStorageKey for Vec<u8> {
+    fn into_storage_key(self) -> Vec<u8> {
+        self
+    }
+}
+
+impl<'a> IntoStorageKey for &'a [u8] {
+    fn into_storage_key(self) -> Vec<u8> {
+        self.to_vec()
+    }
+}
+
+impl IntoStorageKey for u8 {
+    fn into_storage_key(self) -> Vec<u8> {
+        vec![self]
+    }
+}
+
+/// Marker for the types that are converted into the storage key prefix by serializing them with
+/// Borsh. It is meant for an enum with a variant per collection, which gives every collection a
+/// distinct and compact prefix:
+///
+/// ```
+/// use near_sdk::borsh::{self, BorshSerialize};
+/// use near_sdk::collections::{BorshIntoStorageKey, LookupMap, Vector};
+///
+/// #[derive(BorshSerialize)]
+/// enum StorageKey {
+///     Accounts,
+///     History,
+///     SubAccounts { account_hash: Vec<u8> },
+/// }
+///
+/// impl BorshIntoStorageKey for StorageKey {}
+///
+/// let accounts: LookupMap<String, u128> = LookupMap::new(StorageKey::Accounts);
+/// let history: Vector<u128> = Vector::new(StorageKey::History);
+/// let sub_accounts: Vector<String> =
+///     Vector::new(StorageKey::SubAccounts { account_hash: vec![1, 2, 3] });
+/// ```
+pub trait BorshIntoStorageKey: BorshSerialize {}
+
+impl<T> IntoStorageKey for T
+where
+    T: BorshIntoStorageKey,
+{
+    fn into_storage_key(self) -> Vec<u8> {
+        self.try_to_vec().expect("Failed to serialize the storage key with Borsh.")
+    }
+}
+
+/// Computes the part of the storage key of a map entry that follows the prefix of the map from the
+/// Borsh serialization of the entry's key. `LookupMap` and `UnorderedMap` use `BorshKey` by
+/// default, and `Sha256Key` or a custom implementation can be set with `new_with_to_key`, e.g. to
+/// bound the length of the storage keys when the map keys are long strings:
+///
+/// ```
+/// use near_sdk::collections::{LookupMap, ToKey};
+///
+/// struct Keccak256Key;
+///
+/// impl ToKey for Keccak256Key {
+///     fn to_key(key_raw: &[u8]) -> Vec<u8> {
+///         near_sdk::env::keccak256(key_raw)
+///     }
+/// }
+///
+/// let names: LookupMap<String, u64, Keccak256Key> = LookupMap::new_with_to_key(b"n".to_vec());
+/// ```
+pub trait ToKey {
+    fn to_key(key_raw: &[u8]) -> Vec<u8>;
+}
+
+/// Uses the Borsh serialization of the key as is.
+pub struct BorshKey;
+
+impl ToKey for BorshKey {
+    fn to_key(key_raw: &[u8]) -> Vec<u8> {
+        key_raw.to_vec()
+    }
+}
+
+/// Uses the sha256 hash of the Borsh serialization of the key, so that every storage key of the
+/// map is 32 bytes longer than its prefix regardless of the length of the key.
+pub struct Sha256Key;
+
+impl ToKey for Sha256Key {
+    fn to_key(key_raw: &[u8]) -> Vec<u8> {
+        crate::env::sha256(key_raw)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collections::{LookupMap, UnorderedSet};
+    use crate::test_utils::test_env;
+
+    #[derive(BorshSerialize)]
+    enum StorageKey {
+        Balances,
+        Owners,
+        Nested { id: u32 },
+    }
+
+    impl BorshIntoStorageKey for StorageKey {}
+
+    #[test]
+    fn test_borsh_storage_key() {
+        assert_eq!(StorageKey::Balances.into_storage_key(), vec![0]);
+        assert_eq!(StorageKey::Owners.into_storage_key(), vec![1]);
+        assert_eq!(StorageKey::Nested { id: 7 }.into_storage_key(), vec![2, 7, 0, 0, 0]);
+        assert_eq!(b"m".to_vec().into_storage_key(), b"m".to_vec());
+        assert_eq!((&b"m"[..]).into_storage_key(), b"m".to_vec());
+        assert_eq!(b'm'.into_storage_key(), b"m".to_vec());
+    }
+
+    #[test]
+    fn test_distinct_prefixes_do_not_interfere() {
+        test_env::setup();
+        let mut balances: LookupMap<u64, u64> = LookupMap::new(StorageKey::Balances);
+        let mut owners: LookupMap<u64, u64> = LookupMap::new(StorageKey::Owners);
+        let mut first: UnorderedSet<u64> = UnorderedSet::new(StorageKey::Nested { id: 1 });
+        let mut second: UnorderedSet<u64> = UnorderedSet::new(StorageKey::Nested { id: 2 });
+        for i in 0..10 {
+            balances.insert(&i, &(i * 2));
+            owners.insert(&i, &(i * 3));
+            first.insert(&i);
+            second.insert(&(i + 100));
+        }
+        owners.remove(&5);
+        second.clear();
+        for i in 0..10 {
+            assert_eq!(balances.get(&i), Some(i * 2));
+            assert_eq!(owners.get(&i), if i == 5 { None } else { Some(i * 3) });
+            assert!(first.contains(&i));
+        }
+        assert_eq!(first.len(), 10);
+        assert_eq!(second.len(), 0);
+    }
+}
@@ -2,7 +2,7 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use std::ops::Bound;
 
 use crate::collections::LookupMap;
-use crate::collections::{append, Vector};
+use crate::collections::{append, IntoStorageKey, Vector};
 
 /// TreeMap based on AVL-tree
 ///
@@ -43,7 +43,11 @@ where
     K: Ord + Clone + BorshSerialize + BorshDeserialize,
     V: BorshSerialize + BorshDeserialize,
 {
-    pub fn new(id: Vec<u8>) -> Self {
+    pub fn new<S>(id: S) -> Self
+    where
+        S: IntoStorageKey,
+    {
+        let id = id.into_storage_key();
         Self {
             root: 0,
             val: LookupMap::new(append(&id, b'v')),
@@ -1,8 +1,9 @@
 //! A map implemented on a trie. Unlike `std::collections::HashMap` the keys in this map are not
 //! hashed but are instead serialized.
-use crate::collections::{append, append_slice, Vector};
+use crate::collections::{append, append_slice, BorshKey, IntoStorageKey, ToKey, Vector};
 use crate::env;
 use borsh::{BorshDeserialize, BorshSerialize};
+use std::marker::PhantomData;
 use std::mem::size_of;
 
 const ERR_INCONSISTENT_STATE: &[u8] = b"The collection is an inconsistent state. Did previous smart contract execution terminate unexpectedly?";
@@ -12,13 +13,28 @@ const ERR_VALUE_SERIALIZATION: &[u8] = b"Cannot serialize value with Borsh";
 
 /// An iterable implementation of a map that stores its content directly on the trie.
 #[derive(BorshSerialize, BorshDeserialize)]
-pub struct UnorderedMap<K, V> {
+pub struct UnorderedMap<K, V, H = BorshKey> {
     key_index_prefix: Vec<u8>,
     keys: Vector<K>,
     values: Vector<V>,
+    #[borsh_skip]
+    to_key: PhantomData<H>,
 }
 
 impl<K, V> UnorderedMap<K, V> {
+    /// Create new map with zero elements. Use `id` as a unique identifier.
+    pub fn new<S>(id: S) -> Self
+    where
+        S: IntoStorageKey,
+    {
+        Self::new_with_to_key(id)
+    }
+}
+
+impl<K, V, H> UnorderedMap<K, V, H>
+where
+    H: ToKey,
+{
     /// Returns the number of elements in the map, also referred to as its size.
     pub fn len(&self) -> u64 {
         let keys_len = self.keys.len();
@@ -41,8 +57,14 @@ impl<K, V> UnorderedMap<K, V> {
         }
     }
 
-    /// Create new map with zero elements. Use `id` as a unique identifier.
-    pub fn new(id: Vec<u8>) -> Self {
+    /// Create new map with zero elements that computes the keys of the index lookups with `H`.
+    /// The keys themselves are still stored with Borsh so that they can be iterated over.
+    /// Use `id` as a unique identifier.
+    pub fn new_with_to_key<S>(id: S) -> Self
+    where
+        S: IntoStorageKey,
+    {
+        let id = id.into_storage_key();
         let key_index_prefix = append(&id, b'i');
         let index_key_id = append(&id, b'k');
         let index_value_id = append(&id, b'v');
@@ -51,6 +73,7 @@ impl<K, V> UnorderedMap<K, V> {
             key_index_prefix,
             keys: Vector::new(index_key_id),
             values: Vector::new(index_value_id),
+            to_key: PhantomData,
         }
     }
 
@@ -65,7 +88,7 @@ impl<K, V> UnorderedMap<K, V> {
     }
 
     fn raw_key_to_index_lookup(&self, raw_key: &[u8]) -> Vec<u8> {
-        append_slice(&self.key_index_prefix, raw_key)
+        append_slice(&self.key_index_prefix, &H::to_key(raw_key))
     }
 
     /// Returns an index of the given raw key.
@@ -140,10 +163,11 @@ impl<K, V> UnorderedMap<K, V> {
     }
 }
 
-impl<K, V> UnorderedMap<K, V>
+impl<K, V, H> UnorderedMap<K, V, H>
 where
     K: BorshSerialize + BorshDeserialize,
     V: BorshSerialize + BorshDeserialize,
+    H: ToKey,
 {
     fn serialize_key(key: &K) -> Vec<u8> {
         match key.try_to_vec() {
@@ -441,4 +465,34 @@ mod tests {
         let actual: HashMap<u64, u64> = HashMap::from_iter(map.iter());
         assert_eq!(actual, key_to_value);
     }
+
+    #[test]
+    pub fn test_sha256_key() {
+        use crate::collections::Sha256Key;
+
+        test_env::setup();
+        let mut map: UnorderedMap<String, u64, Sha256Key> =
+            UnorderedMap::new_with_to_key(b"m".to_vec());
+        let keys: Vec<String> = (0..10).map(|i| i.to_string().repeat(100)).collect();
+        for (i, key) in keys.iter().enumerate() {
+            map.insert(key, &(i as u64));
+        }
+        assert_eq!(map.remove(&keys[3]), Some(3));
+        assert_eq!(map.insert(&keys[5], &50), Some(5));
+        for (i, key) in keys.iter().enumerate() {
+            let expected = match i {
+                3 => None,
+                5 => Some(50),
+                _ => Some(i as u64),
+            };
+            assert_eq!(map.get(key), expected);
+        }
+        let actual: HashSet<String> = HashSet::from_iter(map.keys());
+        let expected: HashSet<String> =
+            keys.iter().enumerate().filter(|(i, _)| *i != 3).map(|(_, key)| key.clone()).collect();
+        assert_eq!(actual, expected);
+        map.clear();
+        assert!(map.is_empty());
+        assert_eq!(map.get(&keys[0]), None);
+    }
 }
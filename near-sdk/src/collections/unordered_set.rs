@@ -1,6 +1,6 @@
 //! A set implemented on a trie. Unlike `std::collections::HashSet` the elements in this set are not
 //! hashed but are instead serialized.
-use crate::collections::{append, append_slice, Vector};
+use crate::collections::{append, append_slice, IntoStorageKey, Vector};
 use crate::env;
 use borsh::{BorshDeserialize, BorshSerialize};
 use std::mem::size_of;
@@ -27,7 +27,11 @@ impl<T> UnorderedSet<T> {
     }
 
     /// Create new map with zero elements. Use `id` as a unique identifier.
-    pub fn new(id: Vec<u8>) -> Self {
+    pub fn new<S>(id: S) -> Self
+    where
+        S: IntoStorageKey,
+    {
+        let id = id.into_storage_key();
         let element_index_prefix = append(&id, b'i');
         let elements_prefix = append(&id, b'e');
 
@@ -328,4 +332,36 @@ mod tests {
         let actual: HashSet<u64> = HashSet::from_iter(set.iter());
         assert_eq!(actual, keys);
     }
+
+    #[test]
+    pub fn test_insert_duplicate() {
+        test_env::setup();
+        let mut set = UnorderedSet::new(b"s".to_vec());
+        assert!(set.insert(&1u64));
+        assert!(!set.insert(&1u64));
+        assert_eq!(set.len(), 1);
+        assert_eq!(set.as_vector().len(), 1);
+    }
+
+    #[test]
+    pub fn test_remove_middle() {
+        test_env::setup();
+        let mut set = UnorderedSet::new(b"s".to_vec());
+        set.extend(vec![1u64, 2, 3, 4, 5]);
+        assert!(set.remove(&3));
+        assert!(!set.remove(&3));
+        assert!(!set.contains(&3));
+        assert_eq!(set.len(), 4);
+        // The last element takes the place of the removed one.
+        assert_eq!(set.to_vec(), vec![1, 2, 5, 4]);
+        for key in &[1u64, 2, 4, 5] {
+            assert!(set.contains(key));
+        }
+        assert!(set.remove(&5));
+        assert!(set.remove(&1));
+        let actual: HashSet<u64> = HashSet::from_iter(set.iter());
+        assert_eq!(actual, HashSet::from_iter(vec![2, 4]));
+        assert!(set.insert(&3));
+        assert_eq!(set.to_vec(), vec![4, 2, 3]);
+    }
 }
@@ -4,7 +4,7 @@ use std::marker::PhantomData;
 
 use borsh::{BorshDeserialize, BorshSerialize};
 
-use crate::collections::append_slice;
+use crate::collections::{append_slice, IntoStorageKey};
 use crate::env;
 
 const ERR_INCONSISTENT_STATE: &[u8] = b"The collection is an inconsistent state. Did previous smart contract execution terminate unexpectedly?";
@@ -35,8 +35,11 @@ impl<T> Vector<T> {
     }
 
     /// Create new vector with zero elements. Use `id` as a unique identifier on the trie.
-    pub fn new(id: Vec<u8>) -> Self {
-        Self { len: 0, prefix: id, el: PhantomData }
+    pub fn new<S>(id: S) -> Self
+    where
+        S: IntoStorageKey,
+    {
+        Self { len: 0, prefix: id.into_storage_key(), el: PhantomData }
     }
 
     fn index_to_lookup_key(&self, index: u64) -> Vec<u8> {
@@ -73,13 +76,9 @@ impl<T> Vector<T> {
         } else {
             let lookup_key = self.index_to_lookup_key(index);
             let raw_last_value = self.pop_raw().expect("checked `index < len` above, so `len > 0`");
-            if env::storage_write(&lookup_key, &raw_last_value) {
-                match env::storage_get_evicted() {
-                    Some(x) => x,
-                    None => env::panic(ERR_INCONSISTENT_STATE),
-                }
-            } else {
-                env::panic(ERR_INCONSISTENT_STATE)
+            match env::storage_write_evicted(&lookup_key, &raw_last_value) {
+                Some(x) => x,
+                None => env::panic(ERR_INCONSISTENT_STATE),
             }
         }
     }
@@ -122,13 +121,9 @@ impl<T> Vector<T> {
             env::panic(ERR_INDEX_OUT_OF_BOUNDS)
         } else {
             let lookup_key = self.index_to_lookup_key(index);
-            if env::storage_write(&lookup_key, &raw_element) {
-                match env::storage_get_evicted() {
-                    Some(x) => x,
-                    None => env::panic(ERR_INCONSISTENT_STATE),
-                }
-            } else {
-                env::panic(ERR_INCONSISTENT_STATE);
+            match env::storage_write_evicted(&lookup_key, &raw_element) {
+                Some(x) => x,
+                None => env::panic(ERR_INCONSISTENT_STATE),
             }
         }
     }
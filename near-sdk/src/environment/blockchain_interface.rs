@@ -35,6 +35,19 @@ pub trait BlockchainInterface {
     unsafe fn sha256(&self, value_len: u64, value_ptr: u64, register_id: u64);
     unsafe fn keccak256(&self, value_len: u64, value_ptr: u64, register_id: u64);
     unsafe fn keccak512(&self, value_len: u64, value_ptr: u64, register_id: u64);
+    unsafe fn alt_bn128_g1_multiexp(&self, value_len: u64, value_ptr: u64, register_id: u64);
+    unsafe fn alt_bn128_g1_sum(&self, value_len: u64, value_ptr: u64, register_id: u64);
+    unsafe fn alt_bn128_pairing_check(&self, value_len: u64, value_ptr: u64) -> u64;
+    unsafe fn ecrecover(
+        &self,
+        hash_len: u64,
+        hash_ptr: u64,
+        sig_len: u64,
+        sig_ptr: u64,
+        v: u64,
+        malleability_flag: u64,
+        register_id: u64,
+    ) -> u64;
     // #####################
     // # Miscellaneous API #
     // #####################
@@ -97,6 +110,17 @@ pub trait BlockchainInterface {
         amount_ptr: u64,
         gas: u64,
     );
+    unsafe fn promise_batch_action_function_call_weight(
+        &self,
+        promise_index: u64,
+        method_name_len: u64,
+        method_name_ptr: u64,
+        arguments_len: u64,
+        arguments_ptr: u64,
+        amount_ptr: u64,
+        gas: u64,
+        gas_weight: u64,
+    );
     unsafe fn promise_batch_action_transfer(&self, promise_index: u64, amount_ptr: u64);
     unsafe fn promise_batch_action_stake(
         &self,
@@ -169,4 +193,12 @@ pub trait BlockchainInterface {
     fn as_mocked_blockchain(&self) -> Option<&MockedBlockchain> {
         None
     }
+
+    /// Whether the interface is already wrapped into the gas profiler, so that it is not wrapped
+    /// again when it is taken and set back.
+    #[cfg(all(feature = "gas-profile", debug_assertions))]
+    #[doc(hidden)]
+    fn is_gas_profiler(&self) -> bool {
+        false
+    }
 }
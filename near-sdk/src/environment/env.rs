@@ -3,6 +3,7 @@
 //! whenever possible. In case of cross-contract calls prefer using even higher-level API available
 //! through `callback_args`, `callback_args_vec`, `ext_contract`, `Promise`, and `PromiseOrValue`.
 
+use std::any::Any;
 use std::borrow::Borrow;
 use std::cell::RefCell;
 use std::mem::size_of;
@@ -13,12 +14,37 @@ use near_vm_logic::types::{
 };
 
 use crate::environment::blockchain_interface::BlockchainInterface;
+use crate::json_types::ValidAccountId;
 
 thread_local! {
-/// Low-level blockchain interface wrapped by the environment. Prefer using `env::*` and `testing_env`
-/// for interacting with the real and fake blockchains.
+    /// Low-level blockchain interface wrapped by the environment. Prefer using `env::*` and
+    /// `testing_env` for interacting with the real and fake blockchains.
     pub static BLOCKCHAIN_INTERFACE: RefCell<Option<Box<dyn BlockchainInterface>>>
          = RefCell::new(None);
+    /// Values of the context that don't change during the invocation, read from the blockchain
+    /// interface on first access. Reset whenever the blockchain interface is replaced.
+    static CONTEXT_CACHE: RefCell<ContextCache> = RefCell::new(ContextCache::default());
+}
+
+#[derive(Default)]
+struct ContextCache {
+    current_account_id: Option<ValidAccountId>,
+    signer_account_id: Option<ValidAccountId>,
+    signer_account_pk: Option<PublicKey>,
+    predecessor_account_id: Option<ValidAccountId>,
+    block_index: Option<BlockHeight>,
+    block_timestamp: Option<u64>,
+    epoch_height: Option<u64>,
+}
+
+/// Returns the cached value of the `field`, reading it with `read` on first access.
+fn cached<T: Clone>(field: fn(&mut ContextCache) -> &mut Option<T>, read: impl FnOnce() -> T) -> T {
+    if let Some(value) = CONTEXT_CACHE.with(|c| field(&mut c.borrow_mut()).clone()) {
+        return value;
+    }
+    let value = read();
+    CONTEXT_CACHE.with(|c| *field(&mut c.borrow_mut()) = Some(value.clone()));
+    value
 }
 
 const BLOCKCHAIN_INTERFACE_NOT_SET_ERR: &str = "Blockchain interface not set.";
@@ -80,10 +106,21 @@ macro_rules! method_into_register {
 ///       );
 /// near_sdk::env::set_blockchain_interface(Box::new(mocked_blockchain));
 /// ```
+///
+/// In debug builds with the `gas-profile` feature the interface is wrapped into a profiler that
+/// records the gas of every host call, see `log_gas_profile`.
 pub fn set_blockchain_interface(blockchain_interface: Box<dyn BlockchainInterface>) {
+    #[cfg(all(feature = "gas-profile", debug_assertions))]
+    let blockchain_interface: Box<dyn BlockchainInterface> =
+        if blockchain_interface.is_gas_profiler() {
+            blockchain_interface
+        } else {
+            Box::new(crate::environment::gas_profile::GasProfiler::new(blockchain_interface))
+        };
     BLOCKCHAIN_INTERFACE.with(|b| {
         *b.borrow_mut() = Some(blockchain_interface);
-    })
+    });
+    CONTEXT_CACHE.with(|c| *c.borrow_mut() = ContextCache::default());
 }
 
 /// Removes and returns the current low-level blockchain interface accessible through `env::*`.
@@ -107,13 +144,29 @@ pub fn take_blockchain_interface() -> Option<Box<dyn BlockchainInterface>> {
     BLOCKCHAIN_INTERFACE.with(|b| b.replace(None))
 }
 
-/// Implements panic hook that converts `PanicInfo` into a string and provides it through the
-/// blockchain interface.
+/// Extracts the message from the payload of `panic!`, `expect`, `unwrap`, etc.
+fn panic_payload_message(payload: &(dyn Any + Send)) -> Option<&str> {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        Some(message)
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        Some(message.as_str())
+    } else {
+        None
+    }
+}
+
+/// Implements panic hook that translates the panic payload into a message and provides it through
+/// the blockchain interface. Falls back to the full `PanicInfo` if the payload is not a string.
 fn panic_hook_impl(info: &std_panic::PanicInfo) {
-    panic(info.to_string().as_bytes());
+    match panic_payload_message(info.payload()) {
+        Some(message) => panic_str(message),
+        None => panic_str(&info.to_string()),
+    }
 }
 
-/// Setups panic hook to expose error info to the blockchain.
+/// Setups panic hook to expose error info to the blockchain. `near_bindgen` calls it at the
+/// beginning of every exported method, so that the message of a Rust panic, e.g. from a failed
+/// `unwrap` deep in the contract logic, becomes the message of the host panic.
 pub fn setup_panic_hook() {
     std_panic::set_hook(Box::new(panic_hook_impl));
 }
@@ -131,6 +184,25 @@ pub fn read_register(register_id: u64) -> Option<Vec<u8>> {
     Some(res)
 }
 
+/// Reads the content of the `register_id` into `buf`, replacing its previous content. Unlike
+/// `read_register` it doesn't allocate if `buf` already has enough capacity, so a buffer can be
+/// reused for reading several large values. Returns `false` if the register is not used.
+pub fn read_register_into(register_id: u64, buf: &mut Vec<u8>) -> bool {
+    let len = match register_len(register_id) {
+        Some(len) => len as usize,
+        None => return false,
+    };
+    buf.clear();
+    buf.resize(len, 0);
+    BLOCKCHAIN_INTERFACE.with(|b| unsafe {
+        b.borrow()
+            .as_ref()
+            .expect(BLOCKCHAIN_INTERFACE_NOT_SET_ERR)
+            .read_register(register_id, buf.as_mut_ptr() as _)
+    });
+    true
+}
+
 /// Returns the size of the register. If register is not used returns `None`.
 pub fn register_len(register_id: u64) -> Option<u64> {
     let len = BLOCKCHAIN_INTERFACE.with(|b| unsafe {
@@ -146,26 +218,49 @@ pub fn register_len(register_id: u64) -> Option<u64> {
 // ###############
 // # Context API #
 // ###############
+// The account ids, the signer's key and the block values are constant during the invocation, so
+// they are read from the host only once.
 /// The id of the account that owns the current contract.
-pub fn current_account_id() -> AccountId {
-    String::from_utf8(method_into_register!(current_account_id)).unwrap()
+pub fn current_account_id() -> ValidAccountId {
+    cached(
+        |c| &mut c.current_account_id,
+        || {
+            ValidAccountId::new_unchecked(
+                String::from_utf8(method_into_register!(current_account_id)).unwrap(),
+            )
+        },
+    )
 }
 
 /// The id of the account that either signed the original transaction or issued the initial
 /// cross-contract call.
-pub fn signer_account_id() -> AccountId {
-    String::from_utf8(method_into_register!(signer_account_id)).unwrap()
+pub fn signer_account_id() -> ValidAccountId {
+    cached(
+        |c| &mut c.signer_account_id,
+        || {
+            ValidAccountId::new_unchecked(
+                String::from_utf8(method_into_register!(signer_account_id)).unwrap(),
+            )
+        },
+    )
 }
 
 /// The public key of the account that did the signing.
 pub fn signer_account_pk() -> PublicKey {
-    method_into_register!(signer_account_pk)
+    cached(|c| &mut c.signer_account_pk, || method_into_register!(signer_account_pk))
 }
 
 /// The id of the account that was the previous contract in the chain of cross-contract calls.
 /// If this is the first contract, it is equal to `signer_account_id`.
-pub fn predecessor_account_id() -> String {
-    String::from_utf8(method_into_register!(predecessor_account_id)).unwrap()
+pub fn predecessor_account_id() -> ValidAccountId {
+    cached(
+        |c| &mut c.predecessor_account_id,
+        || {
+            ValidAccountId::new_unchecked(
+                String::from_utf8(method_into_register!(predecessor_account_id)).unwrap(),
+            )
+        },
+    )
 }
 
 /// The input to the contract call serialized as bytes. If input is not provided returns `None`.
@@ -173,29 +268,57 @@ pub fn input() -> Option<Vec<u8>> {
     try_method_into_register!(input)
 }
 
+/// The length of the input to the contract call in bytes, `0` if input is not provided. Unlike
+/// `input()` it doesn't copy the input into the memory of the contract, which makes it cheaper to
+/// check whether any input was provided.
+pub fn input_len() -> u64 {
+    BLOCKCHAIN_INTERFACE.with(|b| unsafe {
+        b.borrow().as_ref().expect(BLOCKCHAIN_INTERFACE_NOT_SET_ERR).input(ATOMIC_OP_REGISTER);
+    });
+    register_len(ATOMIC_OP_REGISTER).unwrap_or(0)
+}
+
 /// Current block index.
 pub fn block_index() -> BlockHeight {
-    unsafe {
-        BLOCKCHAIN_INTERFACE
-            .with(|b| b.borrow().as_ref().expect(BLOCKCHAIN_INTERFACE_NOT_SET_ERR).block_index())
-    }
+    cached(
+        |c| &mut c.block_index,
+        || unsafe {
+            BLOCKCHAIN_INTERFACE.with(|b| {
+                b.borrow().as_ref().expect(BLOCKCHAIN_INTERFACE_NOT_SET_ERR).block_index()
+            })
+        },
+    )
 }
 
 /// Current block timestamp, i.e, number of non-leap-nanoseconds since January 1, 1970 0:00:00 UTC.
+/// The SDK measures time in nanoseconds everywhere, like `json_types::Timestamp`, so convert
+/// explicitly, e.g. with `block_timestamp_ms`, when another unit is needed.
 pub fn block_timestamp() -> u64 {
-    unsafe {
-        BLOCKCHAIN_INTERFACE.with(|b| {
-            b.borrow().as_ref().expect(BLOCKCHAIN_INTERFACE_NOT_SET_ERR).block_timestamp()
-        })
-    }
+    cached(
+        |c| &mut c.block_timestamp,
+        || unsafe {
+            BLOCKCHAIN_INTERFACE.with(|b| {
+                b.borrow().as_ref().expect(BLOCKCHAIN_INTERFACE_NOT_SET_ERR).block_timestamp()
+            })
+        },
+    )
+}
+
+/// Current block timestamp in milliseconds since January 1, 1970 0:00:00 UTC, rounded down.
+pub fn block_timestamp_ms() -> u64 {
+    block_timestamp() / 1_000_000
 }
 
 /// Current epoch height.
 pub fn epoch_height() -> u64 {
-    unsafe {
-        BLOCKCHAIN_INTERFACE
-            .with(|b| b.borrow().as_ref().expect(BLOCKCHAIN_INTERFACE_NOT_SET_ERR).epoch_height())
-    }
+    cached(
+        |c| &mut c.epoch_height,
+        || unsafe {
+            BLOCKCHAIN_INTERFACE.with(|b| {
+                b.borrow().as_ref().expect(BLOCKCHAIN_INTERFACE_NOT_SET_ERR).epoch_height()
+            })
+        },
+    )
 }
 
 /// Current total storage usage of this smart contract that this account would be paying for.
@@ -305,7 +428,8 @@ pub fn keccak256(value: &[u8]) -> Vec<u8> {
     read_register(ATOMIC_OP_REGISTER).expect(REGISTER_EXPECTED_ERR)
 }
 
-/// Hashes the random sequence of bytes using keccak512.
+/// Hashes the random sequence of bytes using keccak512. The 64 bytes of the hash are computed by the
+/// host like the other hashes.
 pub fn keccak512(value: &[u8]) -> Vec<u8> {
     unsafe {
         BLOCKCHAIN_INTERFACE.with(|b| {
@@ -319,6 +443,114 @@ pub fn keccak512(value: &[u8]) -> Vec<u8> {
     read_register(ATOMIC_OP_REGISTER).expect(REGISTER_EXPECTED_ERR)
 }
 
+/// Hashes the random sequence of bytes using sha512 and returns the 64 bytes of the hash. The host
+/// doesn't provide sha512, so it is computed inside the contract by the `sha2` crate, which burns
+/// more gas than the host hashes and adds several kilobytes to the contract. Only available with
+/// the `sha512` feature.
+#[cfg(feature = "sha512")]
+pub fn sha512(value: &[u8]) -> Vec<u8> {
+    use sha2::Digest;
+    sha2::Sha512::digest(value).to_vec()
+}
+
+/// Hashes 1 to 3 elements of the alt_bn128 scalar field, encoded as little-endian `u256`, with
+/// Poseidon and returns the hash encoded the same way. The parameters are the ones of circomlib,
+/// so the hash matches the one computed by the circuits, e.g. for the roots of a Merkle tree.
+/// The host doesn't provide Poseidon, so it is computed inside the contract, which takes
+/// about 850 field multiplications for 2 inputs and costs much more gas than the host hashes.
+/// Only available with the `poseidon` feature. Panics if an input is not less than the field
+/// modulus.
+#[cfg(feature = "poseidon")]
+pub fn poseidon(inputs: &[[u8; 32]]) -> [u8; 32] {
+    super::poseidon::poseidon(inputs)
+}
+
+/// Computes multiexp on alt_bn128 curve using Pippenger's algorithm, i.e. `\sum_i mul_i g_{1 i}`.
+/// `value` is encoded as packed, little-endian `[((u256, u256), u256)]` slice of G1 points with
+/// their scalars. Returns the resulting G1 point encoded as little-endian `(u256, u256)`.
+pub fn alt_bn128_g1_multiexp(value: &[u8]) -> Vec<u8> {
+    unsafe {
+        BLOCKCHAIN_INTERFACE.with(|b| {
+            b.borrow().as_ref().expect(BLOCKCHAIN_INTERFACE_NOT_SET_ERR).alt_bn128_g1_multiexp(
+                value.len() as _,
+                value.as_ptr() as _,
+                ATOMIC_OP_REGISTER,
+            )
+        });
+    };
+    read_register(ATOMIC_OP_REGISTER).expect(REGISTER_EXPECTED_ERR)
+}
+
+/// Computes the sum of signed G1 points on alt_bn128 curve, i.e. `\sum_i (-1)^{sign_i} g_{1 i}`.
+/// `value` is encoded as packed, little-endian `[(u8, (u256, u256))]` slice, where `0u8` is the
+/// positive sign and `1u8` is the negative one. Returns the resulting G1 point encoded as
+/// little-endian `(u256, u256)`.
+pub fn alt_bn128_g1_sum(value: &[u8]) -> Vec<u8> {
+    unsafe {
+        BLOCKCHAIN_INTERFACE.with(|b| {
+            b.borrow().as_ref().expect(BLOCKCHAIN_INTERFACE_NOT_SET_ERR).alt_bn128_g1_sum(
+                value.len() as _,
+                value.as_ptr() as _,
+                ATOMIC_OP_REGISTER,
+            )
+        });
+    };
+    read_register(ATOMIC_OP_REGISTER).expect(REGISTER_EXPECTED_ERR)
+}
+
+/// Computes pairing check on alt_bn128 curve, i.e. `\prod_i e(g_{1 i}, g_{2 i}) == 1`.
+/// `value` is encoded as packed, little-endian `[((u256, u256), ((u256, u256), (u256, u256)))]`
+/// slice of G1 and G2 point pairs. Returns `true` if the product of the pairings is one.
+pub fn alt_bn128_pairing_check(value: &[u8]) -> bool {
+    match unsafe {
+        BLOCKCHAIN_INTERFACE.with(|b| {
+            b.borrow()
+                .as_ref()
+                .expect(BLOCKCHAIN_INTERFACE_NOT_SET_ERR)
+                .alt_bn128_pairing_check(value.len() as _, value.as_ptr() as _)
+        })
+    } {
+        0 => false,
+        1 => true,
+        _ => panic!(RETURN_CODE_ERR),
+    }
+}
+
+/// Recovers the 64-byte uncompressed secp256k1 public key, without the `0x04` prefix, that signed
+/// `hash` with the signature `sig` in the `r || s` form. `v` is the recovery id, either `0` or `1`,
+/// and the host panics on values above `3`. If `malleability_flag` is set, signatures with the high
+/// `s` value are rejected, as in Ethereum transactions. Returns `None` if the signature is invalid.
+pub fn ecrecover(
+    hash: &[u8; 32],
+    sig: &[u8; 64],
+    v: u8,
+    malleability_flag: bool,
+) -> Option<[u8; 64]> {
+    let recovered = unsafe {
+        BLOCKCHAIN_INTERFACE.with(|b| {
+            b.borrow().as_ref().expect(BLOCKCHAIN_INTERFACE_NOT_SET_ERR).ecrecover(
+                hash.len() as _,
+                hash.as_ptr() as _,
+                sig.len() as _,
+                sig.as_ptr() as _,
+                v as _,
+                malleability_flag as _,
+                ATOMIC_OP_REGISTER,
+            )
+        })
+    };
+    match recovered {
+        0 => None,
+        1 => {
+            let public_key = read_register(ATOMIC_OP_REGISTER).expect(REGISTER_EXPECTED_ERR);
+            let mut result = [0u8; 64];
+            result.copy_from_slice(&public_key);
+            Some(result)
+        }
+        _ => panic!(RETURN_CODE_ERR),
+    }
+}
+
 // ################
 // # Promises API #
 // ################
@@ -375,7 +607,9 @@ pub fn promise_then(
     }
 }
 
-/// Creates a new promise which completes when time all promises passed as arguments complete.
+/// Creates a new promise which completes when all promises passed as arguments complete. The
+/// returned index can be passed to `promise_then` to run a single callback after all of them, which
+/// then receives one promise result per joined promise, e.g. for a `#[callback_vec]` argument.
 pub fn promise_and(promise_indices: &[PromiseIndex]) -> PromiseIndex {
     let mut data = vec![0u8; promise_indices.len() * size_of::<PromiseIndex>()];
     for i in 0..promise_indices.len() {
@@ -431,7 +665,7 @@ pub fn promise_batch_action_create_account(promise_index: PromiseIndex) {
     }
 }
 
-pub fn promise_batch_action_deploy_contract(promise_index: u64, code: &[u8]) {
+pub fn promise_batch_action_deploy_contract(promise_index: PromiseIndex, code: &[u8]) {
     unsafe {
         BLOCKCHAIN_INTERFACE.with(|b| {
             b.borrow()
@@ -471,6 +705,67 @@ pub fn promise_batch_action_function_call(
     }
 }
 
+/// Same as `promise_batch_action_function_call`, but `gas` is the minimum amount of gas attached
+/// to the call. The gas that remains unused after the contract execution is distributed between
+/// the function calls of the created receipts proportionally to their `gas_weight`.
+pub fn promise_batch_action_function_call_weight(
+    promise_index: PromiseIndex,
+    method_name: &[u8],
+    arguments: &[u8],
+    amount: Balance,
+    gas: Gas,
+    gas_weight: u64,
+) {
+    unsafe {
+        BLOCKCHAIN_INTERFACE.with(|b| {
+            b.borrow()
+                .as_ref()
+                .expect(BLOCKCHAIN_INTERFACE_NOT_SET_ERR)
+                .promise_batch_action_function_call_weight(
+                    promise_index,
+                    method_name.len() as _,
+                    method_name.as_ptr() as _,
+                    arguments.len() as _,
+                    arguments.as_ptr() as _,
+                    &amount as *const Balance as _,
+                    gas,
+                    gas_weight,
+                )
+        })
+    }
+}
+
+/// Creates a promise that calls `method_name` on `account_id` with the arguments serialized to
+/// JSON, and attaches the given amount and gas. Same as `promise_batch_create` followed by
+/// `promise_batch_action_function_call`.
+///
+/// ```
+/// # use near_sdk::env;
+/// # use near_sdk::serde_json::json;
+/// # near_sdk::test_utils::test_env::setup();
+/// let args = json!({ "receiver_id": "bob.near", "amount": "100" });
+/// env::promise_function_call_json(&"token.near".to_string(), "ft_transfer", &args, 1, 10_000_000_000_000);
+/// ```
+pub fn promise_function_call_json<A: Borrow<AccountId>, T: serde::Serialize + ?Sized>(
+    account_id: A,
+    method_name: &str,
+    arguments: &T,
+    amount: Balance,
+    gas: Gas,
+) -> PromiseIndex {
+    let arguments =
+        serde_json::to_vec(arguments).expect("Failed to serialize the arguments using JSON.");
+    let promise_index = promise_batch_create(account_id);
+    promise_batch_action_function_call(
+        promise_index,
+        method_name.as_bytes(),
+        &arguments,
+        amount,
+        gas,
+    );
+    promise_index
+}
+
 pub fn promise_batch_action_transfer(promise_index: PromiseIndex, amount: Balance) {
     unsafe {
         BLOCKCHAIN_INTERFACE.with(|b| {
@@ -689,6 +984,10 @@ pub fn panic(message: &[u8]) -> ! {
     }
     unreachable!()
 }
+/// Terminates the execution of the program with the given message.
+pub fn panic_str(message: &str) -> ! {
+    panic(message.as_bytes())
+}
 /// Log the UTF-8 encodable message.
 pub fn log(message: &[u8]) {
     unsafe {
@@ -700,6 +999,41 @@ pub fn log(message: &[u8]) {
         })
     }
 }
+/// Log the string message.
+pub fn log_str(message: &str) {
+    log(message.as_bytes())
+}
+
+/// Logs the gas burnt by every host function called since the previous call of `log_gas_profile`,
+/// e.g. `Gas profile: storage_read x2 = 112559194568, storage_write x1 = 253815326625`, and resets
+/// the profile. Meant to be called at the end of every method with
+/// `#[near_bindgen(after_call = near_sdk::env::log_gas_profile)]`.
+///
+/// It is a no-op unless the contract is built in debug mode with the `gas-profile` feature, so
+/// release builds pay nothing for the profiling.
+pub fn log_gas_profile() {
+    #[cfg(all(feature = "gas-profile", debug_assertions))]
+    {
+        let breakdown = crate::environment::gas_profile::take_breakdown();
+        let entries: Vec<String> = breakdown
+            .iter()
+            .map(|(name, calls, gas)| format!("{} x{} = {}", name, calls, gas))
+            .collect();
+        log_str(&format!("Gas profile: {}", entries.join(", ")));
+    }
+}
+
+/// Log the UTF-16 encoded message.
+pub fn log_utf16(message: &[u16]) {
+    unsafe {
+        BLOCKCHAIN_INTERFACE.with(|b| {
+            b.borrow()
+                .as_ref()
+                .expect(BLOCKCHAIN_INTERFACE_NOT_SET_ERR)
+                .log_utf16((message.len() * size_of::<u16>()) as _, message.as_ptr() as _)
+        })
+    }
+}
 
 // ###############
 // # Storage API #
@@ -723,6 +1057,15 @@ pub fn storage_write(key: &[u8], value: &[u8]) -> bool {
         _ => panic!(RETURN_CODE_ERR),
     }
 }
+/// Same as `storage_write`, but returns the evicted value instead of `true`. Reading the evicted
+/// value costs gas, so use `storage_write` if it is not needed.
+pub fn storage_write_evicted(key: &[u8], value: &[u8]) -> Option<Vec<u8>> {
+    if storage_write(key, value) {
+        Some(storage_get_evicted().expect(REGISTER_EXPECTED_ERR))
+    } else {
+        None
+    }
+}
 /// Reads the value stored under the given key.
 pub fn storage_read(key: &[u8]) -> Option<Vec<u8>> {
     match unsafe {
@@ -739,6 +1082,26 @@ pub fn storage_read(key: &[u8]) -> Option<Vec<u8>> {
         _ => panic!(RETURN_CODE_ERR),
     }
 }
+/// Reads the value stored under the given key into the register `register_id` without copying it
+/// into the contract memory. Returns `false` if the key doesn't exist. The size of the value is
+/// then available with `register_len` and the value itself with `read_register_into`.
+/// Register `0` is overwritten by most of the `env` functions, so pick another one if the value
+/// has to stay there across other calls.
+pub fn storage_read_to_register(key: &[u8], register_id: u64) -> bool {
+    match unsafe {
+        BLOCKCHAIN_INTERFACE.with(|b| {
+            b.borrow().as_ref().expect(BLOCKCHAIN_INTERFACE_NOT_SET_ERR).storage_read(
+                key.len() as _,
+                key.as_ptr() as _,
+                register_id,
+            )
+        })
+    } {
+        0 => false,
+        1 => true,
+        _ => panic!(RETURN_CODE_ERR),
+    }
+}
 /// Removes the value stored under the given key.
 /// If key-value existed returns `true`, otherwise `false`.
 pub fn storage_remove(key: &[u8]) -> bool {
@@ -779,12 +1142,22 @@ pub fn storage_has_key(key: &[u8]) -> bool {
 // ############################################
 // # Saving and loading of the contract state #
 // ############################################
-/// Load the state of the given object.
+/// Load the state of the given object. Returns `None` if the state does not exist and panics if it cannot be
+/// deserialized.
 pub fn state_read<T: borsh::BorshDeserialize>() -> Option<T> {
     storage_read(STATE_KEY)
         .map(|data| T::try_from_slice(&data).expect("Cannot deserialize the contract state."))
 }
 
+/// Same as `state_read`, but returns the deserialization error instead of panicking, so that
+/// a migration method can handle legacy or malformed state.
+pub fn try_state_read<T: borsh::BorshDeserialize>() -> std::io::Result<Option<T>> {
+    match storage_read(STATE_KEY) {
+        Some(data) => T::try_from_slice(&data).map(Some),
+        None => Ok(None),
+    }
+}
+
 pub fn state_write<T: borsh::BorshSerialize>(state: &T) {
     let data = state.try_to_vec().expect("Cannot serialize the contract state.");
     storage_write(STATE_KEY, &data);
@@ -795,6 +1168,11 @@ pub fn state_exists() -> bool {
     storage_has_key(STATE_KEY)
 }
 
+/// Removes the contract state. Returns `true` if the state existed and `false` otherwise.
+pub fn state_remove() -> bool {
+    storage_remove(STATE_KEY)
+}
+
 // ##################
 // # Helper methods #
 // ##################
@@ -916,4 +1294,443 @@ mod tests {
         assert!(!is_valid_account_id(&[0, 1, 2]));
         assert!(is_valid_account_id(b"near"));
     }
+
+    /// Generator of the G1 group, i.e. `(1, 2)`.
+    const G1: [u8; 64] = [
+        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0,
+    ];
+    /// Negation of the G1 generator, i.e. `(1, q - 2)`.
+    const NEG_G1: [u8; 64] = [
+        1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 69, 253, 124, 216, 22, 140, 32, 60, 141, 202, 113, 104, 145, 106, 129, 151, 93, 88,
+        129, 129, 182, 69, 80, 184, 41, 160, 49, 225, 114, 78, 100, 48,
+    ];
+    /// Generator of the G2 group.
+    const G2: [u8; 128] = [
+        237, 246, 146, 217, 92, 189, 222, 70, 221, 218, 94, 247, 212, 34, 67, 103, 121, 68, 92, 94,
+        102, 0, 106, 66, 118, 30, 31, 18, 239, 222, 0, 24, 194, 18, 243, 174, 183, 133, 228, 151,
+        18, 231, 169, 53, 51, 73, 170, 241, 37, 93, 251, 49, 183, 191, 96, 114, 58, 72, 13, 146,
+        147, 147, 142, 25, 170, 125, 250, 102, 1, 204, 230, 76, 123, 211, 67, 12, 105, 231, 209,
+        227, 143, 64, 203, 141, 128, 113, 171, 74, 235, 109, 140, 219, 165, 94, 200, 18, 91, 151,
+        34, 209, 220, 218, 172, 85, 243, 142, 179, 112, 51, 49, 75, 188, 149, 51, 12, 105, 173,
+        153, 158, 236, 117, 240, 95, 88, 208, 137, 6, 9,
+    ];
+
+    #[test]
+    fn test_alt_bn128_g1_multiexp() {
+        crate::test_utils::test_env::setup();
+        let mut one = [0u8; 32];
+        one[0] = 1;
+        let value = [&G1[..], &one[..]].concat();
+        assert_eq!(alt_bn128_g1_multiexp(&value), G1.to_vec());
+    }
+
+    #[test]
+    fn test_alt_bn128_g1_sum() {
+        crate::test_utils::test_env::setup();
+        let value = [&[0u8][..], &G1[..]].concat();
+        assert_eq!(alt_bn128_g1_sum(&value), G1.to_vec());
+
+        // `G1 - G1` is the point at infinity which is encoded as zeros.
+        let value = [&[0u8][..], &G1[..], &[1u8][..], &G1[..]].concat();
+        assert_eq!(alt_bn128_g1_sum(&value), vec![0u8; 64]);
+    }
+
+    #[test]
+    #[should_panic(expected = "AltBn128InvalidInput: the G1 point is not on the curve")]
+    fn test_alt_bn128_invalid_point() {
+        crate::test_utils::test_env::setup();
+        let mut point = G1;
+        point[32] ^= 1;
+        alt_bn128_g1_sum(&[&[0u8][..], &point[..]].concat());
+    }
+
+    #[test]
+    fn test_alt_bn128_pairing_check() {
+        crate::test_utils::test_env::setup();
+        // Empty product of pairings equals one.
+        assert!(alt_bn128_pairing_check(&[]));
+        // `e(G1, G2) != 1` since the pairing is non-degenerate.
+        let value = [&G1[..], &G2[..]].concat();
+        assert!(!alt_bn128_pairing_check(&value));
+        // `e(G1, G2) * e(-G1, G2) == 1`.
+        let value = [&G1[..], &G2[..], &NEG_G1[..], &G2[..]].concat();
+        assert!(alt_bn128_pairing_check(&value));
+    }
+
+    #[test]
+    fn test_panic_payload_message() {
+        let payload = std_panic::catch_unwind(|| panic!("static message")).unwrap_err();
+        assert_eq!(panic_payload_message(payload.as_ref()), Some("static message"));
+        let payload = std_panic::catch_unwind(|| panic!("formatted {}", 1)).unwrap_err();
+        assert_eq!(panic_payload_message(payload.as_ref()), Some("formatted 1"));
+        let payload =
+            std_panic::catch_unwind(|| std_panic::resume_unwind(Box::new(1u8))).unwrap_err();
+        assert_eq!(panic_payload_message(payload.as_ref()), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Deep unwrap failed")]
+    fn test_panic_str() {
+        crate::test_utils::test_env::setup();
+        panic_str("Deep unwrap failed");
+    }
+
+    #[test]
+    fn test_typed_account_ids() {
+        use crate::test_utils::test_env::{alice, bob, setup};
+        use std::convert::TryFrom;
+
+        setup();
+        let current: ValidAccountId = current_account_id();
+        assert!(is_valid_account_id(current.as_ref().as_bytes()));
+        assert_eq!(current, alice());
+        assert_eq!(signer_account_id(), ValidAccountId::try_from(bob()).unwrap());
+        assert_eq!(String::from(predecessor_account_id()), bob());
+    }
+
+    #[test]
+    fn test_try_state_read() {
+        crate::test_utils::test_env::setup();
+        assert_eq!(state_read::<u64>(), None);
+        assert_eq!(try_state_read::<u64>().unwrap(), None);
+
+        state_write(&42u64);
+        assert_eq!(try_state_read::<u64>().unwrap(), Some(42));
+
+        // Legacy state that can't be deserialized into the new layout.
+        storage_write(STATE_KEY, &[1, 2, 3]);
+        assert!(try_state_read::<u64>().is_err());
+    }
+
+    #[test]
+    fn test_storage_read_to_register() {
+        crate::test_utils::test_env::setup_free();
+        let value = vec![7u8; 1 << 20];
+        storage_write(b"blob", &value);
+        assert!(!storage_read_to_register(b"missing", 1));
+        assert!(storage_read_to_register(b"blob", 1));
+        assert_eq!(register_len(1), Some(value.len() as u64));
+
+        let mut buf = Vec::with_capacity(value.len());
+        let ptr = buf.as_ptr();
+        assert!(read_register_into(1, &mut buf));
+        assert_eq!(buf, value);
+        // The value was read into the existing allocation.
+        assert_eq!(buf.as_ptr(), ptr);
+        assert!(!read_register_into(2, &mut buf));
+    }
+
+    #[test]
+    fn test_context_cache() {
+        use crate::test_utils::test_env::setup;
+        use crate::test_utils::VMContextBuilder;
+
+        setup();
+        let gas_before_first = used_gas();
+        assert_eq!(String::from(current_account_id()), "alice");
+        let gas_before_second = used_gas();
+        assert_eq!(String::from(current_account_id()), "alice");
+        let gas_after_second = used_gas();
+        // The second read doesn't call the host, so it only costs the `used_gas` call.
+        assert_eq!(gas_after_second - gas_before_second, used_gas() - gas_after_second);
+        assert!(gas_after_second - gas_before_second < gas_before_second - gas_before_first);
+
+        crate::testing_env!(VMContextBuilder::new()
+            .current_account_id("bob".to_string())
+            .block_index(42)
+            .build());
+        assert_eq!(String::from(current_account_id()), "bob");
+        assert_eq!(block_index(), 42);
+    }
+
+    #[test]
+    fn test_block_timestamp_ms() {
+        use crate::test_utils::VMContextBuilder;
+
+        for nanos in &[0, 999_999, 1_600_000_000_123_456_789, u64::max_value()] {
+            crate::testing_env!(VMContextBuilder::new().block_timestamp(*nanos).build());
+            assert_eq!(block_timestamp(), *nanos);
+            assert_eq!(block_timestamp_ms(), *nanos / 1_000_000);
+        }
+    }
+
+    #[test]
+    fn test_input_len() {
+        use crate::test_utils::VMContextBuilder;
+
+        for input in &[vec![], vec![7u8; 1000]] {
+            crate::testing_env!(VMContextBuilder::new().input(input.clone()).build());
+            let gas_before_len = used_gas();
+            assert_eq!(input_len(), input.len() as u64);
+            let gas_before_read = used_gas();
+            assert_eq!(super::input().as_ref(), Some(input));
+            // Reading the input also charges for copying the register into the memory.
+            assert!(gas_before_read - gas_before_len < used_gas() - gas_before_read);
+        }
+    }
+
+    #[test]
+    fn test_value_return_raw_bytes() {
+        use crate::test_utils::{get_return_data, test_env};
+        use near_vm_logic::types::ReturnData;
+
+        test_env::setup();
+        // What a method with `#[result_serializer(raw)]` returns, not valid JSON or UTF-8.
+        let payload = vec![0u8, 159, 146, 150, 255, 1];
+        value_return(&payload);
+        match get_return_data() {
+            ReturnData::Value(value) => assert_eq!(value, payload),
+            _ => panic!("Expected the value to be returned"),
+        }
+    }
+
+    #[test]
+    fn test_promise_return() {
+        use crate::test_utils::{get_return_data, test_env};
+        use near_vm_logic::types::ReturnData;
+
+        test_env::setup();
+        let promise_idx = promise_batch_create(&test_env::bob());
+        promise_return(promise_idx);
+        match get_return_data() {
+            ReturnData::ReceiptIndex(idx) => assert_eq!(idx, promise_idx),
+            _ => panic!("Expected the promise to be returned"),
+        }
+    }
+
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn test_keccak512() {
+        crate::test_utils::test_env::setup();
+        assert_eq!(
+            to_hex(&keccak512(b"")),
+            "0eab42de4c3ceb9235fc91acffe746b29c29a8c366b7c60e4e67c466f36a4304\
+             c00fa9caf9d87976ba469bcbe06713b435f091ef2769fb160cdab33d3670680e"
+        );
+    }
+
+    #[cfg(feature = "sha512")]
+    #[test]
+    fn test_sha512() {
+        assert_eq!(
+            to_hex(&sha512(b"")),
+            "cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce\
+             47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e"
+        );
+        assert_eq!(
+            to_hex(&sha512(b"abc")),
+            "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+             2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+        );
+    }
+
+    #[test]
+    fn test_ecrecover() {
+        fn from_hex(hex: &str) -> Vec<u8> {
+            (0..hex.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+                .collect()
+        }
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(&sha256(b"hello"));
+        let mut sig = [0u8; 64];
+        sig.copy_from_slice(&from_hex(
+            "11527e8407fa8ea5562f48df653d5aef2b87dd7a9322253a6a004812b4336cfb\
+             3a26cdd336733b248bd16247dec3e14abd912dbea479ec82b8c90da978ce1112",
+        ));
+        // The same signature with `s` replaced by `n - s`, which also recovers the key.
+        let mut high_s_sig = [0u8; 64];
+        high_s_sig.copy_from_slice(&from_hex(
+            "11527e8407fa8ea5562f48df653d5aef2b87dd7a9322253a6a004812b4336cfb\
+             c5d9322cc98cc4db742e9db8213c1eb3fd1daf280aceb3b9070950e35768302f",
+        ));
+        let public_key = from_hex(
+            "085fe2ca7a5758957ea811bd8e743d9cee6bc20072f1470a888c43a1091a8e8b\
+             6c24b94641fa44c371b757127afcba3652e884413ada780be21d0585190deeac",
+        );
+
+        crate::test_utils::test_env::setup();
+        assert_eq!(ecrecover(&hash, &sig, 1, true).unwrap().to_vec(), public_key);
+        assert_eq!(ecrecover(&hash, &high_s_sig, 0, false).unwrap().to_vec(), public_key);
+        assert_eq!(ecrecover(&hash, &high_s_sig, 0, true), None);
+        assert_ne!(ecrecover(&hash, &sig, 0, true).map(|key| key.to_vec()), Some(public_key));
+        assert_eq!(ecrecover(&hash, &[0; 64], 0, false), None);
+        assert_eq!(ecrecover(&hash, &[0xff; 64], 0, false), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "V recovery byte 0 through 3 are valid but was 4")]
+    fn test_ecrecover_invalid_v() {
+        crate::test_utils::test_env::setup();
+        ecrecover(&[0; 32], &[1; 64], 4, false);
+    }
+
+    #[test]
+    fn test_storage_write_evicted() {
+        crate::test_utils::test_env::setup();
+        assert_eq!(storage_write_evicted(b"key", b"first"), None);
+        assert_eq!(storage_write_evicted(b"key", b"second"), Some(b"first".to_vec()));
+        assert_eq!(storage_read(b"key"), Some(b"second".to_vec()));
+        assert!(storage_write(b"key", b"third"));
+        assert!(!storage_write(b"other", b"value"));
+    }
+
+    #[test]
+    fn test_promise_batch_deploy_and_stake() {
+        use crate::test_utils::{get_created_receipts, test_env};
+
+        test_env::setup();
+        let public_key = vec![0u8; 33];
+        let promise_idx = promise_batch_create(&test_env::bob());
+        promise_batch_action_create_account(promise_idx);
+        promise_batch_action_deploy_contract(promise_idx, &[1, 2, 3]);
+        promise_batch_action_stake(promise_idx, 1_000, &public_key);
+
+        let receipts = get_created_receipts();
+        assert_eq!(receipts.len(), 1);
+        let receipt = format!("{:?}", receipts[0]);
+        assert!(receipt.contains("CreateAccount"));
+        assert!(receipt.contains("DeployContract"));
+        assert!(receipt.contains("code: [1, 2, 3]"));
+        assert!(receipt.contains("Stake"));
+        assert!(receipt.contains("stake: 1000"));
+        assert!(receipt.contains(&format!("{:?}", public_key)));
+    }
+
+    #[test]
+    fn test_promise_function_call_json() {
+        use crate::test_utils::{get_created_receipts, test_env};
+
+        #[derive(serde::Serialize)]
+        struct TransferArgs {
+            receiver_id: String,
+            amount: u64,
+        }
+
+        test_env::setup();
+        let args = TransferArgs { receiver_id: "carol.near".to_string(), amount: 5 };
+        promise_function_call_json(&test_env::bob(), "ft_transfer", &args, 1, 1_000);
+
+        let receipts = get_created_receipts();
+        assert_eq!(receipts.len(), 1);
+        let receipt = format!("{:?}", receipts[0]);
+        assert!(receipt.contains(&format!("{:?}", test_env::bob())));
+        assert!(receipt.contains("FunctionCall"));
+        assert!(receipt.contains(&format!("{:?}", b"ft_transfer".to_vec())));
+        let expected_args = br#"{"receiver_id":"carol.near","amount":5}"#.to_vec();
+        assert!(receipt.contains(&format!("{:?}", expected_args)));
+        assert!(receipt.contains("gas: 1000"));
+    }
+
+    #[test]
+    fn test_promise_and_then() {
+        use crate::test_utils::{get_created_receipts, test_env};
+
+        test_env::setup();
+        let first = promise_create(test_env::bob(), b"get", b"{}", 0, 1_000);
+        let second = promise_create(test_env::carol(), b"get", b"{}", 0, 1_000);
+        let joined = promise_and(&[first, second]);
+        promise_then(joined, test_env::alice(), b"on_get", b"{}", 0, 1_000);
+
+        let receipts = get_created_receipts();
+        assert_eq!(receipts.len(), 3);
+        // The callback waits for both of the joined promises.
+        let callback = format!("{:?}", receipts[2]);
+        assert!(callback.contains(&format!("{:?}", test_env::alice())));
+        assert!(callback.contains(&format!("receipt_indices: [{}, {}]", first, second)));
+        assert!(callback.contains(&format!("{:?}", b"on_get".to_vec())));
+    }
+
+    #[test]
+    fn test_promise_batch_access_keys() {
+        use crate::test_utils::{get_created_receipts, test_env};
+
+        test_env::setup();
+        let full_access_key = vec![1u8; 33];
+        let function_call_key = vec![2u8; 33];
+        let promise_idx = promise_batch_create(&test_env::bob());
+        promise_batch_action_add_key_with_full_access(promise_idx, &full_access_key, 7);
+        promise_batch_action_add_key_with_function_call(
+            promise_idx,
+            &function_call_key,
+            8,
+            500,
+            &test_env::alice(),
+            b"inc,dec",
+        );
+        promise_batch_action_delete_key(promise_idx, &full_access_key);
+
+        let receipt = format!("{:?}", get_created_receipts()[0]);
+        assert!(receipt.contains("AddKeyWithFullAccess"));
+        assert!(receipt.contains("nonce: 7"));
+        assert!(receipt.contains("AddKeyWithFunctionCall"));
+        assert!(receipt.contains("nonce: 8"));
+        assert!(receipt.contains("500"));
+        assert!(receipt.contains(&format!("{:?}", test_env::alice())));
+        assert!(receipt.contains("DeleteKey"));
+        assert!(receipt.contains(&format!("{:?}", full_access_key)));
+        assert!(receipt.contains(&format!("{:?}", function_call_key)));
+    }
+
+    #[test]
+    fn test_log_utf16() {
+        crate::test_utils::test_env::setup();
+        let message: Vec<u16> = "héllo 世界".encode_utf16().collect();
+        log_utf16(&message);
+        log_str("done");
+        assert_eq!(
+            crate::test_utils::get_logs(),
+            vec!["héllo 世界".to_string(), "done".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_state_remove_and_clear_storage() {
+        crate::test_utils::test_env::setup();
+        state_write(&42u64);
+        storage_write(b"key", b"value");
+        assert!(state_remove());
+        assert!(!state_exists());
+        assert!(!state_remove());
+        assert!(storage_has_key(b"key"));
+
+        state_write(&42u64);
+        crate::test_utils::clear_storage();
+        // A new mocked blockchain of the same test keeps the storage, unless it was cleared.
+        crate::test_utils::test_env::setup();
+        assert!(!state_exists());
+        assert!(!storage_has_key(b"key"));
+    }
+
+    #[test]
+    #[cfg(all(feature = "gas-profile", debug_assertions))]
+    fn test_log_gas_profile() {
+        crate::test_utils::test_env::setup();
+        storage_write(b"key", b"value");
+        storage_write(b"key", b"other value");
+        sha256(b"value");
+        log_gas_profile();
+        let logs = crate::test_utils::get_logs();
+        assert_eq!(logs.len(), 1);
+        assert!(logs[0].starts_with("Gas profile: "));
+        assert!(logs[0].contains("sha256 x1 = "));
+        assert!(logs[0].contains("storage_write x2 = "));
+        assert!(!logs[0].contains("used_gas"));
+
+        // The profile is reset after it is logged, and survives taking and setting the interface.
+        crate::test_utils::get_logs();
+        storage_read(b"key");
+        log_gas_profile();
+        let logs = crate::test_utils::get_logs();
+        assert!(logs[1].contains("log_utf8 x1 = "));
+        assert!(logs[1].contains("storage_read x1 = "));
+        assert!(!logs[1].contains("storage_write"));
+    }
 }
@@ -0,0 +1,214 @@
+//! Blockchain interface wrapper that records the gas burnt by every host call, so that the gas
+//! cost of a method can be broken down by host function. It is only compiled in debug builds with
+//! the `gas-profile` feature, see `env::log_gas_profile`.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+use near_vm_logic::types::Gas;
+
+use crate::environment::blockchain_interface::BlockchainInterface;
+use crate::MockedBlockchain;
+
+thread_local! {
+    /// The number of calls and the total gas of every host function called since the last
+    /// `take_breakdown`.
+    static BREAKDOWN: RefCell<BTreeMap<&'static str, (u64, Gas)>> = RefCell::new(BTreeMap::new());
+}
+
+/// Returns the recorded calls sorted by the name of the host function and resets them.
+pub(crate) fn take_breakdown() -> Vec<(&'static str, u64, Gas)> {
+    BREAKDOWN
+        .with(|b| std::mem::take(&mut *b.borrow_mut()))
+        .into_iter()
+        .map(|(name, (calls, gas))| (name, calls, gas))
+        .collect()
+}
+
+/// Delegates every host call to the wrapped interface and records the difference of `used_gas`
+/// around it. The difference includes the base cost of the second `used_gas` call, and
+/// `used_gas` itself is not recorded.
+pub(crate) struct GasProfiler {
+    inner: Box<dyn BlockchainInterface>,
+}
+
+impl GasProfiler {
+    pub(crate) fn new(inner: Box<dyn BlockchainInterface>) -> Self {
+        Self { inner }
+    }
+
+    unsafe fn record<T>(&self, name: &'static str, call: impl FnOnce() -> T) -> T {
+        let before = self.inner.used_gas();
+        let result = call();
+        let gas = self.inner.used_gas().saturating_sub(before);
+        BREAKDOWN.with(|b| {
+            let mut breakdown = b.borrow_mut();
+            let entry = breakdown.entry(name).or_insert((0, 0));
+            entry.0 += 1;
+            entry.1 += gas;
+        });
+        result
+    }
+}
+
+macro_rules! profiled_methods {
+    ($(fn $name:ident($($arg:ident: $ty:ty),*) $(-> $ret:ty)?;)*) => {
+        $(
+            unsafe fn $name(&self, $($arg: $ty),*) $(-> $ret)? {
+                self.record(stringify!($name), || self.inner.$name($($arg),*))
+            }
+        )*
+    };
+}
+
+#[allow(clippy::too_many_arguments)]
+impl BlockchainInterface for GasProfiler {
+    profiled_methods! {
+        fn read_register(register_id: u64, ptr: u64);
+        fn register_len(register_id: u64) -> u64;
+        fn current_account_id(register_id: u64);
+        fn signer_account_id(register_id: u64);
+        fn signer_account_pk(register_id: u64);
+        fn predecessor_account_id(register_id: u64);
+        fn input(register_id: u64);
+        fn block_index() -> u64;
+        fn block_timestamp() -> u64;
+        fn epoch_height() -> u64;
+        fn storage_usage() -> u64;
+        fn account_balance(balance_ptr: u64);
+        fn account_locked_balance(balance_ptr: u64);
+        fn attached_deposit(balance_ptr: u64);
+        fn prepaid_gas() -> u64;
+        fn random_seed(register_id: u64);
+        fn sha256(value_len: u64, value_ptr: u64, register_id: u64);
+        fn keccak256(value_len: u64, value_ptr: u64, register_id: u64);
+        fn keccak512(value_len: u64, value_ptr: u64, register_id: u64);
+        fn alt_bn128_g1_multiexp(value_len: u64, value_ptr: u64, register_id: u64);
+        fn alt_bn128_g1_sum(value_len: u64, value_ptr: u64, register_id: u64);
+        fn alt_bn128_pairing_check(value_len: u64, value_ptr: u64) -> u64;
+        fn ecrecover(
+            hash_len: u64,
+            hash_ptr: u64,
+            sig_len: u64,
+            sig_ptr: u64,
+            v: u64,
+            malleability_flag: u64,
+            register_id: u64
+        ) -> u64;
+        fn value_return(value_len: u64, value_ptr: u64);
+        fn panic();
+        fn panic_utf8(len: u64, ptr: u64);
+        fn log_utf8(len: u64, ptr: u64);
+        fn log_utf16(len: u64, ptr: u64);
+        fn promise_create(
+            account_id_len: u64,
+            account_id_ptr: u64,
+            method_name_len: u64,
+            method_name_ptr: u64,
+            arguments_len: u64,
+            arguments_ptr: u64,
+            amount_ptr: u64,
+            gas: u64
+        ) -> u64;
+        fn promise_then(
+            promise_index: u64,
+            account_id_len: u64,
+            account_id_ptr: u64,
+            method_name_len: u64,
+            method_name_ptr: u64,
+            arguments_len: u64,
+            arguments_ptr: u64,
+            amount_ptr: u64,
+            gas: u64
+        ) -> u64;
+        fn promise_and(promise_idx_ptr: u64, promise_idx_count: u64) -> u64;
+        fn promise_batch_create(account_id_len: u64, account_id_ptr: u64) -> u64;
+        fn promise_batch_then(promise_index: u64, account_id_len: u64, account_id_ptr: u64) -> u64;
+        fn promise_batch_action_create_account(promise_index: u64);
+        fn promise_batch_action_deploy_contract(promise_index: u64, code_len: u64, code_ptr: u64);
+        fn promise_batch_action_function_call(
+            promise_index: u64,
+            method_name_len: u64,
+            method_name_ptr: u64,
+            arguments_len: u64,
+            arguments_ptr: u64,
+            amount_ptr: u64,
+            gas: u64
+        );
+        fn promise_batch_action_function_call_weight(
+            promise_index: u64,
+            method_name_len: u64,
+            method_name_ptr: u64,
+            arguments_len: u64,
+            arguments_ptr: u64,
+            amount_ptr: u64,
+            gas: u64,
+            gas_weight: u64
+        );
+        fn promise_batch_action_transfer(promise_index: u64, amount_ptr: u64);
+        fn promise_batch_action_stake(
+            promise_index: u64,
+            amount_ptr: u64,
+            public_key_len: u64,
+            public_key_ptr: u64
+        );
+        fn promise_batch_action_add_key_with_full_access(
+            promise_index: u64,
+            public_key_len: u64,
+            public_key_ptr: u64,
+            nonce: u64
+        );
+        fn promise_batch_action_add_key_with_function_call(
+            promise_index: u64,
+            public_key_len: u64,
+            public_key_ptr: u64,
+            nonce: u64,
+            allowance_ptr: u64,
+            receiver_id_len: u64,
+            receiver_id_ptr: u64,
+            method_names_len: u64,
+            method_names_ptr: u64
+        );
+        fn promise_batch_action_delete_key(
+            promise_index: u64,
+            public_key_len: u64,
+            public_key_ptr: u64
+        );
+        fn promise_batch_action_delete_account(
+            promise_index: u64,
+            beneficiary_id_len: u64,
+            beneficiary_id_ptr: u64
+        );
+        fn promise_results_count() -> u64;
+        fn promise_result(result_idx: u64, register_id: u64) -> u64;
+        fn promise_return(promise_id: u64);
+        fn storage_write(
+            key_len: u64,
+            key_ptr: u64,
+            value_len: u64,
+            value_ptr: u64,
+            register_id: u64
+        ) -> u64;
+        fn storage_read(key_len: u64, key_ptr: u64, register_id: u64) -> u64;
+        fn storage_remove(key_len: u64, key_ptr: u64, register_id: u64) -> u64;
+        fn storage_has_key(key_len: u64, key_ptr: u64) -> u64;
+        fn validator_stake(account_id_len: u64, account_id_ptr: u64, stake_ptr: u64);
+        fn validator_total_stake(stake_ptr: u64);
+    }
+
+    unsafe fn used_gas(&self) -> u64 {
+        self.inner.used_gas()
+    }
+
+    fn as_mut_mocked_blockchain(&mut self) -> Option<&mut MockedBlockchain> {
+        self.inner.as_mut_mocked_blockchain()
+    }
+
+    fn as_mocked_blockchain(&self) -> Option<&MockedBlockchain> {
+        self.inner.as_mocked_blockchain()
+    }
+
+    fn is_gas_profiler(&self) -> bool {
+        true
+    }
+}
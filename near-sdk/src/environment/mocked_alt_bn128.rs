@@ -0,0 +1,105 @@
+//! The alt_bn128 host functions of `MockedBlockchain`, which are computed with the `zeropool-bn`
+//! crate, the same implementation the runtime uses, since `near-vm-logic` 2.0.0 doesn't have them.
+//! The encoding of the inputs and outputs is the one of the host functions, see
+//! `env::alt_bn128_g1_multiexp`. Unlike the runtime, the mock doesn't charge gas for them.
+
+use zeropool_bn::arith::U256;
+use zeropool_bn::{pairing_batch, AffineG1, AffineG2, Fq, Fq2, Fr, Group, Gt, G1, G2};
+
+const FQ_LEN: usize = 32;
+const G1_LEN: usize = 2 * FQ_LEN;
+const G2_LEN: usize = 4 * FQ_LEN;
+const SCALAR_LEN: usize = 32;
+
+/// Panics like the errors of `VMLogic`, so that the tests can assert the message.
+fn invalid_input(msg: &str) -> ! {
+    panic!("AltBn128InvalidInput: {}", msg)
+}
+
+/// Little-endian bytes reversed into the big-endian order of `zeropool-bn`.
+fn to_big_endian(bytes: &[u8]) -> [u8; 32] {
+    let mut result = [0u8; 32];
+    result.copy_from_slice(bytes);
+    result.reverse();
+    result
+}
+
+fn decode_fq(bytes: &[u8]) -> Fq {
+    Fq::from_slice(&to_big_endian(bytes))
+        .unwrap_or_else(|_| invalid_input("the coordinate is not less than the modulus"))
+}
+
+fn decode_scalar(bytes: &[u8]) -> Fr {
+    // The scalars are reduced modulo the group order like in the runtime.
+    Fr::new_mul_factor(U256::from_slice(&to_big_endian(bytes)).unwrap())
+}
+
+/// Decodes the point, where `(0, 0)` is the point at infinity.
+fn decode_g1(bytes: &[u8]) -> G1 {
+    let (x, y) = (decode_fq(&bytes[..FQ_LEN]), decode_fq(&bytes[FQ_LEN..]));
+    if x == Fq::zero() && y == Fq::zero() {
+        return G1::zero();
+    }
+    AffineG1::new(x, y)
+        .map(G1::from)
+        .unwrap_or_else(|_| invalid_input("the G1 point is not on the curve"))
+}
+
+/// Decodes the point with the coordinates in `Fq2`, where the real part goes first.
+fn decode_g2(bytes: &[u8]) -> G2 {
+    let x = Fq2::new(decode_fq(&bytes[..FQ_LEN]), decode_fq(&bytes[FQ_LEN..2 * FQ_LEN]));
+    let y = Fq2::new(decode_fq(&bytes[2 * FQ_LEN..3 * FQ_LEN]), decode_fq(&bytes[3 * FQ_LEN..]));
+    if x == Fq2::zero() && y == Fq2::zero() {
+        return G2::zero();
+    }
+    AffineG2::new(x, y)
+        .map(G2::from)
+        .unwrap_or_else(|_| invalid_input("the G2 point is not on the curve"))
+}
+
+fn encode_fq(value: Fq) -> [u8; 32] {
+    let mut result = [0u8; 32];
+    value.to_big_endian(&mut result).unwrap();
+    result.reverse();
+    result
+}
+
+fn encode_g1(point: G1) -> Vec<u8> {
+    match AffineG1::from_jacobian(point) {
+        Some(point) => [encode_fq(point.x()), encode_fq(point.y())].concat(),
+        None => vec![0; G1_LEN],
+    }
+}
+
+fn chunks(value: &[u8], len: usize) -> std::slice::Chunks<u8> {
+    if value.len() % len != 0 {
+        invalid_input(&format!("the length of the input is not a multiple of {}", len));
+    }
+    value.chunks(len)
+}
+
+pub(crate) fn g1_multiexp(value: &[u8]) -> Vec<u8> {
+    let result = chunks(value, G1_LEN + SCALAR_LEN).fold(G1::zero(), |sum, item| {
+        sum + decode_g1(&item[..G1_LEN]) * decode_scalar(&item[G1_LEN..])
+    });
+    encode_g1(result)
+}
+
+pub(crate) fn g1_sum(value: &[u8]) -> Vec<u8> {
+    let result = chunks(value, 1 + G1_LEN).fold(G1::zero(), |sum, item| {
+        let point = decode_g1(&item[1..]);
+        match item[0] {
+            0 => sum + point,
+            1 => sum - point,
+            _ => invalid_input("the sign is neither 0 nor 1"),
+        }
+    });
+    encode_g1(result)
+}
+
+pub(crate) fn pairing_check(value: &[u8]) -> bool {
+    let pairs: Vec<(G1, G2)> = chunks(value, G1_LEN + G2_LEN)
+        .map(|item| (decode_g1(&item[..G1_LEN]), decode_g2(&item[G1_LEN..])))
+        .collect();
+    pairs.is_empty() || pairing_batch(&pairs) == Gt::one()
+}
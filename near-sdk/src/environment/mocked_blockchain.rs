@@ -1,8 +1,9 @@
 use crate::environment::blockchain_interface::BlockchainInterface;
+use crate::environment::{mocked_alt_bn128, mocked_secp256k1};
 use near_runtime_fees::RuntimeFeesConfig;
 use near_vm_logic::mocks::mock_external::{MockedExternal, Receipt};
 use near_vm_logic::mocks::mock_memory::MockedMemory;
-use near_vm_logic::types::{AccountId, Balance, PromiseResult};
+use near_vm_logic::types::{AccountId, Balance, PromiseResult, ReturnData};
 use near_vm_logic::{External, MemoryLike, VMConfig, VMContext, VMLogic};
 use std::cell::RefCell;
 use std::collections::HashMap;
@@ -14,6 +15,7 @@ use std::collections::HashMap;
 /// message.
 pub struct MockedBlockchain {
     logic: RefCell<VMLogic<'static>>,
+    gas_weights: RefCell<Vec<(u64, u64)>>,
     // We keep ownership over logic fixture so that references in `VMLogic` are valid.
     #[allow(dead_code)]
     logic_fixture: LogicFixture,
@@ -60,7 +62,7 @@ impl MockedBlockchain {
         };
 
         let logic = RefCell::new(logic);
-        Self { logic, logic_fixture }
+        Self { logic, gas_weights: RefCell::new(vec![]), logic_fixture }
     }
 
     pub fn take_storage(&mut self) -> HashMap<Vec<u8>, Vec<u8>> {
@@ -71,9 +73,25 @@ impl MockedBlockchain {
         self.logic_fixture.ext.get_receipt_create_calls()
     }
 
+    /// The promise index and the gas weight of every function call scheduled with a weight, in
+    /// the order of the calls.
+    pub fn gas_weights(&self) -> Vec<(u64, u64)> {
+        self.gas_weights.borrow().clone()
+    }
+
     pub fn logs(&self) -> Vec<String> {
         self.logic.borrow().clone_outcome().logs
     }
+
+    /// The value or the promise returned by the contract so far.
+    pub fn return_data(&self) -> ReturnData {
+        self.logic.borrow().clone_outcome().return_data
+    }
+}
+
+/// The memory of the guest passed by a pointer, which is a pointer of the host for `MockedMemory`.
+unsafe fn guest_slice<'a>(len: u64, ptr: u64) -> &'a [u8] {
+    std::slice::from_raw_parts(ptr as *const u8, len as usize)
 }
 
 impl BlockchainInterface for MockedBlockchain {
@@ -157,6 +175,44 @@ impl BlockchainInterface for MockedBlockchain {
         self.logic.borrow_mut().keccak512(value_len, value_ptr, register_id).unwrap()
     }
 
+    unsafe fn alt_bn128_g1_multiexp(&self, value_len: u64, value_ptr: u64, register_id: u64) {
+        let result = mocked_alt_bn128::g1_multiexp(guest_slice(value_len, value_ptr));
+        self.logic.borrow_mut().wrapped_internal_write_register(register_id, &result).unwrap()
+    }
+
+    unsafe fn alt_bn128_g1_sum(&self, value_len: u64, value_ptr: u64, register_id: u64) {
+        let result = mocked_alt_bn128::g1_sum(guest_slice(value_len, value_ptr));
+        self.logic.borrow_mut().wrapped_internal_write_register(register_id, &result).unwrap()
+    }
+
+    unsafe fn alt_bn128_pairing_check(&self, value_len: u64, value_ptr: u64) -> u64 {
+        mocked_alt_bn128::pairing_check(guest_slice(value_len, value_ptr)) as u64
+    }
+
+    unsafe fn ecrecover(
+        &self,
+        hash_len: u64,
+        hash_ptr: u64,
+        sig_len: u64,
+        sig_ptr: u64,
+        v: u64,
+        malleability_flag: u64,
+        register_id: u64,
+    ) -> u64 {
+        let hash = guest_slice(hash_len, hash_ptr);
+        let sig = guest_slice(sig_len, sig_ptr);
+        match mocked_secp256k1::ecrecover(hash, sig, v, malleability_flag) {
+            Some(public_key) => {
+                self.logic
+                    .borrow_mut()
+                    .wrapped_internal_write_register(register_id, &public_key)
+                    .unwrap();
+                1
+            }
+            None => 0,
+        }
+    }
+
     unsafe fn value_return(&self, value_len: u64, value_ptr: u64) {
         self.logic.borrow_mut().value_return(value_len, value_ptr).unwrap()
     }
@@ -286,6 +342,33 @@ impl BlockchainInterface for MockedBlockchain {
             )
             .unwrap()
     }
+    unsafe fn promise_batch_action_function_call_weight(
+        &self,
+        promise_index: u64,
+        method_name_len: u64,
+        method_name_ptr: u64,
+        arguments_len: u64,
+        arguments_ptr: u64,
+        amount_ptr: u64,
+        gas: u64,
+        gas_weight: u64,
+    ) {
+        // `near-vm-logic` 2.0.0 has no weighted function calls, so the call is scheduled with the
+        // minimum gas and the weight is recorded next to the receipts.
+        self.logic
+            .borrow_mut()
+            .promise_batch_action_function_call(
+                promise_index,
+                method_name_len,
+                method_name_ptr,
+                arguments_len,
+                arguments_ptr,
+                amount_ptr,
+                gas,
+            )
+            .unwrap();
+        self.gas_weights.borrow_mut().push((promise_index, gas_weight));
+    }
     unsafe fn promise_batch_action_transfer(&self, promise_index: u64, amount_ptr: u64) {
         self.logic.borrow_mut().promise_batch_action_transfer(promise_index, amount_ptr).unwrap()
     }
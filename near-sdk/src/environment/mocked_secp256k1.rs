@@ -0,0 +1,53 @@
+//! The `ecrecover` host function of `MockedBlockchain`, which is computed with the pure Rust
+//! `libsecp256k1` crate since `near-vm-logic` 2.0.0 doesn't have it. The checks of the inputs are
+//! the ones of the runtime, see `env::ecrecover`. Unlike the runtime, the mock doesn't charge gas.
+
+use secp256k1::{Message, RecoveryId, Signature};
+
+/// The order of the secp256k1 group in big-endian.
+const GROUP_ORDER: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe,
+    0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x41,
+];
+
+/// Panics like the errors of `VMLogic`, so that the tests can assert the message.
+fn invalid_input(msg: &str) -> ! {
+    panic!("ECRecoverError: {}", msg)
+}
+
+/// Returns the 64-byte public key without the `0x04` prefix, or `None` if the signature is
+/// invalid or doesn't recover a key.
+pub(crate) fn ecrecover(
+    hash: &[u8],
+    sig: &[u8],
+    v: u64,
+    malleability_flag: u64,
+) -> Option<Vec<u8>> {
+    if hash.len() != 32 {
+        invalid_input("the hash is not 32 bytes long");
+    }
+    if sig.len() != 64 {
+        invalid_input("the signature is not 64 bytes long");
+    }
+    if v >= 4 {
+        invalid_input(&format!("V recovery byte 0 through 3 are valid but was {}", v));
+    }
+    // `r` and `s` have to be in `1..GROUP_ORDER`, while the parsing would reduce them.
+    let (r, s) = sig.split_at(32);
+    if [r, s].iter().any(|value| value.iter().all(|&b| b == 0) || **value >= GROUP_ORDER[..]) {
+        return None;
+    }
+    let mut hash_bytes = [0u8; 32];
+    hash_bytes.copy_from_slice(hash);
+    let mut sig_bytes = [0u8; 64];
+    sig_bytes.copy_from_slice(sig);
+    let signature = Signature::parse(&sig_bytes);
+    // High `s` values are rejected to prevent the malleability of the signatures.
+    if malleability_flag != 0 && signature.s.is_high() {
+        return None;
+    }
+    let recovery_id = RecoveryId::parse(v as u8).ok()?;
+    let public_key =
+        secp256k1::recover(&Message::parse(&hash_bytes), &signature, &recovery_id).ok()?;
+    Some(public_key.serialize()[1..].to_vec())
+}
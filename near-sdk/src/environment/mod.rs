@@ -1,3 +1,9 @@
 pub mod blockchain_interface;
 pub mod env;
+#[cfg(all(feature = "gas-profile", debug_assertions))]
+mod gas_profile;
+mod mocked_alt_bn128;
 pub mod mocked_blockchain;
+mod mocked_secp256k1;
+#[cfg(feature = "poseidon")]
+mod poseidon;
@@ -0,0 +1,128 @@
+//! Poseidon hash over the scalar field of alt_bn128, with the parameters of circomlib, so that the
+//! hashes computed by the contract match the ones of the circuits. The runtime doesn't provide
+//! Poseidon, so it is computed inside the contract.
+
+mod constants;
+
+use constants::{MDS_2, MDS_3, MDS_4, ROUND_CONSTANTS_2, ROUND_CONSTANTS_3, ROUND_CONSTANTS_4};
+
+use crate::bn254::{Limbs, SCALAR_FIELD};
+
+const FULL_ROUNDS: usize = 8;
+
+/// Element of the scalar field in Montgomery form.
+#[derive(Clone, Copy)]
+struct Fr(Limbs);
+
+impl Fr {
+    const ZERO: Fr = Fr([0; 4]);
+
+    fn from_le_bytes(bytes: &[u8; 32]) -> Option<Self> {
+        SCALAR_FIELD.from_le_bytes(bytes).map(Fr)
+    }
+
+    fn to_le_bytes(self) -> [u8; 32] {
+        SCALAR_FIELD.to_le_bytes(&self.0)
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        Fr(SCALAR_FIELD.add(&self.0, &other.0))
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        Fr(SCALAR_FIELD.mul(&self.0, &other.0))
+    }
+
+    fn pow5(&self) -> Self {
+        let square = self.mul(self);
+        square.mul(&square).mul(self)
+    }
+}
+
+/// Hashes 1 to 3 field elements, encoded as little-endian `u256`, into a field element.
+/// The state of the permutation is the capacity element `0` followed by the inputs, and the
+/// hash is the first element of the permuted state.
+pub fn poseidon(inputs: &[[u8; 32]]) -> [u8; 32] {
+    let (round_constants, mds, partial_rounds): (&[Limbs], &[Limbs], usize) = match inputs.len() {
+        1 => (&ROUND_CONSTANTS_2[..], &MDS_2[..], 56),
+        2 => (&ROUND_CONSTANTS_3[..], &MDS_3[..], 57),
+        3 => (&ROUND_CONSTANTS_4[..], &MDS_4[..], 56),
+        _ => panic!("Poseidon supports from 1 to 3 inputs"),
+    };
+    let width = inputs.len() + 1;
+    let mut state = vec![Fr::ZERO];
+    for input in inputs {
+        state
+            .push(Fr::from_le_bytes(input).expect("Poseidon input is not an element of the field"));
+    }
+    for round in 0..FULL_ROUNDS + partial_rounds {
+        for (element, constant) in state.iter_mut().zip(&round_constants[round * width..]) {
+            *element = element.add(&Fr(*constant));
+        }
+        if round < FULL_ROUNDS / 2 || round >= FULL_ROUNDS / 2 + partial_rounds {
+            for element in state.iter_mut() {
+                *element = element.pow5();
+            }
+        } else {
+            state[0] = state[0].pow5();
+        }
+        state = mds
+            .chunks(width)
+            .map(|row| {
+                row.iter()
+                    .zip(state.iter())
+                    .fold(Fr::ZERO, |acc, (entry, element)| acc.add(&Fr(*entry).mul(element)))
+            })
+            .collect();
+    }
+    state[0].to_le_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Big-endian hex of the little-endian number.
+    fn to_hex(value: &[u8; 32]) -> String {
+        value.iter().rev().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    fn scalar(value: u8) -> [u8; 32] {
+        let mut result = [0u8; 32];
+        result[0] = value;
+        result
+    }
+
+    #[test]
+    fn test_circomlib_vectors() {
+        assert_eq!(
+            to_hex(&poseidon(&[scalar(1)])),
+            "29176100eaa962bdc1fe6c654d6a3c130e96a4d1168b33848b897dc502820133"
+        );
+        assert_eq!(
+            to_hex(&poseidon(&[scalar(1), scalar(2)])),
+            "115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a"
+        );
+        assert_eq!(
+            to_hex(&poseidon(&[scalar(1), scalar(2), scalar(3)])),
+            "0e7732d89e6939c0ff03d5e58dab6302f3230e269dc5b968f725df34ab36d732"
+        );
+    }
+
+    #[test]
+    fn test_pow5() {
+        assert_eq!(Fr::from_le_bytes(&scalar(2)).unwrap().pow5().to_le_bytes(), scalar(32));
+    }
+
+    #[test]
+    #[should_panic(expected = "Poseidon input is not an element of the field")]
+    fn test_input_out_of_field() {
+        poseidon(&[[255; 32]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Poseidon supports from 1 to 3 inputs")]
+    fn test_too_many_inputs() {
+        poseidon(&[scalar(1); 4]);
+    }
+}
@@ -0,0 +1,346 @@
+//! Events in the NEP-297 format, which indexers and wallets parse from the logs of the contracts,
+//! with typed events of the fungible token (NEP-141) and non-fungible token (NEP-171) standards:
+//!
+//! ```
+//! # near_sdk::test_utils::test_env::setup();
+//! use near_sdk::events::{FtTransfer, StandardEvent};
+//!
+//! FtTransfer {
+//!     old_owner_id: "alice.near",
+//!     new_owner_id: "bob.near",
+//!     amount: 100.into(),
+//!     memo: Some("rent"),
+//! }
+//! .emit();
+//! ```
+
+use crate::env;
+use crate::json_types::{U128, U64};
+use serde::Serialize;
+
+/// Logs the event as `EVENT_JSON:{"standard":..,"version":..,"event":..,"data":..}`, which is
+/// the format of NEP-297.
+pub fn emit_event<T: Serialize + ?Sized>(standard: &str, version: &str, event: &str, data: &T) {
+    env::log_str(&event_log(standard, version, event, data));
+}
+
+/// The log entry of the event in the format of NEP-297.
+fn event_log<T: Serialize + ?Sized>(
+    standard: &str,
+    version: &str,
+    event: &str,
+    data: &T,
+) -> String {
+    #[derive(Serialize)]
+    struct Event<'a, T: ?Sized> {
+        standard: &'a str,
+        version: &'a str,
+        event: &'a str,
+        data: &'a T,
+    }
+
+    let event = Event { standard, version, event, data };
+    let json = serde_json::to_string(&event).expect("Failed to serialize the event using JSON.");
+    format!("EVENT_JSON:{}", json)
+}
+
+/// Events that are logged only when the buffer is flushed, each as a separate log entry in the
+/// order they were pushed. It lets a method decide which events to emit while it runs, e.g. skip
+/// the events of a batch that turned out to be empty, and log them all at the end:
+///
+/// ```
+/// # near_sdk::test_utils::test_env::setup();
+/// use near_sdk::events::{EventBuffer, FtMint, FtTransfer};
+///
+/// let mut events = EventBuffer::new();
+/// events.push(&FtMint { owner_id: "alice.near", amount: 100.into(), memo: None });
+/// events.push(&FtTransfer {
+///     old_owner_id: "alice.near",
+///     new_owner_id: "bob.near",
+///     amount: 10.into(),
+///     memo: None,
+/// });
+/// events.flush();
+/// ```
+///
+/// The events are serialized when they are pushed, so they may borrow values that don't outlive
+/// the method. Dropping the buffer without flushing discards the events.
+#[derive(Debug, Default, Clone)]
+pub struct EventBuffer {
+    logs: Vec<String>,
+}
+
+impl EventBuffer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the single event of a standard.
+    pub fn push<E: StandardEvent>(&mut self, event: &E) {
+        self.push_event(E::STANDARD, E::VERSION, E::EVENT, std::slice::from_ref(event));
+    }
+
+    /// Adds the event with arbitrary data, like `emit_event`.
+    pub fn push_event<T: Serialize + ?Sized>(
+        &mut self,
+        standard: &str,
+        version: &str,
+        event: &str,
+        data: &T,
+    ) {
+        self.logs.push(event_log(standard, version, event, data));
+    }
+
+    /// The number of buffered events.
+    pub fn len(&self) -> usize {
+        self.logs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.logs.is_empty()
+    }
+
+    /// Discards the buffered events.
+    pub fn clear(&mut self) {
+        self.logs.clear();
+    }
+
+    /// Logs the buffered events in the order they were pushed.
+    pub fn flush(self) {
+        for log in &self.logs {
+            env::log_str(log);
+        }
+    }
+}
+
+/// Event of a token standard, which is logged with the standard, version and name of the event
+/// and a list of the event values as the data.
+pub trait StandardEvent: Serialize + Sized {
+    /// Name of the standard, e.g. `nep141`.
+    const STANDARD: &'static str;
+    /// Version of the standard, e.g. `1.0.0`.
+    const VERSION: &'static str;
+    /// Name of the event, e.g. `ft_transfer`.
+    const EVENT: &'static str;
+
+    /// Logs the single event.
+    fn emit(&self) {
+        Self::emit_many(std::slice::from_ref(self));
+    }
+
+    /// Logs the events as a single log entry, e.g. all the transfers of a batch.
+    fn emit_many(events: &[Self]) {
+        emit_event(Self::STANDARD, Self::VERSION, Self::EVENT, events);
+    }
+}
+
+/// Tokens minted to `owner_id`, the `ft_mint` event of NEP-141.
+#[derive(Serialize, Debug, Clone)]
+pub struct FtMint<'a> {
+    pub owner_id: &'a str,
+    pub amount: U128,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<&'a str>,
+}
+
+impl StandardEvent for FtMint<'_> {
+    const STANDARD: &'static str = "nep141";
+    const VERSION: &'static str = "1.0.0";
+    const EVENT: &'static str = "ft_mint";
+}
+
+/// Tokens transferred from `old_owner_id` to `new_owner_id`, the `ft_transfer` event of NEP-141.
+#[derive(Serialize, Debug, Clone)]
+pub struct FtTransfer<'a> {
+    pub old_owner_id: &'a str,
+    pub new_owner_id: &'a str,
+    pub amount: U128,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<&'a str>,
+}
+
+impl StandardEvent for FtTransfer<'_> {
+    const STANDARD: &'static str = "nep141";
+    const VERSION: &'static str = "1.0.0";
+    const EVENT: &'static str = "ft_transfer";
+}
+
+/// Non-fungible tokens transferred from `old_owner_id` to `new_owner_id`, the `nft_transfer` event
+/// of NEP-171. `authorized_id` is the approved account that made the transfer on behalf of the
+/// owner, if any.
+#[derive(Serialize, Debug, Clone)]
+pub struct NftTransfer<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorized_id: Option<&'a str>,
+    pub old_owner_id: &'a str,
+    pub new_owner_id: &'a str,
+    pub token_ids: &'a [&'a str],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<&'a str>,
+}
+
+impl StandardEvent for NftTransfer<'_> {
+    const STANDARD: &'static str = "nep171";
+    const VERSION: &'static str = "1.0.0";
+    const EVENT: &'static str = "nft_transfer";
+}
+
+/// Commitment appended to the Merkle tree of commitments, e.g. a `collections::MerkleTree`, as the
+/// leaf `index`, which made `root` the root of the tree. The hashes are hex strings.
+#[derive(Serialize, Debug, Clone)]
+pub struct CommitmentAppended {
+    pub root: String,
+    pub index: U64,
+    pub commitment: String,
+}
+
+impl StandardEvent for CommitmentAppended {
+    const STANDARD: &'static str = "merkle_tree";
+    const VERSION: &'static str = "1.0.0";
+    const EVENT: &'static str = "commitment_appended";
+}
+
+/// Logs the `CommitmentAppended` event, from which indexers reconstruct the tree off-chain.
+///
+/// ```
+/// # near_sdk::test_utils::test_env::setup();
+/// use near_sdk::collections::MerkleTree;
+/// use near_sdk::events::emit_commitment_event;
+///
+/// let mut commitments = MerkleTree::new(b"c".to_vec(), 20);
+/// let commitment = [1; 32];
+/// let root = commitments.append(&commitment);
+/// emit_commitment_event(&root, commitments.len() - 1, &commitment);
+/// ```
+pub fn emit_commitment_event(root: &[u8; 32], index: u64, commitment: &[u8; 32]) {
+    CommitmentAppended { root: to_hex(root), index: index.into(), commitment: to_hex(commitment) }
+        .emit();
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{get_logs, test_env};
+
+    #[test]
+    fn test_ft_mint() {
+        test_env::setup();
+        FtMint { owner_id: "alice.near", amount: 100.into(), memo: None }.emit();
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"nep141","version":"1.0.0","event":"ft_mint","data":[{"owner_id":"alice.near","amount":"100"}]}"#
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ft_transfer() {
+        test_env::setup();
+        FtTransfer::emit_many(&[
+            FtTransfer {
+                old_owner_id: "alice.near",
+                new_owner_id: "bob.near",
+                amount: 42.into(),
+                memo: Some("rent"),
+            },
+            FtTransfer {
+                old_owner_id: "bob.near",
+                new_owner_id: "carol.near",
+                amount: 7.into(),
+                memo: None,
+            },
+        ]);
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"nep141","version":"1.0.0","event":"ft_transfer","data":[{"old_owner_id":"alice.near","new_owner_id":"bob.near","amount":"42","memo":"rent"},{"old_owner_id":"bob.near","new_owner_id":"carol.near","amount":"7"}]}"#
+            ]
+        );
+    }
+
+    #[test]
+    fn test_nft_transfer() {
+        test_env::setup();
+        NftTransfer {
+            authorized_id: Some("market.near"),
+            old_owner_id: "alice.near",
+            new_owner_id: "bob.near",
+            token_ids: &["1", "2"],
+            memo: None,
+        }
+        .emit();
+        NftTransfer {
+            authorized_id: None,
+            old_owner_id: "bob.near",
+            new_owner_id: "carol.near",
+            token_ids: &["2"],
+            memo: Some("gift"),
+        }
+        .emit();
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"nep171","version":"1.0.0","event":"nft_transfer","data":[{"authorized_id":"market.near","old_owner_id":"alice.near","new_owner_id":"bob.near","token_ids":["1","2"]}]}"#,
+                r#"EVENT_JSON:{"standard":"nep171","version":"1.0.0","event":"nft_transfer","data":[{"old_owner_id":"bob.near","new_owner_id":"carol.near","token_ids":["2"],"memo":"gift"}]}"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_commitment_appended() {
+        test_env::setup();
+        let mut root = [0xab; 32];
+        root[0] = 0x01;
+        emit_commitment_event(&root, 5, &[0x10; 32]);
+        let logs = get_logs();
+        assert_eq!(
+            logs,
+            vec![format!(
+                r#"EVENT_JSON:{{"standard":"merkle_tree","version":"1.0.0","event":"commitment_appended","data":[{{"root":"01{}","index":"5","commitment":"{}"}}]}}"#,
+                "ab".repeat(31),
+                "10".repeat(32)
+            )]
+        );
+        let event: serde_json::Value =
+            serde_json::from_str(logs[0].trim_start_matches("EVENT_JSON:")).unwrap();
+        assert_eq!(event["data"][0]["index"], "5");
+        assert_eq!(event["data"][0]["root"].as_str().unwrap().len(), 64);
+    }
+
+    #[test]
+    fn test_event_buffer() {
+        test_env::setup();
+        let mut events = EventBuffer::new();
+        events.push(&FtMint { owner_id: "alice.near", amount: 100.into(), memo: None });
+        events.push(&FtMint { owner_id: "dave.near", amount: 1.into(), memo: None });
+        events.clear();
+        events.push(&FtMint { owner_id: "alice.near", amount: 100.into(), memo: None });
+        for (new_owner_id, amount) in &[("bob.near", 10u128), ("carol.near", 0), ("dave.near", 5)] {
+            if *amount > 0 {
+                events.push(&FtTransfer {
+                    old_owner_id: "alice.near",
+                    new_owner_id,
+                    amount: (*amount).into(),
+                    memo: None,
+                });
+            }
+        }
+        assert_eq!(events.len(), 3);
+        assert!(get_logs().is_empty());
+        events.flush();
+        assert_eq!(
+            get_logs(),
+            vec![
+                r#"EVENT_JSON:{"standard":"nep141","version":"1.0.0","event":"ft_mint","data":[{"owner_id":"alice.near","amount":"100"}]}"#,
+                r#"EVENT_JSON:{"standard":"nep141","version":"1.0.0","event":"ft_transfer","data":[{"old_owner_id":"alice.near","new_owner_id":"bob.near","amount":"10"}]}"#,
+                r#"EVENT_JSON:{"standard":"nep141","version":"1.0.0","event":"ft_transfer","data":[{"old_owner_id":"alice.near","new_owner_id":"dave.near","amount":"5"}]}"#,
+            ]
+        );
+    }
+}
@@ -0,0 +1,708 @@
+//! Verifier of Groth16 zk-SNARK proofs over the alt_bn128 curve, which runs the pairing check with
+//! the host functions, so that shielded pools don't have to implement it themselves:
+//!
+//! ```no_run
+//! use near_sdk::borsh::BorshDeserialize;
+//! use near_sdk::groth16::{verify_groth16, Proof, VerifyingKey};
+//! # let vk_bytes: Vec<u8> = vec![];
+//! # let proof_bytes: Vec<u8> = vec![];
+//! # let nullifier = near_sdk::json_types::FieldElement::default();
+//!
+//! let vk = VerifyingKey::try_from_slice(&vk_bytes).unwrap();
+//! let proof = Proof::try_from_slice(&proof_bytes).unwrap();
+//! assert!(verify_groth16(&vk, &proof, &[nullifier]));
+//! ```
+//!
+//! The points and scalars are in the little-endian encoding of the host functions, which is also
+//! their Borsh serialization. The Borsh serialization of `VerifyingKey` starts with the version
+//! of its layout, and its deserialization rejects points that are not on the curve, so a key
+//! stored in the state can't be silently corrupted.
+
+use crate::bn254::{Limbs, BASE_FIELD};
+use crate::collections::LazyOption;
+use crate::env;
+use crate::json_types::FieldElement;
+use crate::Gas;
+use borsh::{BorshDeserialize, BorshSerialize};
+use std::io::{self, Write};
+use std::iter;
+
+/// `b` of the G2 curve `y^2 = x^3 + b`, i.e. `3 / (9 + i)`, as `[c0, c1]`.
+const TWIST_B: [Limbs; 2] = [
+    [0x3267e6dc24a138e5, 0xb5b4c5e559dbefa3, 0x81be18991be06ac3, 0x2b149d40ceb8aaae],
+    [0xe4a2bd0685c315d2, 0xa74fa084e52d1852, 0xcd2cafadeed8fdf4, 0x009713b03af0fed4],
+];
+
+/// Point of the G1 group, encoded as little-endian coordinates.
+#[derive(Debug, Clone, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct G1Point {
+    pub x: [u8; 32],
+    pub y: [u8; 32],
+}
+
+impl G1Point {
+    /// Whether the coordinates are less than the field modulus and the point is on the curve
+    /// `y^2 = x^3 + 3`, or is `(0, 0)`, which encodes the point at infinity.
+    pub fn is_valid(&self) -> bool {
+        match (Fq::from_le_bytes(&self.x), Fq::from_le_bytes(&self.y)) {
+            (Some(x), Some(y)) => {
+                (x.is_zero() && y.is_zero())
+                    || y.mul(&y) == x.mul(&x).mul(&x).add(&Fq::from_limbs([3, 0, 0, 0]))
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Point of the G2 group, encoded as little-endian coordinates in `Fq2`, each of which is
+/// `[c0, c1]` for `c0 + c1 * i`.
+#[derive(Debug, Clone, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct G2Point {
+    pub x: [[u8; 32]; 2],
+    pub y: [[u8; 32]; 2],
+}
+
+impl G2Point {
+    /// Whether the coordinates are less than the field modulus and the point is on the curve
+    /// `y^2 = x^3 + 3 / (9 + i)`, or is `(0, 0)`, which encodes the point at infinity. It doesn't
+    /// check that the point is in the subgroup, which the pairing check of the host does.
+    pub fn is_valid(&self) -> bool {
+        match (Fq2::from_le_bytes(&self.x), Fq2::from_le_bytes(&self.y)) {
+            (Some(x), Some(y)) => {
+                (x.is_zero() && y.is_zero())
+                    || y.mul(&y)
+                        == x.mul(&x)
+                            .mul(&x)
+                            .add(&Fq2(Fq::from_limbs(TWIST_B[0]), Fq::from_limbs(TWIST_B[1])))
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Verifying key of a circuit. In Borsh it is `VerifyingKey::VERSION` followed by the fields,
+/// and the deserialization fails if the version is unknown, a point is not valid or `ic` is empty.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerifyingKey {
+    pub alpha_g1: G1Point,
+    pub beta_g2: G2Point,
+    pub gamma_g2: G2Point,
+    pub delta_g2: G2Point,
+    /// The points that the public inputs are multiplied by, with one more point than there are
+    /// public inputs.
+    pub ic: Vec<G1Point>,
+}
+
+impl VerifyingKey {
+    /// The version of the Borsh layout of the key.
+    pub const VERSION: u8 = 1;
+}
+
+impl BorshSerialize for VerifyingKey {
+    fn serialize<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        Self::VERSION.serialize(writer)?;
+        self.alpha_g1.serialize(writer)?;
+        self.beta_g2.serialize(writer)?;
+        self.gamma_g2.serialize(writer)?;
+        self.delta_g2.serialize(writer)?;
+        self.ic.serialize(writer)
+    }
+}
+
+impl BorshDeserialize for VerifyingKey {
+    fn deserialize(buf: &mut &[u8]) -> io::Result<Self> {
+        let invalid = |message: String| io::Error::new(io::ErrorKind::InvalidData, message);
+        let version = u8::deserialize(buf)?;
+        if version != Self::VERSION {
+            return Err(invalid(format!("Unsupported verifying key version {}", version)));
+        }
+        let key = Self {
+            alpha_g1: BorshDeserialize::deserialize(buf)?,
+            beta_g2: BorshDeserialize::deserialize(buf)?,
+            gamma_g2: BorshDeserialize::deserialize(buf)?,
+            delta_g2: BorshDeserialize::deserialize(buf)?,
+            ic: BorshDeserialize::deserialize(buf)?,
+        };
+        let g2_points =
+            [("beta_g2", &key.beta_g2), ("gamma_g2", &key.gamma_g2), ("delta_g2", &key.delta_g2)];
+        if !key.alpha_g1.is_valid() {
+            return Err(invalid("Invalid point alpha_g1 of the verifying key".to_string()));
+        }
+        if let Some((name, _)) = g2_points.iter().find(|(_, point)| !point.is_valid()) {
+            return Err(invalid(format!("Invalid point {} of the verifying key", name)));
+        }
+        if key.ic.is_empty() {
+            return Err(invalid("The verifying key has no ic points".to_string()));
+        }
+        if let Some(idx) = key.ic.iter().position(|point| !point.is_valid()) {
+            return Err(invalid(format!("Invalid point ic[{}] of the verifying key", idx)));
+        }
+        Ok(key)
+    }
+}
+
+/// Storage key of the verifying key of a `#[near_bindgen(ownable, verifying_key)]` contract.
+pub const VERIFYING_KEY_STORAGE_KEY: &[u8] = b"__VERIFYING_KEY";
+
+/// The verifying key of a `#[near_bindgen(ownable, verifying_key)]` contract, which is read and
+/// decoded only by `LazyOption::get`. A pool can keep it in the contract state, so that the calls
+/// that return before verifying a proof, e.g. for a spent nullifier, don't pay for decoding it:
+///
+/// ```
+/// use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+/// use near_sdk::collections::{LazyOption, NullifierSet};
+/// use near_sdk::groth16::{self, Proof, VerifyingKey};
+/// use near_sdk::json_types::FieldElement;
+///
+/// #[derive(BorshDeserialize, BorshSerialize)]
+/// pub struct Pool {
+///     vk: LazyOption<VerifyingKey>,
+///     nullifiers: NullifierSet<FieldElement>,
+/// }
+///
+/// impl Pool {
+///     pub fn new() -> Self {
+///         Self { vk: groth16::lazy_verifying_key(), nullifiers: NullifierSet::new(b"n".to_vec()) }
+///     }
+///
+///     pub fn withdraw(&mut self, nullifier: FieldElement, proof: Proof) -> bool {
+///         if self.nullifiers.contains(&nullifier) {
+///             return false;
+///         }
+///         let vk = self.vk.get().expect("The verifying key is not set");
+///         groth16::assert_valid_proof(&vk, &proof, &[nullifier]);
+///         self.nullifiers.insert_or_panic(&nullifier);
+///         true
+///     }
+/// }
+/// ```
+pub fn lazy_verifying_key() -> LazyOption<VerifyingKey> {
+    LazyOption::new(VERIFYING_KEY_STORAGE_KEY, None)
+}
+
+/// The verifying key of a `#[near_bindgen(ownable, verifying_key)]` contract, or `None` if it was
+/// never set. Panics if the stored key is not valid. It is stored under the key of
+/// `lazy_verifying_key`, but is decoded here, so that the panic message tells why the key is not
+/// valid.
+pub fn verifying_key() -> Option<VerifyingKey> {
+    env::storage_read(VERIFYING_KEY_STORAGE_KEY).map(|bytes| {
+        VerifyingKey::try_from_slice(&bytes)
+            .unwrap_or_else(|err| env::panic_str(&format!("Invalid stored verifying key: {}", err)))
+    })
+}
+
+/// Stores the verifying key, e.g. from the `#[init]` method. Anyone who can call this function,
+/// can choose the key, so it is up to the caller to check access.
+pub fn set_verifying_key(vk: &VerifyingKey) {
+    lazy_verifying_key().set(vk);
+}
+
+/// Stores the verifying key from its Borsh serialization. Panics unless called by the owner or if
+/// the key is not valid. Called by the `set_verifying_key` method of
+/// a `#[near_bindgen(ownable, verifying_key)]` contract with the input of the method.
+pub fn update_verifying_key(vk_bytes: &[u8]) {
+    crate::assert_owner();
+    let vk = VerifyingKey::try_from_slice(vk_bytes)
+        .unwrap_or_else(|err| env::panic_str(&format!("Invalid verifying key: {}", err)));
+    set_verifying_key(&vk);
+}
+
+/// The gas costs of the alt_bn128 host functions in the runtime config of protocol version 55,
+/// which stabilized them, i.e. `core/primitives/res/runtime_configs/55.txt` of nearcore. They have
+/// to be updated if a later config changes them.
+const MULTIEXP_BASE_GAS: Gas = 713_000_000_000;
+const MULTIEXP_ELEMENT_GAS: Gas = 320_000_000_000;
+const SUM_BASE_GAS: Gas = 3_000_000_000;
+const SUM_ELEMENT_GAS: Gas = 5_000_000_000;
+const PAIRING_CHECK_BASE_GAS: Gas = 9_686_000_000_000;
+const PAIRING_CHECK_ELEMENT_GAS: Gas = 5_102_000_000_000;
+/// An allowance for the gas of the rest of `verify_groth16`, e.g. the wasm instructions and the
+/// memory and the registers of the serialized points, which depends on the compiled contract. It
+/// is not derived from the runtime config.
+const VERIFICATION_OVERHEAD_GAS: Gas = 5_000_000_000_000;
+
+/// Proof that the prover knows the witness for the public inputs.
+#[derive(Debug, Clone, PartialEq, BorshDeserialize, BorshSerialize)]
+pub struct Proof {
+    pub a: G1Point,
+    pub b: G2Point,
+    pub c: G1Point,
+}
+
+/// Verifies the proof for the public inputs by checking
+/// `e(A, B) == e(alpha, beta) * e(L, gamma) * e(C, delta)` where
+/// `L = ic[0] + sum(public_inputs[i] * ic[i + 1])`.
+///
+/// The inputs are `FieldElement`s, which are less than the group order, as the same proof would
+/// otherwise be valid for another encoding of an input. Returns `false` if the number of the
+/// public inputs doesn't match the key. Panics if a point is not on the curve.
+pub fn verify_groth16(vk: &VerifyingKey, proof: &Proof, public_inputs: &[FieldElement]) -> bool {
+    if public_inputs.len() + 1 != vk.ic.len() {
+        return false;
+    }
+    let mut one = [0u8; 32];
+    one[0] = 1;
+    let scalars = iter::once(one).chain(public_inputs.iter().map(|input| input.to_le_bytes()));
+    let mut multiexp = Vec::with_capacity(vk.ic.len() * 96);
+    for (point, scalar) in vk.ic.iter().zip(scalars) {
+        multiexp.extend(to_bytes(point));
+        multiexp.extend(&scalar[..]);
+    }
+    let l = env::alt_bn128_g1_multiexp(&multiexp);
+    let neg_a = env::alt_bn128_g1_sum(&[&[1u8][..], &to_bytes(&proof.a)].concat());
+    let pairs = [
+        neg_a,
+        to_bytes(&proof.b),
+        to_bytes(&vk.alpha_g1),
+        to_bytes(&vk.beta_g2),
+        l,
+        to_bytes(&vk.gamma_g2),
+        to_bytes(&proof.c),
+        to_bytes(&vk.delta_g2),
+    ]
+    .concat();
+    env::alt_bn128_pairing_check(&pairs)
+}
+
+/// Estimates the gas that `verify_groth16` burns for a proof with `num_public_inputs` inputs
+/// from the costs of the host functions in protocol version 55, without verifying anything, so
+/// that the callers can provision the gas of a call, e.g. of a cross-contract call that verifies
+/// a proof, which grows with the number of the inputs:
+///
+/// ```
+/// use near_sdk::groth16::estimate_verify_groth16_gas;
+///
+/// assert!(estimate_verify_groth16_gas(3) > estimate_verify_groth16_gas(2));
+/// ```
+pub fn estimate_verify_groth16_gas(num_public_inputs: usize) -> Gas {
+    let num_points = num_public_inputs as Gas + 1;
+    MULTIEXP_BASE_GAS
+        + num_points * MULTIEXP_ELEMENT_GAS
+        + SUM_BASE_GAS
+        + SUM_ELEMENT_GAS
+        + PAIRING_CHECK_BASE_GAS
+        + 4 * PAIRING_CHECK_ELEMENT_GAS
+        + VERIFICATION_OVERHEAD_GAS
+}
+
+/// Same as `verify_groth16`, but first panics if less than `min_gas` is left to the call, so that
+/// the call fails with a clear message instead of running out of gas in the middle of the pairing
+/// check. `estimate_verify_groth16_gas(public_inputs.len())` is the usual floor.
+pub fn verify_groth16_with_min_gas(
+    vk: &VerifyingKey,
+    proof: &Proof,
+    public_inputs: &[FieldElement],
+    min_gas: Gas,
+) -> bool {
+    let remaining_gas = env::prepaid_gas().saturating_sub(env::used_gas());
+    if remaining_gas < min_gas {
+        env::panic_str(&format!(
+            "Insufficient gas to verify the proof: {} is required, but only {} is left",
+            min_gas, remaining_gas
+        ));
+    }
+    verify_groth16(vk, proof, public_inputs)
+}
+
+/// Panics with `SdkError::InvalidProof` unless `verify_groth16` accepts the proof.
+pub fn assert_valid_proof(vk: &VerifyingKey, proof: &Proof, public_inputs: &[FieldElement]) {
+    if !verify_groth16(vk, proof, public_inputs) {
+        crate::SdkError::InvalidProof.panic();
+    }
+}
+
+fn to_bytes<T: BorshSerialize>(value: &T) -> Vec<u8> {
+    value.try_to_vec().expect("Failed to serialize the point with Borsh.")
+}
+
+/// Element of the field of the coordinates in Montgomery form.
+#[derive(Clone, Copy, PartialEq)]
+struct Fq(Limbs);
+
+impl Fq {
+    /// Converts the number that is less than the modulus into Montgomery form.
+    fn from_limbs(limbs: Limbs) -> Self {
+        Fq(BASE_FIELD.from_limbs(limbs))
+    }
+
+    fn from_le_bytes(bytes: &[u8; 32]) -> Option<Self> {
+        BASE_FIELD.from_le_bytes(bytes).map(Fq)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == [0; 4]
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        Fq(BASE_FIELD.add(&self.0, &other.0))
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        Fq(BASE_FIELD.sub(&self.0, &other.0))
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        Fq(BASE_FIELD.mul(&self.0, &other.0))
+    }
+}
+
+/// Element `c0 + c1 * i` of the quadratic extension of the field, where `i^2 = -1`.
+#[derive(Clone, Copy, PartialEq)]
+struct Fq2(Fq, Fq);
+
+impl Fq2 {
+    fn from_le_bytes(bytes: &[[u8; 32]; 2]) -> Option<Self> {
+        Some(Fq2(Fq::from_le_bytes(&bytes[0])?, Fq::from_le_bytes(&bytes[1])?))
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_zero() && self.1.is_zero()
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        Fq2(self.0.add(&other.0), self.1.add(&other.1))
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        Fq2(
+            self.0.mul(&other.0).sub(&self.1.mul(&other.1)),
+            self.0.mul(&other.1).add(&self.1.mul(&other.0)),
+        )
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bn254;
+    use crate::test_utils::test_env;
+
+    /// Little-endian bytes of the big-endian hex number.
+    fn le(hex: &str) -> [u8; 32] {
+        let mut result = [0u8; 32];
+        for (i, byte) in result.iter_mut().rev().enumerate() {
+            *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).unwrap();
+        }
+        result
+    }
+
+    fn g1(x: &str, y: &str) -> G1Point {
+        G1Point { x: le(x), y: le(y) }
+    }
+
+    fn g2(x: [&str; 2], y: [&str; 2]) -> G2Point {
+        G2Point { x: [le(x[0]), le(x[1])], y: [le(y[0]), le(y[1])] }
+    }
+
+    fn scalar(value: u8) -> [u8; 32] {
+        let mut result = [0u8; 32];
+        result[0] = value;
+        result
+    }
+
+    fn input(value: u8) -> FieldElement {
+        FieldElement::from_le_bytes(scalar(value)).unwrap()
+    }
+
+    /// Key with known discrete logarithms, `alpha = 11`, `beta = 13`, `gamma = 17`, `delta = 19`
+    /// and `ic = [23, 29, 31]`, which makes it possible to compute a valid proof without a circuit.
+    fn verifying_key() -> VerifyingKey {
+        VerifyingKey {
+            alpha_g1: g1(
+                "2a14705537b009189da8808651eecdb82482477fe92ac12ca8b71f80fc3d49ef",
+                "2df7ee7f243ea8b38e1ddf14029258877a618c779fd4717db6177e19ea67ec38",
+            ),
+            beta_g2: g2(
+                [
+                    "23ad66f3a7cca9dc75049635faebd124316244b91de5fb2764cd151572a905f7",
+                    "009edaf0698a8c56f51139588acc094cee3c37d427bb6d2eab830aae529097d1",
+                ],
+                [
+                    "1ad4f87d3b4375a39988ac099b042b1e7c0c715678e4c2bea8905f607cf950f8",
+                    "2700e8a29b7bb45f3022a18a07bdc66d0254559e17cce64e3b4ad21578fcf410",
+                ],
+            ),
+            gamma_g2: g2(
+                [
+                    "0c51a367b61d3119677b29739ddccbb78002b5558d8f49ff16e299c1b41f8098",
+                    "227071bba5ff3b47ed8b504bb5b215bc701d7a3259b933bff1a4164eae499c2c",
+                ],
+                [
+                    "18fd2fd13be8494c39e8a91325d1ef3ba7d1a205d10788e38bc9e09d9be87769",
+                    "08bb188b2a6187bb1e87834c85a6a917763d65b98febf2c45ea339dd77fac415",
+                ],
+            ),
+            delta_g2: g2(
+                [
+                    "15cbba9ab73bc73d0ba4ad132a15cb0c73107a9c19b040c4c73d89f6bf75404d",
+                    "25407be35f18c6594174374841311466c0e66ff003762448c06bca4fa5e9c54e",
+                ],
+                [
+                    "2c750c045112e4ab07f18b12475309cebdcb726bda1ca9948bacd498a28cf411",
+                    "1edef86c1a42fa85ab6ae8d268a7e9b46890b2130dd83b91c86c504cf1f93fbf",
+                ],
+            ),
+            ic: vec![
+                g1(
+                    "1e28260f0ee971dec1e84cf81ff2776ad314d2cfb9ef81d4c970620c29b811f1",
+                    "28fc8a72d4ff12654c3c39dab54eaef9638d28de738959779fcd3e7ac918b396",
+                ),
+                g1(
+                    "1605ffc1ea2e1aef15d774d3207176420c5cc454b19b55558562b0c7ddf00a7d",
+                    "0cf605873faa8028df38ec2d0800d5ddc67f1776338d675491fe87f6bb7354b3",
+                ),
+                g1(
+                    "14b4fa251277a6f4cbbfe379a152a976641f58a4a2bffd3b677ea093bdad853c",
+                    "28ce094a6d16280abcf8d84efa062c85511819dd87d8da255885ce0580ebee36",
+                ),
+            ],
+        }
+    }
+
+    /// Proof for the public inputs `[5, 7]` with `B = 37` and `C = 41`, and `A` computed from the
+    /// verification equation.
+    fn proof() -> Proof {
+        Proof {
+            a: g1(
+                "00b38080080ec97eebebec03ecd139220ee0e92f4b99c5ef222389fbab68ede8",
+                "2a07c9daedb4534a3a1f547ba2116f2214bf66279bcce874fc94b59a9c5ea153",
+            ),
+            b: g2(
+                [
+                    "3010c68cb50161b7d1d96bb71edfec9880171954e56871abf3d93cc94d745fa1",
+                    "0476be093a6d2b4bbf907172049874af11e1b6267606e00804d3ff0037ec57fd",
+                ],
+                [
+                    "01b33461f39d9e887dbb100f170a2345dde3c07e256d1dfa2b657ba5cd030427",
+                    "14c059d74e5b6c4ec14ae5864ebe23a71781d86c29fb8fb6cce94f70d3de7a21",
+                ],
+            ),
+            c: g1(
+                "235658752a7ef475c544c746269813ac4192b73534cc667df0cfa5b4a76589b3",
+                "0106c4ad7d200e59f40aa8d0ae719339319fd3dd3bad23e3d396b46fdc166d18",
+            ),
+        }
+    }
+
+    #[test]
+    fn test_valid_proof() {
+        test_env::setup();
+        assert!(verify_groth16(&verifying_key(), &proof(), &[input(5), input(7)]));
+
+        let vk = VerifyingKey::try_from_slice(&verifying_key().try_to_vec().unwrap()).unwrap();
+        let proof = Proof::try_from_slice(&proof().try_to_vec().unwrap()).unwrap();
+        assert!(verify_groth16(&vk, &proof, &[input(5), input(7)]));
+    }
+
+    #[test]
+    fn test_tampered_proof() {
+        test_env::setup();
+        let mut proof = proof();
+        // `C = 42`.
+        proof.c = g1(
+            "0988f35db6971fd77c8f9afdae27f7fb355577586de4c517537d17882f9b3f34",
+            "23baffa63fafc8c67007390a6e6dd52860b4a8ae95f49905d52cdb2c3b4cb203",
+        );
+        assert!(!verify_groth16(&verifying_key(), &proof, &[input(5), input(7)]));
+    }
+
+    #[test]
+    fn test_wrong_inputs() {
+        test_env::setup();
+        let vk = verifying_key();
+        assert!(!verify_groth16(&vk, &proof(), &[input(5), input(8)]));
+        assert!(!verify_groth16(&vk, &proof(), &[input(5)]));
+        assert!(!verify_groth16(&vk, &proof(), &[input(5), input(7), input(0)]));
+
+        // `7 + r` is the same scalar for the curve, but can't be an input.
+        let mut seven_plus_r = bn254::to_bytes(&bn254::SCALAR_FIELD.modulus);
+        seven_plus_r[0] += 7;
+        assert_eq!(FieldElement::from_le_bytes(seven_plus_r), None);
+    }
+
+    #[test]
+    fn test_verifying_key_borsh() {
+        let bytes = verifying_key().try_to_vec().unwrap();
+        assert_eq!(bytes[0], VerifyingKey::VERSION);
+        assert_eq!(bytes.len(), 1 + 64 + 3 * 128 + 4 + 3 * 64);
+        assert_eq!(VerifyingKey::try_from_slice(&bytes).unwrap(), verifying_key());
+    }
+
+    #[test]
+    fn test_invalid_verifying_key() {
+        let error = |vk: &VerifyingKey| {
+            VerifyingKey::try_from_slice(&vk.try_to_vec().unwrap()).unwrap_err().to_string()
+        };
+
+        let mut vk = verifying_key();
+        vk.alpha_g1.y[0] ^= 1;
+        assert_eq!(error(&vk), "Invalid point alpha_g1 of the verifying key");
+
+        let mut vk = verifying_key();
+        vk.delta_g2.x[1][0] ^= 1;
+        assert_eq!(error(&vk), "Invalid point delta_g2 of the verifying key");
+
+        // A coordinate that is the field modulus is not a valid encoding of zero.
+        let modulus = bn254::to_bytes(&BASE_FIELD.modulus);
+        let mut vk = verifying_key();
+        vk.ic[2] = G1Point { x: modulus, y: [0; 32] };
+        assert_eq!(error(&vk), "Invalid point ic[2] of the verifying key");
+
+        let mut vk = verifying_key();
+        vk.ic.clear();
+        assert_eq!(error(&vk), "The verifying key has no ic points");
+
+        let mut bytes = verifying_key().try_to_vec().unwrap();
+        bytes[0] = 2;
+        assert_eq!(
+            VerifyingKey::try_from_slice(&bytes).unwrap_err().to_string(),
+            "Unsupported verifying key version 2"
+        );
+    }
+
+    #[test]
+    fn test_point_at_infinity() {
+        assert!(G1Point { x: [0; 32], y: [0; 32] }.is_valid());
+        assert!(G2Point { x: [[0; 32]; 2], y: [[0; 32]; 2] }.is_valid());
+        assert!(!G1Point { x: [0; 32], y: scalar(1) }.is_valid());
+    }
+
+    fn set_owner_to_predecessor() {
+        crate::set_owner_id(Some(env::predecessor_account_id().as_ref()));
+    }
+
+    #[test]
+    fn test_update_verifying_key() {
+        test_env::setup();
+        assert_eq!(super::verifying_key(), None);
+        set_owner_to_predecessor();
+        update_verifying_key(&verifying_key().try_to_vec().unwrap());
+        // The key persists across calls.
+        test_env::setup();
+        assert_eq!(super::verifying_key(), Some(verifying_key()));
+        assert!(verify_groth16(&super::verifying_key().unwrap(), &proof(), &[input(5), input(7)]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid stored verifying key: Unsupported verifying key version 0")]
+    fn test_invalid_stored_verifying_key() {
+        test_env::setup();
+        env::storage_write(VERIFYING_KEY_STORAGE_KEY, &[0]);
+        super::verifying_key();
+    }
+
+    #[test]
+    #[should_panic(expected = "Method can only be called by the owner")]
+    fn test_update_verifying_key_not_owner() {
+        test_env::setup();
+        crate::set_owner_id(Some(&test_env::carol()));
+        update_verifying_key(&verifying_key().try_to_vec().unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid verifying key: Invalid point alpha_g1 of the verifying key")]
+    fn test_update_invalid_verifying_key() {
+        test_env::setup();
+        set_owner_to_predecessor();
+        let mut vk = verifying_key();
+        vk.alpha_g1.y[0] ^= 1;
+        update_verifying_key(&vk.try_to_vec().unwrap());
+    }
+
+    #[test]
+    fn test_verify_groth16_with_min_gas() {
+        test_env::setup();
+        let min_gas = estimate_verify_groth16_gas(2);
+        assert!(verify_groth16_with_min_gas(
+            &verifying_key(),
+            &proof(),
+            &[input(5), input(7)],
+            min_gas
+        ));
+        assert!(!verify_groth16_with_min_gas(
+            &verifying_key(),
+            &proof(),
+            &[input(5), input(8)],
+            min_gas
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "Insufficient gas to verify the proof: 301000000000000 is required")]
+    fn test_verify_groth16_insufficient_gas() {
+        test_env::setup();
+        verify_groth16_with_min_gas(
+            &verifying_key(),
+            &proof(),
+            &[input(5), input(7)],
+            301_000_000_000_000,
+        );
+    }
+
+    #[test]
+    fn test_estimate_verify_groth16_gas() {
+        // `MockedBlockchain` doesn't charge gas for the alt_bn128 host functions, so the estimate
+        // can't be compared with the gas burnt by the mocked verification.
+        let estimate = estimate_verify_groth16_gas(2);
+        // Well within the gas limit of a call, and grows by a multiexp element per input.
+        assert!(estimate < 100_000_000_000_000);
+        assert_eq!(estimate_verify_groth16_gas(3) - estimate, MULTIEXP_ELEMENT_GAS);
+    }
+
+    #[derive(BorshDeserialize, BorshSerialize)]
+    struct Pool {
+        vk: LazyOption<VerifyingKey>,
+        nullifiers: crate::collections::NullifierSet<FieldElement>,
+    }
+
+    impl Pool {
+        fn withdraw(&mut self, nullifier: FieldElement, proof: &Proof) -> bool {
+            if self.nullifiers.contains(&nullifier) {
+                return false;
+            }
+            assert_valid_proof(&self.vk.get().unwrap(), proof, &[nullifier, input(7)]);
+            self.nullifiers.insert_or_panic(&nullifier);
+            true
+        }
+    }
+
+    #[test]
+    fn test_lazy_verifying_key() {
+        test_env::setup();
+        set_verifying_key(&verifying_key());
+        let pool = Pool {
+            vk: lazy_verifying_key(),
+            nullifiers: crate::collections::NullifierSet::new(b"n".to_vec()),
+        };
+        let state = pool.try_to_vec().unwrap();
+        let mut pool = Pool::try_from_slice(&state).unwrap();
+        assert!(pool.withdraw(input(5), &proof()));
+
+        // Corrupts the stored key, so that reading it would panic. The spent nullifier
+        // short-circuits before the key is read.
+        env::storage_write(VERIFYING_KEY_STORAGE_KEY, &[0]);
+        let mut pool = Pool::try_from_slice(&state).unwrap();
+        assert!(!pool.withdraw(input(5), &proof()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot deserialize value with Borsh")]
+    fn test_lazy_verifying_key_read_on_verification() {
+        test_env::setup();
+        env::storage_write(VERIFYING_KEY_STORAGE_KEY, &[0]);
+        let mut pool = Pool {
+            vk: lazy_verifying_key(),
+            nullifiers: crate::collections::NullifierSet::new(b"n".to_vec()),
+        };
+        pool.withdraw(input(5), &proof());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid proof")]
+    fn test_assert_valid_proof() {
+        test_env::setup();
+        assert_valid_proof(&verifying_key(), &proof(), &[input(5), input(7)]);
+        assert_valid_proof(&verifying_key(), &proof(), &[input(5), input(8)]);
+    }
+}
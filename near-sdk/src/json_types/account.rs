@@ -1,22 +1,61 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use serde::Serialize;
 use std::convert::{TryFrom, TryInto};
+use std::fmt;
 
 use crate::env::is_valid_account_id;
 use crate::AccountId;
 
 /// Helper class to validate account ID during serialization and deserializiation
 #[derive(
-    Debug, Clone, PartialEq, PartialOrd, Ord, Eq, BorshDeserialize, BorshSerialize, Serialize,
+    Debug, Clone, PartialEq, PartialOrd, Ord, Eq, Hash, BorshDeserialize, BorshSerialize, Serialize,
 )]
 pub struct ValidAccountId(AccountId);
 
 impl ValidAccountId {
+    /// Wraps the account ID that is known to be valid, e.g. because it was returned by the host.
+    pub(crate) fn new_unchecked(account_id: AccountId) -> Self {
+        Self(account_id)
+    }
+
+    /// Builds the id of the direct sub-account `<prefix>.<self>`, e.g. `token.factory.near` for
+    /// the prefix `token`. Fails if the prefix contains dots or the resulting id is invalid.
+    ///
+    /// ```
+    /// # near_sdk::test_utils::test_env::setup();
+    /// let token = near_sdk::env::current_account_id().sub_account("token").unwrap();
+    /// assert_eq!(token.to_string(), "token.alice.near");
+    /// ```
+    pub fn sub_account(&self, prefix: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        if prefix.contains('.') {
+            return Err("The sub-account prefix can't contain dots".into());
+        }
+        Self::try_from(format!("{}.{}", prefix, self.0))
+    }
+
     fn is_valid(&self) -> bool {
         is_valid_account_id(&self.0.as_bytes())
     }
 }
 
+impl fmt::Display for ValidAccountId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl PartialEq<AccountId> for ValidAccountId {
+    fn eq(&self, other: &AccountId) -> bool {
+        &self.0 == other
+    }
+}
+
+impl PartialEq<ValidAccountId> for AccountId {
+    fn eq(&self, other: &ValidAccountId) -> bool {
+        self == &other.0
+    }
+}
+
 impl AsRef<AccountId> for ValidAccountId {
     fn as_ref(&self) -> &AccountId {
         &self.0
@@ -87,4 +126,30 @@ mod tests {
         let key = ValidAccountId::try_from("alice.near").unwrap();
         assert_eq!(key.as_ref(), &"alice.near".to_string());
     }
+
+    #[test]
+    fn test_sub_account() {
+        let factory = ValidAccountId::try_from("factory.near").unwrap();
+        assert_eq!(factory.sub_account("token").unwrap(), "token.factory.near".to_string());
+        assert_eq!(
+            factory.sub_account("my-token_1").unwrap(),
+            "my-token_1.factory.near".to_string()
+        );
+        for prefix in &["a.b", ".token", "token.", "", "Token", "to ken", "-token", "to@ken"] {
+            assert!(factory.sub_account(prefix).is_err(), "{:?} must be rejected", prefix);
+        }
+        assert_eq!(
+            factory.sub_account("a.b").unwrap_err().to_string(),
+            "The sub-account prefix can't contain dots"
+        );
+        assert!(factory.sub_account(&"a".repeat(64)).is_err());
+    }
+
+    #[test]
+    fn test_display_and_eq() {
+        let key = ValidAccountId::try_from("alice.near").unwrap();
+        assert_eq!(key.to_string(), "alice.near");
+        assert_eq!(key, "alice.near".to_string());
+        assert_eq!("alice.near".to_string(), key);
+    }
 }
@@ -0,0 +1,127 @@
+//! Helper to deserialize fixed-size byte arrays, like hashes and commitments, from JSON.
+//! Frontends usually send them as hex or base64 strings rather than as arrays of numbers.
+
+use serde::de::{Error, SeqAccess, Visitor};
+use serde::Deserializer;
+use std::fmt;
+
+/// Deserializes exactly `len` bytes from either a hex string (optionally prefixed with `0x`),
+/// a base64 string, or a JSON array of numbers.
+/// It is used by `near_bindgen` for `[u8; N]` arguments of the methods with JSON input.
+pub fn deserialize_byte_array<'de, D>(deserializer: D, len: usize) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let bytes = deserializer.deserialize_any(ByteArrayVisitor { len })?;
+    if bytes.len() != len {
+        return Err(Error::custom(format!("Expected {} bytes, got {}", len, bytes.len())));
+    }
+    Ok(bytes)
+}
+
+struct ByteArrayVisitor {
+    len: usize,
+}
+
+impl<'de> Visitor<'de> for ByteArrayVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a hex or base64 string or an array of {} bytes", self.len)
+    }
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+        let hex = value.trim_start_matches("0x");
+        if hex.len() == 2 * self.len && hex.bytes().all(|c| c.is_ascii_hexdigit()) {
+            Ok(decode_hex(hex))
+        } else {
+            base64::decode(value).map_err(|err| Error::custom(err.to_string()))
+        }
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut res = Vec::with_capacity(self.len);
+        while let Some(byte) = seq.next_element()? {
+            res.push(byte);
+        }
+        Ok(res)
+    }
+}
+
+/// Decodes a string that is known to consist of an even number of hex digits.
+fn decode_hex(hex: &str) -> Vec<u8> {
+    hex.as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            let digits = std::str::from_utf8(pair).unwrap();
+            u8::from_str_radix(digits, 16).unwrap()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    struct Input {
+        #[serde(deserialize_with = "deserialize_hash")]
+        hash: [u8; 32],
+    }
+
+    fn deserialize_hash<'de, D>(deserializer: D) -> Result<[u8; 32], D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = deserialize_byte_array(deserializer, 32)?;
+        let mut result = [0u8; 32];
+        result.copy_from_slice(&bytes);
+        Ok(result)
+    }
+
+    fn expected() -> [u8; 32] {
+        let mut res = [0u8; 32];
+        for (i, x) in res.iter_mut().enumerate() {
+            *x = i as u8 * 8;
+        }
+        res
+    }
+
+    #[test]
+    fn test_hex() {
+        let input: Input = serde_json::from_str(
+            r#"{"hash": "0008101820283038404850586068707880889098a0a8b0b8c0c8d0d8e0e8f0f8"}"#,
+        )
+        .unwrap();
+        assert_eq!(input.hash, expected());
+
+        let input: Input = serde_json::from_str(
+            r#"{"hash": "0x0008101820283038404850586068707880889098A0A8B0B8C0C8D0D8E0E8F0F8"}"#,
+        )
+        .unwrap();
+        assert_eq!(input.hash, expected());
+    }
+
+    #[test]
+    fn test_base64() {
+        let json = format!(r#"{{"hash": "{}"}}"#, base64::encode(&expected()));
+        let input: Input = serde_json::from_str(&json).unwrap();
+        assert_eq!(input.hash, expected());
+    }
+
+    #[test]
+    fn test_array() {
+        let json = format!(r#"{{"hash": {}}}"#, serde_json::to_string(&expected()).unwrap());
+        let input: Input = serde_json::from_str(&json).unwrap();
+        assert_eq!(input.hash, expected());
+    }
+
+    #[test]
+    fn test_invalid_length() {
+        let input: Result<Input, _> = serde_json::from_str(r#"{"hash": "0008"}"#);
+        assert!(input.is_err());
+        let input: Result<Input, _> = serde_json::from_str(r#"{"hash": [1, 2, 3]}"#);
+        assert!(input.is_err());
+    }
+}
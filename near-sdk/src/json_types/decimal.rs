@@ -0,0 +1,248 @@
+//! Fixed-point decimal number, e.g. an amount of tokens, that is serialized into a decimal string
+//! like `"1.5"` so that it is never parsed as a floating-point number.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// Non-negative decimal number with `Decimal::DECIMALS` digits after the point, stored as
+/// the number multiplied by `10^DECIMALS`. It is serialized into a decimal string in JSON,
+/// e.g. `"1.5"`, and into the raw `u128` mantissa in Borsh.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, BorshDeserialize, BorshSerialize,
+)]
+pub struct Decimal(u128);
+
+impl Decimal {
+    /// The number of digits after the decimal point.
+    pub const DECIMALS: u32 = 18;
+    /// The mantissa of `1`, i.e. `10^DECIMALS`.
+    const ONE: u128 = 1_000_000_000_000_000_000;
+
+    /// Creates the decimal from its mantissa,
+    /// e.g. `Decimal::from_mantissa(15)` is `0.000000000000000015`.
+    pub fn from_mantissa(mantissa: u128) -> Self {
+        Self(mantissa)
+    }
+
+    /// The number multiplied by `10^DECIMALS`.
+    pub fn mantissa(self) -> u128 {
+        self.0
+    }
+
+    /// Checked addition. Returns `None` if overflow occurred.
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(Self)
+    }
+
+    /// Checked subtraction. Returns `None` if overflow occurred.
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.0.checked_sub(other.0).map(Self)
+    }
+
+    /// Checked multiplication, rounded down to `DECIMALS` digits. Returns `None` if overflow
+    /// occurred.
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        let (a_int, a_frac) = (self.0 / Self::ONE, self.0 % Self::ONE);
+        let (b_int, b_frac) = (other.0 / Self::ONE, other.0 % Self::ONE);
+        a_int
+            .checked_mul(b_int)?
+            .checked_mul(Self::ONE)?
+            .checked_add(a_int.checked_mul(b_frac)?)?
+            .checked_add(a_frac.checked_mul(b_int)?)?
+            .checked_add(a_frac * b_frac / Self::ONE)
+            .map(Self)
+    }
+
+    /// Checked division, rounded down to `DECIMALS` digits. Returns `None` if `other` is zero or
+    /// overflow occurred.
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        if other.0 == 0 {
+            return None;
+        }
+        let mut result = (self.0 / other.0).checked_mul(Self::ONE)?;
+        let mut remainder = self.0 % other.0;
+        let mut scale = Self::ONE;
+        for _ in 0..Self::DECIMALS {
+            scale /= 10;
+            // Computes `remainder * 10` divided by `other` without overflowing, as both
+            // `remainder` and `acc` are less than `other`.
+            let mut digit = 0;
+            let mut acc = 0;
+            for _ in 0..10 {
+                if acc >= other.0 - remainder {
+                    acc -= other.0 - remainder;
+                    digit += 1;
+                } else {
+                    acc += remainder;
+                }
+            }
+            remainder = acc;
+            result = result.checked_add(digit * scale)?;
+        }
+        Some(Self(result))
+    }
+}
+
+/// Error of parsing a `Decimal` from a string.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseDecimalError {
+    /// The string is not a number like `1`, `1.5` or `0.05`.
+    InvalidFormat,
+    /// The number has more than `Decimal::DECIMALS` digits after the point.
+    TooManyDecimals,
+    /// The number is too large.
+    Overflow,
+}
+
+impl fmt::Display for ParseDecimalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseDecimalError::InvalidFormat => write!(f, "Invalid decimal number"),
+            ParseDecimalError::TooManyDecimals => {
+                write!(f, "Decimal number has more than {} decimals", Decimal::DECIMALS)
+            }
+            ParseDecimalError::Overflow => write!(f, "Decimal number is too large"),
+        }
+    }
+}
+
+impl std::error::Error for ParseDecimalError {}
+
+impl FromStr for Decimal {
+    type Err = ParseDecimalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.splitn(2, '.');
+        let int_part = parts.next().unwrap_or_default();
+        let frac_part = parts.next();
+        let is_digits = |part: &str| !part.is_empty() && part.bytes().all(|c| c.is_ascii_digit());
+        if !is_digits(int_part) || !frac_part.map(is_digits).unwrap_or(true) {
+            return Err(ParseDecimalError::InvalidFormat);
+        }
+        let frac_part = frac_part.unwrap_or_default();
+        if frac_part.len() > Self::DECIMALS as usize {
+            return Err(ParseDecimalError::TooManyDecimals);
+        }
+        let int = u128::from_str(int_part).map_err(|_| ParseDecimalError::Overflow)?;
+        let frac = if frac_part.is_empty() {
+            0
+        } else {
+            // Can't overflow as there are at most `DECIMALS` digits.
+            u128::from_str(frac_part).unwrap() * 10u128.pow(Self::DECIMALS - frac_part.len() as u32)
+        };
+        int.checked_mul(Self::ONE)
+            .and_then(|int| int.checked_add(frac))
+            .map(Self)
+            .ok_or(ParseDecimalError::Overflow)
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let int = self.0 / Self::ONE;
+        let frac = self.0 % Self::ONE;
+        if frac == 0 {
+            write!(f, "{}", int)
+        } else {
+            let frac = format!("{:018}", frac);
+            write!(f, "{}.{}", int, frac.trim_end_matches('0'))
+        }
+    }
+}
+
+impl Serialize for Decimal {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Decimal {
+    fn deserialize<D>(deserializer: D) -> Result<Self, <D as Deserializer<'de>>::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s: String = Deserialize::deserialize(deserializer)?;
+        s.parse().map_err(|err: ParseDecimalError| serde::de::Error::custom(err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dec(s: &str) -> Decimal {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(dec("0"), Decimal::from_mantissa(0));
+        assert_eq!(dec("1"), Decimal::from_mantissa(Decimal::ONE));
+        assert_eq!(dec("1.50"), Decimal::from_mantissa(15 * Decimal::ONE / 10));
+        assert_eq!(dec("0.000000000000000001"), Decimal::from_mantissa(1));
+        assert_eq!("".parse::<Decimal>(), Err(ParseDecimalError::InvalidFormat));
+        assert_eq!("1.".parse::<Decimal>(), Err(ParseDecimalError::InvalidFormat));
+        assert_eq!(".5".parse::<Decimal>(), Err(ParseDecimalError::InvalidFormat));
+        assert_eq!("-1".parse::<Decimal>(), Err(ParseDecimalError::InvalidFormat));
+        assert_eq!("1e5".parse::<Decimal>(), Err(ParseDecimalError::InvalidFormat));
+        assert_eq!(
+            "0.0000000000000000001".parse::<Decimal>(),
+            Err(ParseDecimalError::TooManyDecimals)
+        );
+        assert_eq!("340282366920938463464".parse::<Decimal>(), Err(ParseDecimalError::Overflow));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(dec("0").to_string(), "0");
+        assert_eq!(dec("1.50").to_string(), "1.5");
+        assert_eq!(dec("12.000000000000000001").to_string(), "12.000000000000000001");
+        assert_eq!(
+            Decimal::from_mantissa(u128::max_value()).to_string(),
+            "340282366920938463463.374607431768211455"
+        );
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let json = serde_json::to_string(&dec("0.000000000000000001")).unwrap();
+        assert_eq!(json, r#""0.000000000000000001""#);
+        let value: Decimal = serde_json::from_str(&json).unwrap();
+        assert_eq!(value, Decimal::from_mantissa(1));
+        assert!(serde_json::from_str::<Decimal>("1.5").is_err());
+
+        let bytes = dec("0.000000000000000001").try_to_vec().unwrap();
+        assert_eq!(bytes, 1u128.try_to_vec().unwrap());
+        assert_eq!(Decimal::try_from_slice(&bytes).unwrap(), Decimal::from_mantissa(1));
+    }
+
+    #[test]
+    fn test_checked_ops() {
+        assert_eq!(dec("1.5").checked_add(dec("0.25")), Some(dec("1.75")));
+        assert_eq!(dec("1.5").checked_sub(dec("1.75")), None);
+        assert_eq!(dec("1.5").checked_mul(dec("2.5")), Some(dec("3.75")));
+        assert_eq!(
+            dec("1000000").checked_mul(dec("0.000000000000000001")),
+            Some(dec("0.000000000001"))
+        );
+        assert_eq!(
+            dec("0.000000001").checked_mul(dec("0.000000001")),
+            Some(dec("0.000000000000000001"))
+        );
+        assert_eq!(dec("0.000000000000000001").checked_mul(dec("0.5")), Some(dec("0")));
+        assert_eq!(dec("1000000000000000000").checked_mul(dec("1000")), None);
+        assert_eq!(dec("1").checked_div(dec("3")), Some(dec("0.333333333333333333")));
+        assert_eq!(dec("7.5").checked_div(dec("2.5")), Some(dec("3")));
+        assert_eq!(
+            dec("300000000000000000000").checked_div(dec("200000000000000000000")),
+            Some(dec("1.5"))
+        );
+        assert_eq!(dec("1").checked_div(dec("0")), None);
+        assert_eq!(dec("1000000000000000000").checked_div(dec("0.001")), None);
+    }
+}
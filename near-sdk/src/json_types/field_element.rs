@@ -0,0 +1,259 @@
+//! Element of the scalar field of alt_bn128, e.g. a public input of a zk-SNARK proof, which is
+//! serialized into a decimal string like the public signals of snarkjs, and also parsed from
+//! a `0x`-prefixed hex string.
+
+use crate::bn254::{less_than, to_bytes, to_limbs, SCALAR_FIELD};
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// Number that is less than the modulus of the scalar field of alt_bn128. Both JSON and Borsh
+/// deserialization reject numbers that are not, so a method taking `FieldElement`, or
+/// `Vec<FieldElement>` for a batch of public inputs, never sees two encodings of the same
+/// element. It is serialized into a decimal string in JSON, e.g. `"12345"`, and parsed from
+/// either a decimal or a hex string like `"0x3039"`. In Borsh it is 32 little-endian bytes,
+/// which is also the encoding of the scalars of the alt_bn128 host functions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, BorshSerialize)]
+pub struct FieldElement([u8; 32]);
+
+impl FieldElement {
+    /// Creates the element from little-endian bytes. Returns `None` if the number is not less
+    /// than the modulus.
+    pub fn from_le_bytes(bytes: [u8; 32]) -> Option<Self> {
+        if less_than(&to_limbs(&bytes), &SCALAR_FIELD.modulus) {
+            Some(Self(bytes))
+        } else {
+            None
+        }
+    }
+
+    /// The little-endian bytes of the element.
+    pub fn to_le_bytes(self) -> [u8; 32] {
+        self.0
+    }
+}
+
+impl From<FieldElement> for [u8; 32] {
+    fn from(value: FieldElement) -> Self {
+        value.0
+    }
+}
+
+impl BorshDeserialize for FieldElement {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let bytes: [u8; 32] = BorshDeserialize::deserialize(buf)?;
+        Self::from_le_bytes(bytes).ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                ParseFieldElementError::OutOfRange.to_string(),
+            )
+        })
+    }
+}
+
+/// Error of parsing a `FieldElement` from a string.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseFieldElementError {
+    /// The string is neither a decimal number nor a hex number prefixed with `0x`.
+    InvalidFormat,
+    /// The number is not less than the modulus of the field.
+    OutOfRange,
+}
+
+impl fmt::Display for ParseFieldElementError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseFieldElementError::InvalidFormat => write!(f, "Invalid field element"),
+            ParseFieldElementError::OutOfRange => {
+                write!(f, "Field element is not less than the modulus")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseFieldElementError {}
+
+impl FromStr for FieldElement {
+    type Err = ParseFieldElementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (digits, radix) = match s.strip_prefix("0x") {
+            Some(digits) => (digits, 16),
+            None => (s, 10),
+        };
+        if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+            return Err(ParseFieldElementError::InvalidFormat);
+        }
+        let mut limbs = [0u64; 4];
+        for digit in digits.chars() {
+            // Can't fail as the digits were checked above.
+            let mut carry = digit.to_digit(radix).unwrap() as u128;
+            for limb in limbs.iter_mut() {
+                let value = *limb as u128 * radix as u128 + carry;
+                *limb = value as u64;
+                carry = value >> 64;
+            }
+            if carry != 0 {
+                return Err(ParseFieldElementError::OutOfRange);
+            }
+        }
+        Self::from_le_bytes(to_bytes(&limbs)).ok_or(ParseFieldElementError::OutOfRange)
+    }
+}
+
+impl fmt::Display for FieldElement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Splits the number into base `10^19` digits, the largest power of ten that fits `u64`.
+        const BASE: u128 = 10_000_000_000_000_000_000;
+        let mut limbs = to_limbs(&self.0);
+        let mut digits = vec![];
+        loop {
+            let mut remainder = 0u128;
+            for limb in limbs.iter_mut().rev() {
+                let value = (remainder << 64) | *limb as u128;
+                *limb = (value / BASE) as u64;
+                remainder = value % BASE;
+            }
+            digits.push(remainder as u64);
+            if limbs == [0; 4] {
+                break;
+            }
+        }
+        let mut digits = digits.iter().rev();
+        write!(f, "{}", digits.next().unwrap())?;
+        for digit in digits {
+            write!(f, "{:019}", digit)?;
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for FieldElement {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for FieldElement {
+    fn deserialize<D>(deserializer: D) -> Result<Self, <D as Deserializer<'de>>::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s: String = Deserialize::deserialize(deserializer)?;
+        s.parse().map_err(|err: ParseFieldElementError| serde::de::Error::custom(err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The modulus in decimal.
+    const MODULUS_STR: &str =
+        "21888242871839275222246405745257275088548364400416034343698204186575808495617";
+
+    #[test]
+    fn test_in_range() {
+        let max: FieldElement =
+            "21888242871839275222246405745257275088548364400416034343698204186575808495616"
+                .parse()
+                .unwrap();
+        let mut expected = to_bytes(&SCALAR_FIELD.modulus);
+        expected[0] -= 1;
+        assert_eq!(max.to_le_bytes(), expected);
+        assert_eq!(
+            max.to_string(),
+            "21888242871839275222246405745257275088548364400416034343698204186575808495616"
+        );
+
+        let value: FieldElement = serde_json::from_str(r#""12345""#).unwrap();
+        assert_eq!(value.to_le_bytes()[..2], [0x39, 0x30]);
+        assert_eq!(serde_json::to_string(&value).unwrap(), r#""12345""#);
+        assert_eq!(FieldElement::default().to_string(), "0");
+        assert_eq!(
+            "10000000000000000000".parse::<FieldElement>().unwrap().to_string(),
+            "10000000000000000000"
+        );
+
+        let bytes = max.try_to_vec().unwrap();
+        assert_eq!(bytes, expected.to_vec());
+        assert_eq!(FieldElement::try_from_slice(&bytes).unwrap(), max);
+    }
+
+    #[test]
+    fn test_modulus_rejected() {
+        assert_eq!(MODULUS_STR.parse::<FieldElement>(), Err(ParseFieldElementError::OutOfRange));
+        assert!(serde_json::from_str::<FieldElement>(&format!("\"{}\"", MODULUS_STR)).is_err());
+        assert_eq!(FieldElement::from_le_bytes(to_bytes(&SCALAR_FIELD.modulus)), None);
+        assert!(FieldElement::try_from_slice(&to_bytes(&SCALAR_FIELD.modulus)).is_err());
+    }
+
+    #[test]
+    fn test_above_modulus_rejected() {
+        assert_eq!(
+            "21888242871839275222246405745257275088548364400416034343698204186575808495618"
+                .parse::<FieldElement>(),
+            Err(ParseFieldElementError::OutOfRange)
+        );
+        // Doesn't fit 256 bits.
+        assert_eq!(
+            format!("{}0", MODULUS_STR).parse::<FieldElement>(),
+            Err(ParseFieldElementError::OutOfRange)
+        );
+        assert!(FieldElement::try_from_slice(&[255; 32]).is_err());
+        assert_eq!("".parse::<FieldElement>(), Err(ParseFieldElementError::InvalidFormat));
+        assert_eq!("-1".parse::<FieldElement>(), Err(ParseFieldElementError::InvalidFormat));
+        assert!(serde_json::from_str::<FieldElement>("12345").is_err());
+        assert_eq!("0x".parse::<FieldElement>(), Err(ParseFieldElementError::InvalidFormat));
+        assert_eq!("0xg1".parse::<FieldElement>(), Err(ParseFieldElementError::InvalidFormat));
+        assert_eq!("3039".parse::<FieldElement>().unwrap().to_string(), "3039");
+    }
+
+    #[test]
+    fn test_hex() {
+        assert_eq!("0x3039".parse::<FieldElement>(), "12345".parse());
+        assert_eq!("0x0".parse::<FieldElement>(), Ok(FieldElement::default()));
+        assert_eq!(
+            "0x30644E72E131A029B85045B68181585D2833E84879B9709143E1F593F0000000"
+                .parse::<FieldElement>()
+                .unwrap()
+                .to_string(),
+            "21888242871839275222246405745257275088548364400416034343698204186575808495616"
+        );
+        assert_eq!(
+            "0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001"
+                .parse::<FieldElement>(),
+            Err(ParseFieldElementError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_vec() {
+        #[derive(serde::Deserialize)]
+        struct Input {
+            public_inputs: Vec<FieldElement>,
+        }
+
+        let input: Input = serde_json::from_str(
+            r#"{"public_inputs": ["5", "0x07", "21888242871839275222246405745257275088548364400416034343698204186575808495616"]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            input.public_inputs.iter().map(|value| value.to_le_bytes()[0]).collect::<Vec<_>>(),
+            vec![5, 7, 0]
+        );
+        assert_eq!(input.public_inputs[2].to_le_bytes()[31], 0x30);
+
+        let error = serde_json::from_str::<Input>(&format!(
+            r#"{{"public_inputs": ["5", "{}", "7"]}}"#,
+            MODULUS_STR
+        ))
+        .err()
+        .unwrap();
+        assert!(error.to_string().contains("Field element is not less than the modulus"));
+    }
+}
@@ -5,12 +5,70 @@
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::ops::{Add, AddAssign, Sub, SubAssign};
 
 macro_rules! impl_str_type {
     ($iden: ident, $ty: tt) => {
+        /// `+` and `-` panic on overflow in all build profiles, unlike the operators of the
+        /// primitive integers that wrap around in release builds without `overflow-checks`.
+        /// Prefer them, or `checked_add` and `checked_sub`, over arithmetic on the inner value.
         #[derive(Debug, Clone, Copy, PartialEq, BorshDeserialize, BorshSerialize)]
         pub struct $iden(pub $ty);
 
+        impl $iden {
+            /// Checked integer addition. Returns `None` if overflow occurred.
+            pub fn checked_add(self, other: Self) -> Option<Self> {
+                self.0.checked_add(other.0).map(Self)
+            }
+
+            /// Checked integer subtraction. Returns `None` if overflow occurred.
+            pub fn checked_sub(self, other: Self) -> Option<Self> {
+                self.0.checked_sub(other.0).map(Self)
+            }
+        }
+
+        impl Add for $iden {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                match self.checked_add(other) {
+                    Some(result) => result,
+                    None => crate::env::panic_str(concat!(
+                        "Arithmetic overflow in ",
+                        stringify!($iden),
+                        " addition"
+                    )),
+                }
+            }
+        }
+
+        impl Sub for $iden {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                match self.checked_sub(other) {
+                    Some(result) => result,
+                    None => crate::env::panic_str(concat!(
+                        "Arithmetic overflow in ",
+                        stringify!($iden),
+                        " subtraction"
+                    )),
+                }
+            }
+        }
+
+        impl AddAssign for $iden {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $iden {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
         impl From<$ty> for $iden {
             fn from(v: $ty) -> Self {
                 Self(v)
@@ -117,4 +175,39 @@ mod tests {
         test_serde!(I64, i64, i64::max_value());
         test_serde!(I64, i64, i64::min_value());
     }
+
+    #[test]
+    fn test_checked_ops() {
+        assert_eq!(U128(1).checked_add(U128(2)), Some(U128(3)));
+        assert_eq!(U128(u128::max_value()).checked_add(U128(1)), None);
+        assert_eq!(U128(3).checked_sub(U128(2)), Some(U128(1)));
+        assert_eq!(U128(0).checked_sub(U128(1)), None);
+        assert_eq!(I64(i64::min_value()).checked_sub(I64(1)), None);
+    }
+
+    #[test]
+    fn test_ops() {
+        let mut balance = U128(5) + U128(10);
+        balance -= U128(3);
+        balance += U128(1);
+        assert_eq!(balance, U128(13));
+        assert_eq!(I64(-5) - I64(10), I64(-15));
+    }
+
+    // `ci-test.sh` also runs these tests with `--release`, where `overflow-checks` is off and the
+    // operators of the primitive integers wrap around.
+    #[test]
+    #[should_panic(expected = "Arithmetic overflow in U128 addition")]
+    fn test_add_overflow() {
+        crate::test_utils::test_env::setup();
+        let _ = U128(u128::max_value()) + U128(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Arithmetic overflow in U64 subtraction")]
+    fn test_sub_overflow() {
+        crate::test_utils::test_env::setup();
+        let mut balance = U64(1);
+        balance -= U64(2);
+    }
 }
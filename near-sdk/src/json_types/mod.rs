@@ -1,11 +1,60 @@
 //! Helper types for JSON serialization.
 
 mod account;
+mod byte_array;
+mod decimal;
+mod field_element;
 mod integers;
+mod pagination;
 mod public_key;
+mod time;
 mod vector;
 
 pub use account::ValidAccountId;
+pub use byte_array::deserialize_byte_array;
+pub use decimal::{Decimal, ParseDecimalError};
+pub use field_element::{FieldElement, ParseFieldElementError};
 pub use integers::{I128, I64, U128, U64};
+pub use pagination::Pagination;
 pub use public_key::{Base58PublicKey, CurveType};
+pub use time::{Duration, Timestamp};
 pub use vector::Base64VecU8;
+
+/// Implements `JsonSchema` for a type that is serialized into a JSON string, with a `format` that
+/// tells the generators of client types, e.g. TypeScript, what the string holds.
+#[cfg(feature = "abi")]
+macro_rules! impl_string_json_schema {
+    ($($ty:ident => $format:expr),* $(,)?) => {
+        $(
+            impl schemars::JsonSchema for $ty {
+                fn schema_name() -> String {
+                    stringify!($ty).to_string()
+                }
+
+                fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+                    schemars::schema::SchemaObject {
+                        instance_type: Some(schemars::schema::InstanceType::String.into()),
+                        format: Some($format.to_string()),
+                        ..Default::default()
+                    }
+                    .into()
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "abi")]
+impl_string_json_schema! {
+    U64 => "uint64",
+    U128 => "uint128",
+    I64 => "int64",
+    I128 => "int128",
+    Decimal => "decimal",
+    FieldElement => "field-element",
+    Timestamp => "timestamp-nanos",
+    Duration => "duration-nanos",
+    ValidAccountId => "account-id",
+    Base58PublicKey => "public-key",
+    Base64VecU8 => "byte",
+}
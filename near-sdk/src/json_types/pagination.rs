@@ -0,0 +1,89 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+
+/// Argument of view methods that return a page of a large collection, serialized as
+/// `{"from_index": 10, "limit": 20}`. Both fields can be omitted, and so can the whole argument
+/// if it is declared as `#[default = Pagination::default()] page: Pagination`.
+///
+/// ```
+/// # use near_sdk::json_types::Pagination;
+/// # use near_sdk::collections::Vector;
+/// # struct Contract { messages: Vector<String> }
+/// # impl Contract {
+/// pub fn get_messages(&self, page: Pagination) -> Vec<String> {
+///     page.paginate(self.messages.iter())
+/// }
+/// # }
+/// ```
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, BorshDeserialize, BorshSerialize, Serialize, Deserialize,
+)]
+#[cfg_attr(feature = "abi", derive(schemars::JsonSchema))]
+pub struct Pagination {
+    /// Index of the first element of the page. Defaults to `0`.
+    pub from_index: Option<u64>,
+    /// Maximum number of elements on the page. Defaults to `Pagination::DEFAULT_LIMIT`.
+    pub limit: Option<u64>,
+}
+
+impl Pagination {
+    /// The number of elements on the page when the limit is not specified.
+    pub const DEFAULT_LIMIT: u64 = 100;
+
+    pub fn new(from_index: u64, limit: u64) -> Self {
+        Self { from_index: Some(from_index), limit: Some(limit) }
+    }
+
+    /// Index of the first element of the page.
+    pub fn from_index(&self) -> u64 {
+        self.from_index.unwrap_or(0)
+    }
+
+    /// Maximum number of elements on the page.
+    pub fn limit(&self) -> u64 {
+        self.limit.unwrap_or(Self::DEFAULT_LIMIT)
+    }
+
+    /// Collects the elements of the page. Returns an empty `Vec` if `from_index` is past the end.
+    pub fn paginate<I: Iterator>(&self, iter: I) -> Vec<I::Item> {
+        let from_index = usize::try_from(self.from_index()).unwrap_or(usize::max_value());
+        let limit = usize::try_from(self.limit()).unwrap_or(usize::max_value());
+        iter.skip(from_index).take(limit).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults() {
+        let page: Pagination = serde_json::from_str("{}").unwrap();
+        assert_eq!(page, Pagination::default());
+        assert_eq!(page.from_index(), 0);
+        assert_eq!(page.limit(), Pagination::DEFAULT_LIMIT);
+
+        let page: Pagination = serde_json::from_str(r#"{"limit": 5}"#).unwrap();
+        assert_eq!(page.from_index(), 0);
+        assert_eq!(page.limit(), 5);
+    }
+
+    #[test]
+    fn test_paginate() {
+        let values: Vec<u64> = (0..10).collect();
+        assert_eq!(Pagination::new(0, 3).paginate(values.iter().cloned()), vec![0, 1, 2]);
+        assert_eq!(Pagination::new(8, 3).paginate(values.iter().cloned()), vec![8, 9]);
+        assert_eq!(Pagination::new(10, 3).paginate(values.iter().cloned()), Vec::<u64>::new());
+        assert_eq!(Pagination::new(100, 3).paginate(values.iter().cloned()), Vec::<u64>::new());
+        assert_eq!(Pagination::new(2, 0).paginate(values.iter().cloned()), Vec::<u64>::new());
+        assert_eq!(Pagination::default().paginate(values.iter().cloned()), values);
+    }
+
+    #[test]
+    fn test_paginate_large_index() {
+        let values: Vec<u64> = (0..10).collect();
+        let page = Pagination::new(u64::max_value(), u64::max_value());
+        assert_eq!(page.paginate(values.iter().cloned()), Vec::<u64>::new());
+    }
+}
@@ -25,9 +25,21 @@ impl TryFrom<String> for CurveType {
 
 /// Public key in a binary format with base58 string serialization with human-readable curve.
 /// e.g. `ed25519:3tysLvy7KGoE8pznUgXvSHa4vYyGvrDZFcT8jgb8PEQ6`
-#[derive(Debug, Clone, PartialEq, PartialOrd, Ord, Eq, BorshDeserialize, BorshSerialize)]
+///
+/// In Borsh it is the curve byte followed by the key bytes. Both JSON and Borsh deserialization
+/// reject unknown curves and keys of the wrong length, so a method taking `Base58PublicKey` fails
+/// on a malformed key before it runs.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Ord, Eq, BorshSerialize)]
 pub struct Base58PublicKey(pub Vec<u8>);
 
+impl BorshDeserialize for Base58PublicKey {
+    fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let data: Vec<u8> = BorshDeserialize::deserialize(buf)?;
+        Self::try_from(data)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err.to_string()))
+    }
+}
+
 impl Base58PublicKey {
     fn split_key_type_data(value: &str) -> Result<(CurveType, &str), Box<dyn std::error::Error>> {
         if let Some(idx) = value.find(':') {
@@ -156,6 +168,48 @@ mod tests {
         assert_eq!(key.0, binary_key());
     }
 
+    #[test]
+    fn test_secp256k1_public_key() {
+        let s = "secp256k1:2Ana1pUpv2ZbMVkwF5FXapYeBEjdxDatLn7nvJkhgTSXbs59SyZSx866bXirPgj8QQVB57uxHJBG1YFvkRbFj4T";
+        let key: Base58PublicKey = serde_json::from_str(&format!("\"{}\"", s)).unwrap();
+        let mut expected = vec![1];
+        expected.extend(1..=64);
+        assert_eq!(key.0, expected);
+        assert_eq!(String::from(&key), s);
+    }
+
+    #[test]
+    fn test_malformed_public_key() {
+        for s in &[
+            // Unknown curve.
+            "ed448:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp",
+            // 31 bytes.
+            "ed25519:thX6LZfHDZZKUs92febYZhYRcXddmzfzF2NvTkPNE",
+            // 32 bytes for secp256k1, which has 64.
+            "secp256k1:6E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtp",
+            // `0` and `l` are not base58 characters.
+            "ed25519:0E8sCci9badyRkXb3JoRpBj5p8C6Tw41ELDZoiihKEtl",
+            "ed25519:",
+        ] {
+            assert!(Base58PublicKey::try_from(*s).is_err(), "{}", s);
+            assert!(serde_json::from_str::<Base58PublicKey>(&format!("\"{}\"", s)).is_err());
+        }
+    }
+
+    #[test]
+    fn test_public_key_borsh() {
+        let key: Base58PublicKey = binary_key().try_into().unwrap();
+        let data = key.try_to_vec().unwrap();
+        assert_eq!(data, binary_key().try_to_vec().unwrap());
+        assert_eq!(Base58PublicKey::try_from_slice(&data).unwrap(), key);
+
+        let mut unknown_curve = binary_key();
+        unknown_curve[0] = 2;
+        assert!(Base58PublicKey::try_from_slice(&unknown_curve.try_to_vec().unwrap()).is_err());
+        let short = binary_key()[..32].to_vec();
+        assert!(Base58PublicKey::try_from_slice(&short.try_to_vec().unwrap()).is_err());
+    }
+
     #[test]
     fn test_public_key_to_string() {
         let key: Base58PublicKey = binary_key().try_into().unwrap();
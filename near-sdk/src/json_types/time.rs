@@ -0,0 +1,339 @@
+//! Points in time and durations in nanoseconds, the unit of `env::block_timestamp()`, so that
+//! seconds and nanoseconds can't be mixed up in time-lock logic. `Timestamp` and `Duration` are
+//! always in nanoseconds, and other units are only produced and consumed by the explicit
+//! converters like `from_millis` and `as_secs`.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+const NANOS_PER_SEC: u64 = 1_000_000_000;
+const NANOS_PER_MILLI: u64 = 1_000_000;
+
+/// Point in time in nanoseconds since the Unix epoch, like `env::block_timestamp()`. Serialized
+/// into a base-10 string in JSON and parsed from either a string or a number.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, BorshDeserialize, BorshSerialize,
+)]
+pub struct Timestamp(u64);
+
+/// Length of time in nanoseconds. Serialized into a base-10 string in JSON and parsed from either
+/// a string or a number.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, BorshDeserialize, BorshSerialize,
+)]
+pub struct Duration(u64);
+
+impl Timestamp {
+    /// The timestamp of the current block.
+    pub fn now() -> Self {
+        Self(crate::env::block_timestamp())
+    }
+
+    pub fn from_nanos(nanos: u64) -> Self {
+        Self(nanos)
+    }
+
+    /// Panics if the timestamp in nanoseconds doesn't fit into `u64`.
+    pub fn from_millis(millis: u64) -> Self {
+        Self(Duration::from_millis(millis).0)
+    }
+
+    /// Panics if the timestamp in nanoseconds doesn't fit into `u64`.
+    pub fn from_secs(secs: u64) -> Self {
+        Self(Duration::from_secs(secs).0)
+    }
+
+    pub fn as_nanos(self) -> u64 {
+        self.0
+    }
+
+    /// Whole milliseconds since the Unix epoch, rounded down, like `env::block_timestamp_ms()`.
+    pub fn as_millis(self) -> u64 {
+        self.0 / NANOS_PER_MILLI
+    }
+
+    /// Whole seconds since the Unix epoch, rounded down.
+    pub fn as_secs(self) -> u64 {
+        self.0 / NANOS_PER_SEC
+    }
+
+    /// Checked addition. Returns `None` if overflow occurred.
+    pub fn checked_add(self, duration: Duration) -> Option<Self> {
+        self.0.checked_add(duration.0).map(Self)
+    }
+
+    /// Checked subtraction. Returns `None` if overflow occurred.
+    pub fn checked_sub(self, duration: Duration) -> Option<Self> {
+        self.0.checked_sub(duration.0).map(Self)
+    }
+
+    /// The time passed since `earlier`. Returns `None` if `earlier` is later than `self`.
+    pub fn checked_duration_since(self, earlier: Self) -> Option<Duration> {
+        self.0.checked_sub(earlier.0).map(Duration)
+    }
+}
+
+impl Duration {
+    pub fn from_nanos(nanos: u64) -> Self {
+        Self(nanos)
+    }
+
+    /// Panics if the duration in nanoseconds doesn't fit into `u64`.
+    pub fn from_millis(millis: u64) -> Self {
+        match millis.checked_mul(NANOS_PER_MILLI) {
+            Some(nanos) => Self(nanos),
+            None => crate::env::panic_str("Arithmetic overflow in Duration::from_millis"),
+        }
+    }
+
+    /// Panics if the duration in nanoseconds doesn't fit into `u64`.
+    pub fn from_secs(secs: u64) -> Self {
+        match secs.checked_mul(NANOS_PER_SEC) {
+            Some(nanos) => Self(nanos),
+            None => crate::env::panic_str("Arithmetic overflow in Duration::from_secs"),
+        }
+    }
+
+    pub fn as_nanos(self) -> u64 {
+        self.0
+    }
+
+    /// Whole milliseconds, rounded down.
+    pub fn as_millis(self) -> u64 {
+        self.0 / NANOS_PER_MILLI
+    }
+
+    /// Whole seconds, rounded down.
+    pub fn as_secs(self) -> u64 {
+        self.0 / NANOS_PER_SEC
+    }
+
+    /// Checked addition. Returns `None` if overflow occurred.
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(Self)
+    }
+
+    /// Checked subtraction. Returns `None` if overflow occurred.
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.0.checked_sub(other.0).map(Self)
+    }
+}
+
+/// Implements an operator with the checked method, panicking on overflow like `U64`.
+macro_rules! impl_op {
+    (
+        $ty: ident, $trait: ident, $method: ident, $assign_trait: ident, $assign_method: ident,
+        $rhs: ident, $output: ident, $checked: expr, $message: expr
+    ) => {
+        impl $trait<$rhs> for $ty {
+            type Output = $output;
+
+            fn $method(self, other: $rhs) -> $output {
+                match $checked(self, other) {
+                    Some(result) => result,
+                    None => crate::env::panic_str($message),
+                }
+            }
+        }
+
+        impl $assign_trait<$rhs> for $ty {
+            fn $assign_method(&mut self, other: $rhs) {
+                *self = $trait::$method(*self, other);
+            }
+        }
+    };
+}
+
+impl_op!(
+    Timestamp,
+    Add,
+    add,
+    AddAssign,
+    add_assign,
+    Duration,
+    Timestamp,
+    Timestamp::checked_add,
+    "Arithmetic overflow in Timestamp addition"
+);
+impl_op!(
+    Timestamp,
+    Sub,
+    sub,
+    SubAssign,
+    sub_assign,
+    Duration,
+    Timestamp,
+    Timestamp::checked_sub,
+    "Arithmetic overflow in Timestamp subtraction"
+);
+impl_op!(
+    Duration,
+    Add,
+    add,
+    AddAssign,
+    add_assign,
+    Duration,
+    Duration,
+    Duration::checked_add,
+    "Arithmetic overflow in Duration addition"
+);
+impl_op!(
+    Duration,
+    Sub,
+    sub,
+    SubAssign,
+    sub_assign,
+    Duration,
+    Duration,
+    Duration::checked_sub,
+    "Arithmetic overflow in Duration subtraction"
+);
+
+impl Sub for Timestamp {
+    type Output = Duration;
+
+    /// The time passed since `other`. Panics if `other` is later than `self`.
+    fn sub(self, other: Self) -> Duration {
+        match self.checked_duration_since(other) {
+            Some(duration) => duration,
+            None => crate::env::panic_str("Arithmetic overflow in Timestamp subtraction"),
+        }
+    }
+}
+
+/// Parses the nanoseconds from a JSON string or number.
+struct NanosVisitor;
+
+impl<'de> Visitor<'de> for NanosVisitor {
+    type Value = u64;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a non-negative integer or a string with one")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<u64, E> {
+        Ok(value)
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<u64, E> {
+        if value < 0 {
+            return Err(E::invalid_value(de::Unexpected::Signed(value), &self));
+        }
+        Ok(value as u64)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<u64, E> {
+        value.parse().map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+}
+
+macro_rules! impl_serde {
+    ($ty: ident) => {
+        impl Serialize for $ty {
+            fn serialize<S>(
+                &self,
+                serializer: S,
+            ) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+            where
+                S: Serializer,
+            {
+                serializer.serialize_str(&self.0.to_string())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D>(deserializer: D) -> Result<Self, <D as Deserializer<'de>>::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserializer.deserialize_any(NanosVisitor).map(Self)
+            }
+        }
+    };
+}
+
+impl_serde!(Timestamp);
+impl_serde!(Duration);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let start = Timestamp::from_secs(1_600_000_000);
+        let end = start + Duration::from_secs(60);
+        assert_eq!(end.as_nanos(), 1_600_000_060 * NANOS_PER_SEC);
+        assert_eq!(end.as_secs(), 1_600_000_060);
+        assert_eq!(end - start, Duration::from_secs(60));
+        assert_eq!(end - Duration::from_millis(60_000), start);
+        assert!(start < end);
+        assert!(end > start + Duration::from_nanos(59 * NANOS_PER_SEC));
+
+        let mut deadline = start;
+        deadline += Duration::from_secs(1) + Duration::from_millis(500);
+        assert_eq!((deadline - start).as_millis(), 1_500);
+        assert_eq!((deadline - start).as_secs(), 1);
+        deadline -= Duration::from_millis(500);
+        assert_eq!(deadline, Timestamp::from_nanos(start.as_nanos() + NANOS_PER_SEC));
+
+        assert_eq!(start.checked_duration_since(end), None);
+        assert_eq!(
+            Timestamp::from_nanos(u64::max_value()).checked_add(Duration::from_nanos(1)),
+            None
+        );
+        assert_eq!(Duration::from_nanos(1).checked_sub(Duration::from_nanos(2)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Arithmetic overflow in Timestamp subtraction")]
+    fn test_duration_since_later() {
+        crate::test_utils::test_env::setup();
+        let _ = Timestamp::from_nanos(1) - Timestamp::from_nanos(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Arithmetic overflow in Duration::from_secs")]
+    fn test_from_secs_overflow() {
+        crate::test_utils::test_env::setup();
+        Duration::from_secs(u64::max_value());
+    }
+
+    #[test]
+    fn test_now() {
+        crate::test_utils::test_env::setup();
+        assert_eq!(Timestamp::now().as_nanos(), crate::env::block_timestamp());
+    }
+
+    #[test]
+    fn test_millis() {
+        let timestamp = Timestamp::from_nanos(1_600_000_000_123_999_999);
+        assert_eq!(timestamp.as_millis(), 1_600_000_000_123);
+        assert_eq!(timestamp.as_secs(), 1_600_000_000);
+        assert_eq!(Timestamp::from_millis(1_600_000_000_123).as_nanos(), 1_600_000_000_123_000_000);
+    }
+
+    #[test]
+    fn test_serde() {
+        let duration: Duration = serde_json::from_str("1000000000").unwrap();
+        assert_eq!(duration, Duration::from_secs(1));
+        let duration: Duration = serde_json::from_str(r#""1000000000""#).unwrap();
+        assert_eq!(duration, Duration::from_secs(1));
+        assert_eq!(serde_json::to_string(&duration).unwrap(), r#""1000000000""#);
+        assert!(serde_json::from_str::<Duration>("-1").is_err());
+        assert!(serde_json::from_str::<Duration>("1.5").is_err());
+        assert!(serde_json::from_str::<Duration>(r#""1s""#).is_err());
+
+        let timestamp = Timestamp::from_nanos(u64::max_value());
+        let json = serde_json::to_string(&timestamp).unwrap();
+        assert_eq!(json, format!("\"{}\"", u64::max_value()));
+        assert_eq!(serde_json::from_str::<Timestamp>(&json).unwrap(), timestamp);
+
+        let bytes = timestamp.try_to_vec().unwrap();
+        assert_eq!(bytes, u64::max_value().try_to_vec().unwrap());
+        assert_eq!(Timestamp::try_from_slice(&bytes).unwrap(), timestamp);
+    }
+}
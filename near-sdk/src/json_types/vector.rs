@@ -1,6 +1,7 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use serde::de::Error;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::ops::Deref;
 
 /// Helper class to serialize/deserialize `Vec<u8>` to base64 string.
 #[derive(Debug, Clone, PartialEq, BorshDeserialize, BorshSerialize)]
@@ -18,6 +19,14 @@ impl From<Base64VecU8> for Vec<u8> {
     }
 }
 
+impl Deref for Base64VecU8 {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Vec<u8> {
+        &self.0
+    }
+}
+
 impl Serialize for Base64VecU8 {
     fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
     where
@@ -81,4 +90,24 @@ mod tests {
         let a_deser: Base64VecU8 = serde_json::from_str(&a_str).unwrap();
         assert_eq!(a_deser.0, a);
     }
+
+    #[test]
+    fn test_all_bytes() {
+        test_serde!((0..=255).collect());
+    }
+
+    #[test]
+    fn test_deref() {
+        let a = Base64VecU8(vec![1, 2, 3]);
+        assert_eq!(a.len(), 3);
+        assert_eq!(&a[..], &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_borsh() {
+        let a = Base64VecU8((0..=255).collect());
+        let bytes = a.try_to_vec().unwrap();
+        assert_eq!(bytes, a.0.try_to_vec().unwrap());
+        assert_eq!(Base64VecU8::try_from_slice(&bytes).unwrap(), a);
+    }
 }
@@ -3,7 +3,7 @@ extern crate quickcheck;
 
 pub use near_sdk_macros::{
     callback, callback_vec, ext_contract, init, metadata, near_bindgen, result_serializer,
-    serializer, PanicOnDefault,
+    serializer, PanicCode, PanicOnDefault,
 };
 
 pub mod collections;
@@ -14,10 +14,17 @@ mod promise;
 pub use promise::{Promise, PromiseOrValue};
 
 mod metadata;
+#[cfg(feature = "abi")]
+pub use metadata::json_schema_string;
 pub use metadata::{Metadata, MethodMetadata};
 
 pub mod json_types;
 
+pub mod events;
+
+mod bn254;
+pub mod groth16;
+
 pub use environment::mocked_blockchain::MockedBlockchain;
 pub use near_runtime_fees::RuntimeFeesConfig;
 pub use near_vm_logic::types::*;
@@ -32,6 +39,9 @@ pub use environment::blockchain_interface::BlockchainInterface;
 pub mod test_utils;
 
 // Exporting common crates
+#[cfg(feature = "abi")]
+#[doc(hidden)]
+pub use schemars;
 
 #[doc(hidden)]
 pub use borsh;
@@ -48,5 +58,8 @@ pub use serde;
 #[doc(hidden)]
 pub use serde_json;
 
+#[doc(hidden)]
+pub use serde_urlencoded;
+
 #[doc(hidden)]
 pub use wee_alloc;
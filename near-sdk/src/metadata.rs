@@ -1,6 +1,7 @@
 use borsh::{schema::BorshSchemaContainer, BorshDeserialize, BorshSchema, BorshSerialize};
-/// Version of the metadata format.
-const METADATA_SEMVER: [u32; 3] = [0, 1, 0];
+/// Version of the metadata format. Bumped to `0.2.0` by `deprecated`, `bounded_input` and, with
+/// the `abi` feature, `args_json_schema` of `MethodMetadata`, which changed the Borsh layout.
+const METADATA_SEMVER: [u32; 3] = [0, 2, 0];
 
 /// Metadata of the contract.
 #[derive(BorshSerialize, BorshDeserialize, BorshSchema, Debug, PartialEq)]
@@ -15,6 +16,53 @@ impl Metadata {
     pub fn new(methods: Vec<MethodMetadata>) -> Self {
         Self { version: METADATA_SEMVER, methods }
     }
+
+    /// Serializes the metadata into JSON for the tools that generate client types, e.g. TypeScript
+    /// interfaces, in the following shape:
+    ///
+    /// ```json
+    /// {
+    ///   "version": [0, 2, 0],
+    ///   "methods": [
+    ///     {
+    ///       "name": "ft_transfer",
+    ///       "is_view": false,
+    ///       "is_init": false,
+    ///       "args": { "type": "object", "properties": { "amount": { "type": "string", "format": "uint128" } } },
+    ///       "deprecated": null,
+    ///       "bounded_input": false
+    ///     }
+    ///   ]
+    /// }
+    /// ```
+    ///
+    /// `args` is the JSON Schema of the arguments, or `null` if they are not serialized with JSON.
+    /// The types that are serialized into strings, like `U128` or `ValidAccountId`, have
+    /// `"type": "string"` and a `format` naming the value, while `u64` stays an integer with
+    /// `"format": "uint64"`, which generators usually map to a big integer type.
+    #[cfg(feature = "abi")]
+    pub fn to_json(&self) -> String {
+        let methods: Vec<serde_json::Value> = self
+            .methods
+            .iter()
+            .map(|method| {
+                let args = match &method.args_json_schema {
+                    Some(schema) => serde_json::from_str(schema)
+                        .expect("Failed to parse the JSON Schema of the arguments"),
+                    None => serde_json::Value::Null,
+                };
+                serde_json::json!({
+                    "name": method.name,
+                    "is_view": method.is_view,
+                    "is_init": method.is_init,
+                    "args": args,
+                    "deprecated": method.deprecated,
+                    "bounded_input": method.bounded_input,
+                })
+            })
+            .collect();
+        serde_json::json!({ "version": self.version, "methods": methods }).to_string()
+    }
 }
 
 /// Metadata of a single method.
@@ -27,10 +75,96 @@ pub struct MethodMetadata {
     pub is_init: bool,
     /// Schema of the arguments of the method.
     pub args: Option<BorshSchemaContainer>,
+    /// JSON Schema (draft-07) of the arguments of the method, if they are serialized with JSON.
+    #[cfg(feature = "abi")]
+    pub args_json_schema: Option<String>,
     /// Schemas for each callback of the method.
     pub callbacks: Vec<BorshSchemaContainer>,
     /// If all callbacks have the same type then this field can be used instead.
     pub callbacks_vec: Option<BorshSchemaContainer>,
     /// Schema of the return type.
     pub result: Option<BorshSchemaContainer>,
+    /// The note of `#[deprecated(note = "...")]` if the method is deprecated, or an empty string
+    /// if the attribute has no note.
+    pub deprecated: Option<String>,
+    /// Whether all arguments of the method have a bounded size. The input of the methods with
+    /// arguments like `Vec<u8>`, `String` or the structs of the contract can be arbitrarily large,
+    /// which tooling can flag as potentially expensive.
+    pub bounded_input: bool,
+}
+
+/// Serializes the JSON Schema (draft-07) of the type. Used for the arguments of the methods in the
+/// metadata when the `abi` feature is enabled.
+#[cfg(feature = "abi")]
+pub fn json_schema_string<T: schemars::JsonSchema>() -> String {
+    serde_json::to_string(&schemars::schema_for!(T)).expect("Failed to serialize the JSON Schema")
+}
+
+#[cfg(feature = "abi")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    #[derive(schemars::JsonSchema)]
+    #[allow(dead_code)]
+    struct Input {
+        amount: u64,
+        receiver_id: String,
+    }
+
+    #[test]
+    fn test_args_json_schema() {
+        let schema: Value = serde_json::from_str(&json_schema_string::<Input>()).unwrap();
+        assert_eq!(schema["$schema"], "http://json-schema.org/draft-07/schema#");
+        assert_eq!(schema["type"], "object");
+        assert_eq!(schema["properties"]["amount"]["type"], "integer");
+        assert_eq!(schema["properties"]["receiver_id"]["type"], "string");
+        assert_eq!(schema["required"], serde_json::json!(["amount", "receiver_id"]));
+    }
+
+    #[derive(schemars::JsonSchema)]
+    #[allow(dead_code)]
+    struct TransferInput {
+        receiver_id: crate::json_types::ValidAccountId,
+        amount: crate::json_types::U128,
+        nonce: u64,
+    }
+
+    #[test]
+    fn test_metadata_json() {
+        let method = MethodMetadata {
+            name: "ft_transfer".to_string(),
+            is_view: false,
+            is_init: false,
+            args: None,
+            args_json_schema: Some(json_schema_string::<TransferInput>()),
+            callbacks: vec![],
+            callbacks_vec: None,
+            result: None,
+            deprecated: None,
+            bounded_input: true,
+        };
+        let json: Value = serde_json::from_str(&Metadata::new(vec![method]).to_json()).unwrap();
+        assert_eq!(json["version"], serde_json::json!(METADATA_SEMVER));
+        let method = &json["methods"][0];
+        assert_eq!(method["name"], "ft_transfer");
+        assert_eq!(method["is_view"], false);
+        assert_eq!(method["bounded_input"], true);
+        let schema = &method["args"];
+        let definition = |property: &str| {
+            let reference = schema["properties"][property]["$ref"].as_str().unwrap();
+            schema["definitions"][reference.trim_start_matches("#/definitions/")].clone()
+        };
+        assert_eq!(
+            definition("amount"),
+            serde_json::json!({"type": "string", "format": "uint128"})
+        );
+        assert_eq!(
+            definition("receiver_id"),
+            serde_json::json!({"type": "string", "format": "account-id"})
+        );
+        assert_eq!(schema["properties"]["nonce"]["type"], "integer");
+        assert_eq!(schema["properties"]["nonce"]["format"], "uint64");
+    }
 }
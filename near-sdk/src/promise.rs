@@ -16,6 +16,13 @@ pub enum PromiseAction {
         amount: Balance,
         gas: Gas,
     },
+    FunctionCallWeight {
+        method_name: Vec<u8>,
+        arguments: Vec<u8>,
+        amount: Balance,
+        gas: Gas,
+        gas_weight: u64,
+    },
     Transfer {
         amount: Balance,
     },
@@ -57,6 +64,16 @@ impl PromiseAction {
                     *gas,
                 )
             }
+            FunctionCallWeight { method_name, arguments, amount, gas, gas_weight } => {
+                crate::env::promise_batch_action_function_call_weight(
+                    promise_index,
+                    &method_name,
+                    &arguments,
+                    *amount,
+                    *gas,
+                    *gas_weight,
+                )
+            }
             Transfer { amount } => {
                 crate::env::promise_batch_action_transfer(promise_index, *amount)
             }
@@ -218,6 +235,42 @@ impl Promise {
         }
     }
 
+    /// Create a promise that creates the account, funds it with `amount`, deploys `code` to it and
+    /// calls its initialization method, all in one batch, which is the flow of factory contracts:
+    ///
+    /// ```
+    /// # use near_sdk::{env, Promise};
+    /// # near_sdk::test_utils::test_env::setup();
+    /// # let code = vec![];
+    /// Promise::create_contract_account(
+    ///     format!("token.{}", env::current_account_id()),
+    ///     5 * 10u128.pow(24),
+    ///     code,
+    ///     "new",
+    ///     br#"{"owner_id": "alice.near"}"#.to_vec(),
+    ///     20_000_000_000_000,
+    /// );
+    /// ```
+    ///
+    /// The actions of a batch are applied in order and fail together, so if the initialization
+    /// panics the account is not created and `amount` is refunded to the current account. The
+    /// contract can only create its subaccounts, like `token.factory.near` for `factory.near`.
+    pub fn create_contract_account(
+        account_id: AccountId,
+        amount: Balance,
+        code: Vec<u8>,
+        init_method: &str,
+        init_args: Vec<u8>,
+        gas: Gas,
+    ) -> Self {
+        Self::new(account_id).create_account().transfer(amount).deploy_contract(code).function_call(
+            init_method.as_bytes().to_vec(),
+            init_args,
+            0,
+            gas,
+        )
+    }
+
     fn add_action(self, action: PromiseAction) -> Self {
         match &self.subtype {
             PromiseSubtype::Single(x) => x.actions.borrow_mut().push(action),
@@ -247,6 +300,57 @@ impl Promise {
         self.add_action(PromiseAction::FunctionCall { method_name, arguments, amount, gas })
     }
 
+    /// Same as `function_call`, but `gas` is only the minimum amount of gas attached to the call.
+    /// The unused gas of the current execution is distributed between the function calls
+    /// proportionally to their `gas_weight`.
+    pub fn function_call_weight(
+        self,
+        method_name: Vec<u8>,
+        arguments: Vec<u8>,
+        amount: Balance,
+        gas: Gas,
+        gas_weight: u64,
+    ) -> Self {
+        self.add_action(PromiseAction::FunctionCallWeight {
+            method_name,
+            arguments,
+            amount,
+            gas,
+            gas_weight,
+        })
+    }
+
+    /// Sets the gas weight of the last function call of this promise, so that it receives a share
+    /// of the unused gas proportional to `gas_weight`, e.g.
+    /// `contract_b::b(&"bob_near".to_string(), 0, 1_000).gas_weight(1)`.
+    pub fn gas_weight(self, gas_weight: u64) -> Self {
+        match &self.subtype {
+            PromiseSubtype::Single(x) => {
+                let mut actions = x.actions.borrow_mut();
+                let action = actions.pop();
+                match action {
+                    Some(PromiseAction::FunctionCall { method_name, arguments, amount, gas })
+                    | Some(PromiseAction::FunctionCallWeight {
+                        method_name,
+                        arguments,
+                        amount,
+                        gas,
+                        ..
+                    }) => actions.push(PromiseAction::FunctionCallWeight {
+                        method_name,
+                        arguments,
+                        amount,
+                        gas,
+                        gas_weight,
+                    }),
+                    _ => panic!("Gas weight can only be set for a function call."),
+                }
+            }
+            PromiseSubtype::Joint(_) => panic!("Cannot add action to a joint promise."),
+        }
+        self
+    }
+
     /// Transfer tokens to the account that this promise acts on.
     pub fn transfer(self, amount: Balance) -> Self {
         self.add_action(PromiseAction::Transfer { amount })
@@ -369,6 +473,12 @@ impl Promise {
         self
     }
 
+    /// The same promise that is not returned, so that a callback can be attached to it without
+    /// changing the return value of the method.
+    pub(crate) fn clone_without_return(&self) -> Self {
+        Self { subtype: self.subtype.clone(), should_return: RefCell::new(false) }
+    }
+
     fn construct_recursively(&self) -> PromiseIndex {
         let res = match &self.subtype {
             PromiseSubtype::Single(x) => x.construct_recursively(),
@@ -397,6 +507,9 @@ impl serde::Serialize for Promise {
     }
 }
 
+/// Return type of the methods that either return a value right away or delegate the result to a
+/// promise. When a method of `#[near_bindgen]` returns it, the promise is returned with
+/// `env::promise_return` and only the value is serialized.
 pub enum PromiseOrValue<T> {
     Promise(Promise),
     Value(T),
@@ -447,3 +560,75 @@ impl<T: borsh::BorshSerialize> borsh::BorshSerialize for PromiseOrValue<T> {
         }
     }
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{get_created_receipts, get_gas_weights, get_return_data, test_env};
+    use near_vm_logic::types::ReturnData;
+
+    #[test]
+    fn test_gas_weight() {
+        test_env::setup();
+        Promise::new("bob_near".to_string())
+            .function_call(b"method".to_vec(), b"{}".to_vec(), 0, 1_000)
+            .gas_weight(3);
+        Promise::new("alice_near".to_string()).function_call_weight(
+            b"other".to_vec(),
+            b"{}".to_vec(),
+            0,
+            2_000,
+            1,
+        );
+        let receipts = format!("{:?}", get_created_receipts());
+        assert!(receipts.contains("gas: 1000"));
+        assert!(receipts.contains("gas: 2000"));
+        assert_eq!(get_gas_weights(), vec![(0, 3), (1, 1)]);
+    }
+
+    #[test]
+    fn test_create_contract_account() {
+        test_env::setup();
+        Promise::create_contract_account(
+            "token.alice.near".to_string(),
+            1_000,
+            vec![1, 2, 3],
+            "new",
+            b"{}".to_vec(),
+            5_000,
+        );
+        let receipts = get_created_receipts();
+        assert_eq!(receipts.len(), 1);
+        let receipt = format!("{:?}", receipts[0]);
+        assert!(receipt.contains(&format!("{:?}", "token.alice.near")));
+        let positions: Vec<usize> = ["CreateAccount", "Transfer", "DeployContract", "FunctionCall"]
+            .iter()
+            .map(|action| receipt.find(action).expect("Missing action"))
+            .collect();
+        let mut sorted = positions.clone();
+        sorted.sort();
+        assert_eq!(positions, sorted);
+        assert!(receipt.contains("deposit: 1000"));
+        assert!(receipt.contains(&format!("{:?}", b"new".to_vec())));
+        assert!(receipt.contains("gas: 5000"));
+    }
+
+    #[test]
+    fn test_as_return() {
+        test_env::setup();
+        // The generated wrappers return the promise of `PromiseOrValue::Promise` this way.
+        Promise::new("bob_near".to_string()).as_return();
+        match get_return_data() {
+            ReturnData::ReceiptIndex(idx) => assert_eq!(idx, 0),
+            _ => panic!("Expected the promise to be returned"),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Gas weight can only be set for a function call.")]
+    fn test_gas_weight_without_function_call() {
+        test_env::setup();
+        Promise::new("bob_near".to_string()).transfer(1).gas_weight(1);
+    }
+}
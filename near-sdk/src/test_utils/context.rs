@@ -1,5 +1,5 @@
 use crate::test_utils::test_env::*;
-use crate::{AccountId, Balance, BlockHeight, EpochHeight, PublicKey, VMContext};
+use crate::{AccountId, Balance, BlockHeight, EpochHeight, Gas, PublicKey, VMContext};
 
 /// Simple VMContext builder that allows to quickly create custom context in tests.
 pub struct VMContextBuilder {
@@ -56,6 +56,12 @@ impl VMContextBuilder {
         self
     }
 
+    /// Sets the block timestamp in nanoseconds.
+    pub fn block_timestamp(&mut self, block_timestamp: u64) -> &mut Self {
+        self.context.block_timestamp = block_timestamp;
+        self
+    }
+
     pub fn epoch_height(&mut self, epoch_height: EpochHeight) -> &mut Self {
         self.context.epoch_height = epoch_height;
         self
@@ -81,6 +87,16 @@ impl VMContextBuilder {
         self
     }
 
+    pub fn input(&mut self, input: Vec<u8>) -> &mut Self {
+        self.context.input = input;
+        self
+    }
+
+    pub fn prepaid_gas(&mut self, gas: Gas) -> &mut Self {
+        self.context.prepaid_gas = gas;
+        self
+    }
+
     pub fn build(&self) -> VMContext {
         self.context.clone()
     }
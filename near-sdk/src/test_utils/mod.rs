@@ -6,16 +6,17 @@ pub mod test_env;
 mod context;
 pub use context::VMContextBuilder;
 use near_vm_logic::mocks::mock_external::Receipt;
+use near_vm_logic::types::ReturnData;
 
 #[macro_export]
 macro_rules! testing_env {
     ($context:expr, $config:expr, $fee_config:expr, $validator:expr, $promise_results:expr) => {
-        let storage = match near_sdk::env::take_blockchain_interface() {
+        let storage = match $crate::env::take_blockchain_interface() {
             Some(mut bi) => bi.as_mut_mocked_blockchain().unwrap().take_storage(),
             None => Default::default(),
         };
 
-        near_sdk::env::set_blockchain_interface(Box::new(MockedBlockchain::new(
+        $crate::env::set_blockchain_interface(Box::new($crate::MockedBlockchain::new(
             $context,
             $config,
             $fee_config,
@@ -25,14 +26,14 @@ macro_rules! testing_env {
         )));
     };
     ($context:expr, $config:expr, $fee_config:expr, $validator:expr) => {
-        testing_env!($context, $config, $fee_config, $validator, Default::default());
+        $crate::testing_env!($context, $config, $fee_config, $validator, Default::default());
     };
 
     ($context:expr, $config:expr, $fee_config:expr) => {
-        testing_env!($context, $config, $fee_config, Default::default());
+        $crate::testing_env!($context, $config, $fee_config, Default::default());
     };
     ($context:expr) => {
-        testing_env!($context, Default::default(), Default::default());
+        $crate::testing_env!($context, Default::default(), Default::default());
     };
 }
 
@@ -49,6 +50,32 @@ pub fn get_logs() -> Vec<String> {
     logs
 }
 
+/// Returns the value or the promise returned by the contract so far. Only available in unit tests.
+#[allow(dead_code)]
+pub fn get_return_data() -> ReturnData {
+    let blockchain_interface =
+        env::take_blockchain_interface().expect("Blockchain interface is not set");
+    let return_data = blockchain_interface
+        .as_mocked_blockchain()
+        .expect("MockedBlockchain interface expected")
+        .return_data();
+    env::set_blockchain_interface(blockchain_interface);
+    return_data
+}
+
+/// Removes everything from the storage of the mocked blockchain, so that the next unit test doesn't
+/// see the state left by the previous one. Only available in unit tests.
+#[allow(dead_code)]
+pub fn clear_storage() {
+    let mut blockchain_interface =
+        env::take_blockchain_interface().expect("Blockchain interface is not set");
+    blockchain_interface
+        .as_mut_mocked_blockchain()
+        .expect("MockedBlockchain interface expected")
+        .take_storage();
+    env::set_blockchain_interface(blockchain_interface);
+}
+
 /// Accessing receipts created by the contract. Only available in unit tests.
 #[allow(dead_code)]
 pub fn get_created_receipts() -> Vec<Receipt> {
@@ -63,6 +90,20 @@ pub fn get_created_receipts() -> Vec<Receipt> {
     receipts
 }
 
+/// Accessing the promise indices and the gas weights of the function calls scheduled with a weight
+/// by the contract, which `get_created_receipts` doesn't show. Only available in unit tests.
+#[allow(dead_code)]
+pub fn get_gas_weights() -> Vec<(u64, u64)> {
+    let blockchain_interface =
+        env::take_blockchain_interface().expect("Blockchain interface is not set");
+    let gas_weights = blockchain_interface
+        .as_mocked_blockchain()
+        .expect("MockedBlockchain interface expected")
+        .gas_weights();
+    env::set_blockchain_interface(blockchain_interface);
+    gas_weights
+}
+
 /// Objects stored on the trie directly should have identifiers. If identifier is not provided
 /// explicitly than `Default` trait would use this index to generate an id.
 #[allow(dead_code)]
@@ -1,15 +1,373 @@
-use crate::AccountId;
+use crate::{AccountId, BlockHeight, Gas, StorageUsage};
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
 
+mod payout;
+pub use payout::{payout_with_fee, split_fee};
+
+mod sdk_error;
+pub use sdk_error::SdkError;
+
+mod storage_management;
+pub use storage_management::{storage_cost, storage_refund, StorageManagement, STORAGE_BYTE_COST};
+
+/// Logs the message formatted like with `format!`.
+///
+/// ```
+/// # use near_sdk::log;
+/// # near_sdk::test_utils::test_env::setup();
+/// let amount = 5;
+/// log!("Transferred {} to {}", amount, "bob.near");
+/// ```
 #[macro_export]
 macro_rules! log {
-    ($arg:tt) => {
-        crate::env::log($arg.as_bytes())
+    ($arg:expr) => {
+        $crate::env::log_str(::std::convert::AsRef::<str>::as_ref(&$arg))
     };
     ($($arg:tt)*) => {
-        crate::env::log(format!($($arg)*).as_bytes())
+        $crate::env::log_str(format!($($arg)*).as_str())
+    };
+}
+
+/// Unwraps the result of a checked arithmetic operation, like `a.checked_add(b)`, or terminates
+/// the execution with a message that names the overflowing expression.
+///
+/// ```
+/// # use near_sdk::require_no_overflow;
+/// let total: u128 = require_no_overflow!(10u128.checked_add(20));
+/// assert_eq!(total, 30);
+/// ```
+#[macro_export]
+macro_rules! require_no_overflow {
+    ($value:expr) => {
+        $crate::require_no_overflow!(
+            $value,
+            concat!("Arithmetic overflow in `", stringify!($value), "`")
+        )
+    };
+    ($value:expr, $message:expr) => {
+        match $value {
+            Some(value) => value,
+            None => $crate::env::panic_str($message),
+        }
     };
 }
 
+/// Storage key of the flag that is set while a method decorated with `#[non_reentrant]` executes.
+/// There is one flag for the whole contract, so while it is set all `#[non_reentrant]` methods
+/// panic for every caller, including while the promise returned by such a method is pending.
+pub const NON_REENTRANT_KEY: &[u8] = b"__NON_REENTRANT";
+
+/// Sets the reentrancy flag or panics if it is already set. Called on entry to the methods
+/// decorated with `#[non_reentrant]`.
+pub fn enter_non_reentrant(method_name: &str) {
+    if crate::env::storage_write(NON_REENTRANT_KEY, &[]) {
+        SdkError::Reentrancy { method_name: method_name.to_string() }.panic();
+    }
+}
+
+/// Clears the reentrancy flag. Called on exit from the methods decorated with `#[non_reentrant]`.
+/// If the method panics the flag is cleared by the host, which reverts all state changes.
+pub fn exit_non_reentrant() {
+    crate::env::storage_remove(NON_REENTRANT_KEY);
+}
+
+/// Gas attached to the callback that clears the reentrancy flag.
+pub const EXIT_NON_REENTRANT_GAS: Gas = 5_000_000_000_000;
+
+/// Keeps the reentrancy flag set until the `promise` resolves, and schedules the `callback` method
+/// of the current account that clears it after that. Called on exit from the methods
+/// decorated with `#[non_reentrant]` that return the promise, since the calls that the promise
+/// makes can call the contract back. The callback is called whether the promise succeeds or fails,
+/// but if the callback itself fails, e.g. runs out of gas, the flag stays set until it is cleared
+/// with the method generated by `#[near_bindgen(reset_non_reentrant)]`.
+pub fn exit_non_reentrant_after(promise: &crate::Promise, callback: &str) {
+    promise.clone_without_return().then(
+        crate::Promise::new(crate::env::current_account_id().into()).function_call(
+            callback.as_bytes().to_vec(),
+            vec![],
+            0,
+            EXIT_NON_REENTRANT_GAS,
+        ),
+    );
+}
+
+/// Clears the reentrancy flag in the callback scheduled by `exit_non_reentrant_after`. Panics if
+/// the callback isn't called by the contract itself.
+pub fn exit_non_reentrant_callback() {
+    if crate::env::predecessor_account_id() != crate::env::current_account_id() {
+        SdkError::Unauthorized.panic();
+    }
+    exit_non_reentrant();
+}
+
+/// Storage key of the flag that is set while a `#[near_bindgen(pausable)]` contract is paused.
+pub const PAUSED_KEY: &[u8] = b"__PAUSED";
+
+/// Whether the contract is paused with the generated `pause` method of
+/// a `#[near_bindgen(pausable)]` contract.
+pub fn is_paused() -> bool {
+    crate::env::storage_has_key(PAUSED_KEY)
+}
+
+/// Pauses or unpauses the contract. Called by the `pause` and `unpause` methods generated for
+/// `#[near_bindgen(pausable)]`.
+pub fn set_paused(paused: bool) {
+    if paused {
+        crate::env::storage_write(PAUSED_KEY, &[]);
+    } else {
+        crate::env::storage_remove(PAUSED_KEY);
+    }
+}
+
+/// Panics if the contract is paused. Called before the methods that write the state when
+/// the `impl` section is decorated with `#[near_bindgen(pausable)]`.
+pub fn assert_not_paused() {
+    if is_paused() {
+        SdkError::Paused.panic();
+    }
+}
+
+/// Storage key of the owner of a `#[near_bindgen(ownable)]` contract.
+pub const OWNER_KEY: &[u8] = b"__OWNER";
+
+/// The owner of a `#[near_bindgen(ownable)]` contract, or `None` if the ownership was renounced or
+/// the contract was never initialized. Returned by the generated `owner` method.
+pub fn owner_id() -> Option<AccountId> {
+    crate::env::storage_read(OWNER_KEY)
+        .map(|owner_id| String::from_utf8(owner_id).expect("The owner is not valid UTF-8"))
+}
+
+/// Sets the owner of the contract, or removes it if `owner_id` is `None`. Called by the `#[init]`
+/// methods of a `#[near_bindgen(ownable)]` contract with the predecessor, so an `#[init]` method
+/// can call it to choose another owner.
+pub fn set_owner_id(owner_id: Option<&AccountId>) {
+    match owner_id {
+        Some(owner_id) => {
+            crate::env::storage_write(OWNER_KEY, owner_id.as_bytes());
+        }
+        None => {
+            crate::env::storage_remove(OWNER_KEY);
+        }
+    }
+}
+
+/// Panics unless the predecessor is the owner of the contract. Once the ownership is renounced
+/// nobody passes the check.
+pub fn assert_owner() {
+    match owner_id() {
+        Some(owner_id) if crate::env::predecessor_account_id() == owner_id => {}
+        Some(_) => SdkError::NotOwner.panic(),
+        None => SdkError::NoOwner.panic(),
+    }
+}
+
+/// Makes `new_owner_id` the owner of the contract. Panics unless called by the current owner.
+/// Called by the `transfer_ownership` method of a `#[near_bindgen(ownable)]` contract.
+pub fn transfer_ownership(new_owner_id: &AccountId) {
+    assert_owner();
+    set_owner_id(Some(new_owner_id));
+}
+
+/// Removes the owner of the contract, which disables everything that requires the owner for good.
+/// Panics unless called by the current owner. Called by the `renounce_ownership` method of
+/// a `#[near_bindgen(ownable)]` contract.
+pub fn renounce_ownership() {
+    assert_owner();
+    set_owner_id(None);
+}
+
+/// Panics if the current block height is past `expires_at`. Called before the methods with
+/// an argument decorated with `#[expires_at]`.
+pub fn assert_not_expired(expires_at: BlockHeight) {
+    if crate::env::block_index() > expires_at {
+        crate::env::panic(b"Request expired");
+    }
+}
+
+/// Panics if the input is longer than `max_len` bytes. Called before the arguments of the methods
+/// decorated with `#[max_input_len = N]` are deserialized, so that a huge input doesn't burn gas on
+/// decoding. Only the length of the input register is read, the input isn't copied.
+pub fn assert_input_len(max_len: u64) {
+    if crate::env::input_len() > max_len {
+        crate::env::panic(b"Input is too long");
+    }
+}
+
+/// Panics if less than `min_gas` is attached to the call. Called at the start of the methods
+/// decorated with `#[min_gas(tgas = N)]`, so that a call, e.g. a callback, scheduled with too
+/// little gas fails with a clear message instead of running out of gas in the middle.
+pub fn assert_min_gas(min_gas: Gas) {
+    let prepaid_gas = crate::env::prepaid_gas();
+    if prepaid_gas < min_gas {
+        crate::env::panic(
+            format!(
+                "Insufficient gas attached: {} is required, but only {} is attached",
+                min_gas, prepaid_gas
+            )
+            .as_bytes(),
+        );
+    }
+}
+
+/// Panics if the contract uses more than `max_storage` bytes of storage, which reverts the changes
+/// made by the method. Called after the methods that write the state when the `impl` section is
+/// decorated with `#[near_bindgen(max_storage = N)]`.
+pub fn assert_storage_usage(max_storage: StorageUsage) {
+    let usage = crate::env::storage_usage();
+    if usage > max_storage {
+        crate::env::panic(
+            format!("Storage usage {} exceeds the limit of {} bytes", usage, max_storage)
+                .as_bytes(),
+        );
+    }
+}
+
+/// Serializes the value to JSON with the keys of every object in sorted order, so that the result
+/// doesn't depend on the iteration order of maps like `HashMap`. Used for the return values of the
+/// methods decorated with `#[result_serializer(json, sorted_keys)]`.
+pub fn to_json_vec_sorted<T: Serialize + ?Sized>(value: &T) -> serde_json::Result<Vec<u8>> {
+    serde_json::to_vec(&sort_keys(serde_json::to_value(value)?))
+}
+
+/// Compresses the data with gzip. Used for the return values of the methods decorated with
+/// `#[result_serializer(json, gzip)]`, which requires the `gzip` feature of `near-sdk`.
+///
+/// The compression runs inside the contract, so it costs noticeably more gas than returning the
+/// JSON as is, and pays off only for large and repetitive results like config blobs, which shrink
+/// several times. The caller has to decompress the result itself.
+#[cfg(feature = "gzip")]
+pub fn gzip(data: &[u8]) -> Vec<u8> {
+    use std::io::Write;
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(data).expect("Failed to compress with gzip.");
+    encoder.finish().expect("Failed to compress with gzip.")
+}
+
+fn sort_keys(value: Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.into_iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            Value::Object(entries.into_iter().map(|(key, value)| (key, sort_keys(value))).collect())
+        }
+        Value::Array(values) => Value::Array(values.into_iter().map(sort_keys).collect()),
+        value => value,
+    }
+}
+
+/// Panics unless exactly 1 yoctoNEAR is attached, which confirms that the call is signed with a full
+/// access key, as function call access keys can't attach a deposit. Called before the methods
+/// decorated with `#[assert_one_yocto]`.
+pub fn assert_one_yocto() {
+    if crate::env::attached_deposit() != 1 {
+        SdkError::OneYoctoRequired.panic();
+    }
+}
+
+/// Deserializes the Borsh value from the beginning of `data` and ignores the bytes after it, unlike
+/// `BorshDeserialize::try_from_slice` which fails on them. Used for the arguments decorated with
+/// `#[serializer(borsh, allow_trailing)]`, so that newer callers can append fields to the input.
+pub fn borsh_from_slice_allow_trailing<T: BorshDeserialize>(data: &[u8]) -> std::io::Result<T> {
+    T::deserialize(&mut &data[..])
+}
+
+/// Deserializes the input of a method that is called both by frontends with JSON and by contracts
+/// with Borsh. Used for the arguments decorated with `#[serializer(auto_by_caller)]`.
+///
+/// The format is guessed from the caller: if the predecessor is the signer of the transaction, the
+/// method is called directly by a user and JSON is tried first, otherwise it is called by a
+/// contract and Borsh is tried first. If the first format fails, the other one is tried, so the
+/// guess only decides which format wins when the input is valid in both, e.g. the bytes `1234` are
+/// the JSON of the `u32` 1234 as well as the Borsh of 875770417. A contract that calls a method with
+/// JSON relies on the fallback, and a contract calling itself in a transaction signed by its own
+/// key is treated as a user. Returns `None` if neither format fits.
+pub fn deserialize_by_caller<T: DeserializeOwned + BorshDeserialize>(input: &[u8]) -> Option<T> {
+    let from_json = || serde_json::from_slice(input).ok();
+    let from_borsh = || T::try_from_slice(input).ok();
+    if crate::env::predecessor_account_id() == crate::env::signer_account_id() {
+        from_json().or_else(from_borsh)
+    } else {
+        from_borsh().or_else(from_json)
+    }
+}
+
+/// The `numerator / denominator` fraction of `gas`, rounded down. Meant for splitting the gas that
+/// is left for the current call between the promises it creates instead of hardcoding the amounts:
+///
+/// ```
+/// # use near_sdk::{env, gas_fraction};
+/// # near_sdk::test_utils::test_env::setup();
+/// let remaining = env::prepaid_gas() - env::used_gas();
+/// let callback_gas = gas_fraction(remaining, 1, 3);
+/// # assert!(callback_gas < remaining);
+/// ```
+///
+/// Panics if `denominator` is zero or the fraction is greater than one.
+pub fn gas_fraction(gas: Gas, numerator: u64, denominator: u64) -> Gas {
+    if denominator == 0 || numerator > denominator {
+        crate::env::panic(b"Invalid gas fraction");
+    }
+    // Can't overflow `u64` as the fraction is at most one.
+    (u128::from(gas) * u128::from(numerator) / u128::from(denominator)) as Gas
+}
+
+/// The sha256 hash of the Borsh schema of `T`, which changes when a field is added, removed,
+/// renamed or changes its type. Used by `#[near_bindgen(state_layout_hash)]` on the contract
+/// struct, which requires the struct to derive `BorshSchema`.
+pub fn state_layout_hash<T: borsh::BorshSchema>() -> Vec<u8> {
+    let schema = T::schema_container()
+        .try_to_vec()
+        .unwrap_or_else(|_| crate::env::panic(b"Failed to serialize the Borsh schema"));
+    crate::env::sha256(&schema)
+}
+
+/// Error with a stable code for the tooling that matches on the errors of the contract, usually
+/// derived with `#[derive(PanicCode)]`:
+///
+/// ```
+/// use near_sdk::PanicCode;
+///
+/// #[derive(PanicCode)]
+/// enum TokenError {
+///     #[panic_code("E101", "Insufficient balance")]
+///     InsufficientBalance,
+///     #[panic_code("E102", "Unknown account")]
+///     UnknownAccount { account_id: String },
+/// }
+///
+/// assert_eq!(TokenError::InsufficientBalance.panic_message(), "E101: Insufficient balance");
+/// ```
+pub trait PanicCode {
+    /// The stable code of the error, e.g. `E101`.
+    fn code(&self) -> &'static str;
+
+    /// The description of the error, e.g. `Insufficient balance`.
+    fn message(&self) -> &'static str;
+
+    /// The error formatted as `<code>: <message>`, e.g. `E101: Insufficient balance`.
+    fn panic_message(&self) -> String {
+        format!("{}: {}", self.code(), self.message())
+    }
+}
+
+/// Panics with the code and the message of the error, e.g. `E101: Insufficient balance`. Called with
+/// the error returned by the methods decorated with `#[handle_result]`.
+pub fn panic_code<E: PanicCode>(error: &E) -> ! {
+    crate::env::panic_str(&error.panic_message())
+}
+
+/// Panics with "Unauthorized" unless the predecessor is one of the `accounts`. Called before the
+/// methods decorated with `#[only(...)]`.
+pub fn assert_only(accounts: &[&str]) {
+    let predecessor = crate::env::predecessor_account_id();
+    if !accounts.iter().any(|&account| predecessor.as_ref() == account) {
+        SdkError::Unauthorized.panic();
+    }
+}
+
 #[derive(Debug)]
 pub struct PendingContractTx {
     pub receiver_id: AccountId,
@@ -31,7 +389,9 @@ impl PendingContractTx {
 
 #[cfg(test)]
 mod tests {
-    use crate::test_utils::{get_logs, test_env};
+    use super::*;
+    use crate::test_utils::{get_logs, test_env, VMContextBuilder};
+    use crate::testing_env;
 
     #[test]
     fn test_log_simple() {
@@ -48,4 +408,433 @@ mod tests {
 
         assert_eq!(get_logs(), vec!["hello user_name (25)".to_string()]);
     }
+
+    #[test]
+    fn test_log_variable() {
+        test_env::setup();
+        log!("x = {}", 5);
+
+        assert_eq!(get_logs(), vec!["x = 5".to_string()]);
+    }
+
+    #[test]
+    fn test_log_string() {
+        struct Event {
+            message: String,
+        }
+
+        test_env::setup();
+        let message = "hello".to_string();
+        log!(message);
+        let event = Event { message: "world".to_string() };
+        log!(event.message);
+
+        assert_eq!(get_logs(), vec!["hello".to_string(), "world".to_string()]);
+    }
+
+    #[test]
+    fn test_require_no_overflow() {
+        test_env::setup();
+        let balance: u128 = require_no_overflow!(u128::max_value().checked_sub(1));
+        assert_eq!(balance, u128::max_value() - 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Arithmetic overflow in `u128::max_value().checked_add(1)`")]
+    fn test_require_no_overflow_add() {
+        test_env::setup();
+        require_no_overflow!(u128::max_value().checked_add(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Not enough balance")]
+    fn test_require_no_overflow_sub() {
+        test_env::setup();
+        let balance = crate::json_types::U128(5);
+        require_no_overflow!(balance.checked_sub(6.into()), "Not enough balance");
+    }
+
+    #[test]
+    fn test_non_reentrant() {
+        test_env::setup();
+        enter_non_reentrant("withdraw");
+        exit_non_reentrant();
+        enter_non_reentrant("withdraw");
+        exit_non_reentrant();
+        assert!(!crate::env::storage_has_key(NON_REENTRANT_KEY));
+    }
+
+    #[test]
+    #[should_panic(expected = "Reentrant call of method withdraw")]
+    fn test_non_reentrant_rejects_reentrant_call() {
+        test_env::setup();
+        enter_non_reentrant("deposit");
+        enter_non_reentrant("withdraw");
+    }
+
+    #[test]
+    fn test_non_reentrant_after_promise() {
+        test_env::setup();
+        enter_non_reentrant("withdraw");
+        let promise = crate::Promise::new(test_env::bob()).transfer(1);
+        exit_non_reentrant_after(&promise, "__exit_non_reentrant_withdraw");
+        promise.as_return();
+        // The flag stays set until the callback, which is scheduled after the returned promise.
+        assert!(crate::env::storage_has_key(NON_REENTRANT_KEY));
+        match crate::test_utils::get_return_data() {
+            near_vm_logic::types::ReturnData::ReceiptIndex(idx) => assert_eq!(idx, 0),
+            _ => panic!("Expected the promise to be returned"),
+        }
+        let receipts = crate::test_utils::get_created_receipts();
+        assert_eq!(receipts.len(), 2);
+        let callback = format!("{:?}", receipts[1]);
+        assert!(callback.contains(&format!("{:?}", b"__exit_non_reentrant_withdraw".to_vec())));
+        assert!(callback.contains(&format!("{:?}", test_env::alice())));
+
+        testing_env!(VMContextBuilder::new().predecessor_account_id(test_env::alice()).build());
+        exit_non_reentrant_callback();
+        assert!(!crate::env::storage_has_key(NON_REENTRANT_KEY));
+    }
+
+    #[test]
+    #[should_panic(expected = "Unauthorized")]
+    fn test_non_reentrant_callback_is_private() {
+        test_env::setup();
+        exit_non_reentrant_callback();
+    }
+
+    #[test]
+    fn test_pause() {
+        test_env::setup();
+        assert!(!is_paused());
+        assert_not_paused();
+        set_paused(true);
+        assert!(is_paused());
+        assert!(std::panic::catch_unwind(assert_not_paused).is_err());
+        set_paused(false);
+        assert!(!is_paused());
+        assert_not_paused();
+    }
+
+    #[test]
+    #[should_panic(expected = "The contract is paused")]
+    fn test_paused_rejects_calls() {
+        test_env::setup();
+        set_paused(true);
+        assert_not_paused();
+    }
+
+    /// Switches the predecessor while keeping the storage, like consecutive calls to the contract.
+    fn call_as(predecessor: &str) {
+        testing_env!(VMContextBuilder::new()
+            .predecessor_account_id(predecessor.to_string())
+            .build());
+    }
+
+    #[test]
+    fn test_transfer_ownership() {
+        call_as("alice.near");
+        assert_eq!(owner_id(), None);
+        set_owner_id(Some(&"alice.near".to_string()));
+        transfer_ownership(&"bob.near".to_string());
+        assert_eq!(owner_id(), Some("bob.near".to_string()));
+        call_as("bob.near");
+        assert_owner();
+        transfer_ownership(&"carol.near".to_string());
+        assert_eq!(owner_id(), Some("carol.near".to_string()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Method can only be called by the owner")]
+    fn test_unauthorized_transfer_ownership() {
+        call_as("alice.near");
+        set_owner_id(Some(&"alice.near".to_string()));
+        call_as("bob.near");
+        transfer_ownership(&"bob.near".to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "The contract has no owner")]
+    fn test_renounce_ownership() {
+        call_as("alice.near");
+        set_owner_id(Some(&"alice.near".to_string()));
+        renounce_ownership();
+        assert_eq!(owner_id(), None);
+        transfer_ownership(&"alice.near".to_string());
+    }
+
+    enum TokenError {
+        InsufficientBalance,
+    }
+
+    impl PanicCode for TokenError {
+        fn code(&self) -> &'static str {
+            "E101"
+        }
+
+        fn message(&self) -> &'static str {
+            "Insufficient balance"
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "E101: Insufficient balance")]
+    fn test_panic_code() {
+        test_env::setup();
+        panic_code(&TokenError::InsufficientBalance);
+    }
+
+    #[test]
+    fn test_borsh_from_slice_allow_trailing() {
+        #[derive(BorshSerialize, BorshDeserialize, Debug, PartialEq)]
+        struct Transfer {
+            receiver_id: String,
+            amount: u64,
+        }
+
+        let transfer = Transfer { receiver_id: "bob.near".to_string(), amount: 5 };
+        let mut data = transfer.try_to_vec().unwrap();
+        assert_eq!(borsh_from_slice_allow_trailing::<Transfer>(&data).unwrap(), transfer);
+        data.extend_from_slice(&[1, 2, 3]);
+        assert!(Transfer::try_from_slice(&data).is_err());
+        assert_eq!(borsh_from_slice_allow_trailing::<Transfer>(&data).unwrap(), transfer);
+        assert!(borsh_from_slice_allow_trailing::<Transfer>(&data[..4]).is_err());
+    }
+
+    #[derive(BorshSerialize, BorshDeserialize, Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Transfer {
+        receiver_id: String,
+        amount: u64,
+    }
+
+    #[test]
+    fn test_deserialize_by_caller_user() {
+        testing_env!(VMContextBuilder::new()
+            .signer_account_id("alice.near".to_string())
+            .predecessor_account_id("alice.near".to_string())
+            .build());
+        let transfer = Transfer { receiver_id: "bob.near".to_string(), amount: 5 };
+        let json = serde_json::to_vec(&transfer).unwrap();
+        assert_eq!(deserialize_by_caller::<Transfer>(&json), Some(transfer));
+        let transfer = Transfer { receiver_id: "bob.near".to_string(), amount: 5 };
+        let borsh = transfer.try_to_vec().unwrap();
+        assert_eq!(deserialize_by_caller::<Transfer>(&borsh), Some(transfer));
+        assert_eq!(deserialize_by_caller::<Transfer>(b"{}"), None);
+        // Valid in both formats, so JSON wins for the user.
+        assert_eq!(deserialize_by_caller::<u32>(b"1234"), Some(1234));
+    }
+
+    #[test]
+    fn test_deserialize_by_caller_contract() {
+        testing_env!(VMContextBuilder::new()
+            .signer_account_id("alice.near".to_string())
+            .predecessor_account_id("exchange.near".to_string())
+            .build());
+        let transfer = Transfer { receiver_id: "bob.near".to_string(), amount: 5 };
+        let borsh = transfer.try_to_vec().unwrap();
+        assert_eq!(deserialize_by_caller::<Transfer>(&borsh), Some(transfer));
+        let transfer = Transfer { receiver_id: "bob.near".to_string(), amount: 5 };
+        let json = serde_json::to_vec(&transfer).unwrap();
+        assert_eq!(deserialize_by_caller::<Transfer>(&json), Some(transfer));
+        // Valid in both formats, so Borsh wins for the contract.
+        assert_eq!(deserialize_by_caller::<u32>(b"1234"), Some(u32::from_le_bytes(*b"1234")));
+    }
+
+    #[test]
+    fn test_gas_fraction() {
+        assert_eq!(gas_fraction(300, 1, 3), 100);
+        assert_eq!(gas_fraction(10, 1, 3), 3);
+        assert_eq!(gas_fraction(10, 2, 3), 6);
+        assert_eq!(gas_fraction(10, 0, 3), 0);
+        assert_eq!(gas_fraction(10, 3, 3), 10);
+        assert_eq!(
+            gas_fraction(u64::max_value(), u64::max_value() - 1, u64::max_value()),
+            u64::max_value() - 1
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid gas fraction")]
+    fn test_gas_fraction_zero_denominator() {
+        test_env::setup();
+        gas_fraction(10, 0, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid gas fraction")]
+    fn test_gas_fraction_greater_than_one() {
+        test_env::setup();
+        gas_fraction(10, 4, 3);
+    }
+
+    #[test]
+    fn test_only() {
+        test_env::setup();
+        assert_only(&["bob.near"]);
+        assert_only(&["alice.near", "bob.near"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unauthorized")]
+    fn test_only_unauthorized() {
+        test_env::setup();
+        assert_only(&[crate::env::current_account_id().as_ref().as_str(), "carol.near"]);
+    }
+
+    #[test]
+    fn test_one_yocto() {
+        testing_env!(VMContextBuilder::new().attached_deposit(1).build());
+        assert_one_yocto();
+    }
+
+    #[test]
+    #[should_panic(expected = "Requires attached deposit of exactly 1 yoctoNEAR")]
+    fn test_one_yocto_zero_deposit() {
+        testing_env!(VMContextBuilder::new().attached_deposit(0).build());
+        assert_one_yocto();
+    }
+
+    #[test]
+    #[should_panic(expected = "Requires attached deposit of exactly 1 yoctoNEAR")]
+    fn test_one_yocto_two_yocto() {
+        testing_env!(VMContextBuilder::new().attached_deposit(2).build());
+        assert_one_yocto();
+    }
+
+    #[test]
+    fn test_not_expired() {
+        testing_env!(VMContextBuilder::new().block_index(10).build());
+        assert_not_expired(10);
+        assert_not_expired(11);
+    }
+
+    #[test]
+    #[should_panic(expected = "Request expired")]
+    fn test_expired() {
+        testing_env!(VMContextBuilder::new().block_index(10).build());
+        assert_not_expired(9);
+    }
+
+    #[test]
+    fn test_input_len() {
+        testing_env!(VMContextBuilder::new().input(vec![0; 16]).build());
+        assert_input_len(16);
+        testing_env!(VMContextBuilder::new().input(vec![]).build());
+        assert_input_len(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Input is too long")]
+    fn test_input_too_long() {
+        // A Borsh `Vec<u64>` whose length prefix alone would make the decoder allocate a lot.
+        let mut input = u32::max_value().to_le_bytes().to_vec();
+        input.extend(vec![0; 16]);
+        testing_env!(VMContextBuilder::new().input(input).build());
+        assert_input_len(16);
+        let _: Vec<u64> = borsh::BorshDeserialize::try_from_slice(&crate::env::input().unwrap())
+            .expect("The input must be rejected before decoding");
+    }
+
+    #[test]
+    fn test_min_gas() {
+        testing_env!(VMContextBuilder::new().prepaid_gas(20 * 10u64.pow(12)).build());
+        assert_min_gas(20 * 10u64.pow(12));
+        assert_min_gas(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Insufficient gas attached")]
+    fn test_insufficient_gas() {
+        testing_env!(VMContextBuilder::new().prepaid_gas(5 * 10u64.pow(12)).build());
+        assert_min_gas(20 * 10u64.pow(12));
+    }
+
+    #[test]
+    fn test_storage_usage_within_limit() {
+        test_env::setup();
+        crate::env::storage_write(b"key", b"value");
+        assert_storage_usage(crate::env::storage_usage());
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeds the limit of")]
+    fn test_storage_usage_exceeds_limit() {
+        test_env::setup();
+        let limit = crate::env::storage_usage();
+        crate::env::storage_write(b"key", &[0; 1000]);
+        assert_storage_usage(limit);
+    }
+
+    mod v1 {
+        #[derive(borsh::BorshSchema)]
+        pub struct Contract {
+            pub owner: String,
+            pub total: u128,
+        }
+    }
+
+    mod v2 {
+        #[derive(borsh::BorshSchema)]
+        pub struct Contract {
+            pub owner: String,
+            pub total: u128,
+            pub paused: bool,
+        }
+    }
+
+    mod v1_copy {
+        #[derive(borsh::BorshSchema)]
+        pub struct Contract {
+            pub owner: String,
+            pub total: u128,
+        }
+    }
+
+    #[test]
+    fn test_state_layout_hash() {
+        test_env::setup();
+        let v1 = state_layout_hash::<v1::Contract>();
+        assert_eq!(v1.len(), 32);
+        assert_eq!(v1, state_layout_hash::<v1_copy::Contract>());
+        assert_ne!(v1, state_layout_hash::<v2::Contract>());
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn test_gzip() {
+        use std::io::Read;
+
+        let config: Vec<String> = (0..100).map(|i| format!("validator-{}.near", i)).collect();
+        let json = serde_json::to_vec(&config).unwrap();
+        let compressed = gzip(&json);
+        // Gzip magic bytes.
+        assert_eq!(&compressed[..2], &[0x1f, 0x8b]);
+        assert!(compressed.len() < json.len() / 2);
+        let mut decompressed = Vec::new();
+        flate2::read::GzDecoder::new(&compressed[..]).read_to_end(&mut decompressed).unwrap();
+        assert_eq!(decompressed, json);
+        assert_eq!(serde_json::from_slice::<Vec<String>>(&decompressed).unwrap(), config);
+    }
+
+    #[test]
+    fn test_to_json_vec_sorted() {
+        use std::collections::HashMap;
+
+        // Every `HashMap` gets its own random hasher, so the two maps most likely iterate in different orders.
+        let build_map = || {
+            let mut map = HashMap::new();
+            for i in 0..20 {
+                let mut inner = HashMap::new();
+                inner.insert(format!("b{}", i), i);
+                inner.insert(format!("a{}", i), i);
+                map.insert(format!("key{:02}", i), inner);
+            }
+            map
+        };
+        let first = to_json_vec_sorted(&build_map()).unwrap();
+        let second = to_json_vec_sorted(&build_map()).unwrap();
+        assert_eq!(first, second);
+        let json = String::from_utf8(first).unwrap();
+        assert!(json.starts_with(r#"{"key00":{"a0":0,"b0":0},"key01":{"a1":1,"b1":1}"#));
+    }
 }
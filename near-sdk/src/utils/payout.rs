@@ -0,0 +1,79 @@
+use crate::{env, AccountId, Balance, Promise};
+
+/// Splits `total` into the amount of the recipient and the fee of the relayer.
+/// Panics if the fee exceeds the total.
+pub fn split_fee(total: Balance, fee: Balance) -> (Balance, Balance) {
+    match total.checked_sub(fee) {
+        Some(amount) => (amount, fee),
+        None => env::panic(format!("The fee {} exceeds the amount {}", fee, total).as_bytes()),
+    }
+}
+
+/// Pays out `total` to `recipient` minus the `fee` of the `relayer` that submitted the
+/// transaction, e.g. of a withdrawal from a shielded pool, and returns the transfers joined with
+/// `Promise::and`. The fee is validated before any promise is created, so the method either
+/// schedules both transfers or none, and no transfer to the relayer is scheduled if the fee is zero.
+///
+/// ```
+/// # near_sdk::test_utils::test_env::setup();
+/// use near_sdk::payout_with_fee;
+///
+/// payout_with_fee("alice.near".to_string(), "relayer.near".to_string(), 1_000, 10);
+/// ```
+pub fn payout_with_fee(
+    recipient: AccountId,
+    relayer: AccountId,
+    total: Balance,
+    fee: Balance,
+) -> Promise {
+    let (amount, fee) = split_fee(total, fee);
+    let payout = Promise::new(recipient).transfer(amount);
+    if fee == 0 {
+        payout
+    } else {
+        payout.and(Promise::new(relayer).transfer(fee))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{get_created_receipts, test_env};
+
+    #[test]
+    fn test_split_fee() {
+        assert_eq!(split_fee(1_000, 10), (990, 10));
+        assert_eq!(split_fee(1_000, 1_000), (0, 1_000));
+        assert_eq!(split_fee(u128::max_value(), 1), (u128::max_value() - 1, 1));
+        assert_eq!(split_fee(0, 0), (0, 0));
+    }
+
+    #[test]
+    fn test_payout_with_fee() {
+        test_env::setup();
+        payout_with_fee("alice.near".to_string(), "relayer.near".to_string(), 1_000, 10);
+        let receipts: Vec<_> =
+            get_created_receipts().iter().map(|receipt| format!("{:?}", receipt)).collect();
+        assert_eq!(receipts.len(), 2);
+        assert!(receipts[0].contains("alice.near") && receipts[0].contains("deposit: 990"));
+        assert!(receipts[1].contains("relayer.near") && receipts[1].contains("deposit: 10"));
+    }
+
+    #[test]
+    fn test_payout_without_fee() {
+        test_env::setup();
+        payout_with_fee("alice.near".to_string(), "relayer.near".to_string(), 1_000, 0);
+        let receipts = get_created_receipts();
+        assert_eq!(receipts.len(), 1);
+        let receipt = format!("{:?}", receipts[0]);
+        assert!(receipt.contains("alice.near") && receipt.contains("deposit: 1000"));
+    }
+
+    #[test]
+    #[should_panic(expected = "The fee 1001 exceeds the amount 1000")]
+    fn test_fee_exceeds_total() {
+        test_env::setup();
+        payout_with_fee("alice.near".to_string(), "relayer.near".to_string(), 1_000, 1_001);
+    }
+}
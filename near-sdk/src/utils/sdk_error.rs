@@ -0,0 +1,124 @@
+use std::fmt;
+
+/// Failures of the checks made by the generated wrappers and the helpers of the SDK. Their
+/// messages are stable, so that tooling can recognize them in the panic messages of failed
+/// transactions, and the contracts can panic with the same messages.
+///
+/// ```
+/// use near_sdk::SdkError;
+///
+/// assert_eq!(SdkError::Unauthorized.to_string(), "Unauthorized");
+/// assert_eq!(
+///     SdkError::Reentrancy { method_name: "withdraw".to_string() }.to_string(),
+///     "Reentrant call of method withdraw"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum SdkError {
+    /// The input of the method couldn't be deserialized with the `serializer`, e.g. `JSON`.
+    DeserializationFailed { serializer: &'static str, error: Option<String> },
+    /// The balance is less than the amount that is taken from it.
+    InsufficientBalance,
+    /// The predecessor isn't allowed to call the method.
+    Unauthorized,
+    /// The predecessor isn't the owner of a `#[near_bindgen(ownable)]` contract.
+    NotOwner,
+    /// The ownership of a `#[near_bindgen(ownable)]` contract was renounced or never set.
+    NoOwner,
+    /// A `#[near_bindgen(pausable)]` contract is paused.
+    Paused,
+    /// A deposit is attached to a method that isn't `#[payable]`.
+    DepositNotAccepted { method_name: String },
+    /// A deposit other than 1 yoctoNEAR is attached to an `#[assert_one_yocto]` method.
+    OneYoctoRequired,
+    /// The zk-SNARK proof isn't valid for the public inputs.
+    InvalidProof,
+    /// The method was entered while a `#[non_reentrant]` method executes.
+    Reentrancy { method_name: String },
+}
+
+impl SdkError {
+    /// Terminates the execution with the message of the error.
+    pub fn panic(&self) -> ! {
+        crate::env::panic_str(&self.to_string())
+    }
+}
+
+impl fmt::Display for SdkError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SdkError::DeserializationFailed { serializer, error: Some(error) } => {
+                write!(f, "Failed to deserialize input from {}: {}", serializer, error)
+            }
+            SdkError::DeserializationFailed { serializer, error: None } => {
+                write!(f, "Failed to deserialize input from {}", serializer)
+            }
+            SdkError::InsufficientBalance => write!(f, "Insufficient balance"),
+            SdkError::Unauthorized => write!(f, "Unauthorized"),
+            SdkError::NotOwner => write!(f, "Method can only be called by the owner"),
+            SdkError::NoOwner => write!(f, "The contract has no owner"),
+            SdkError::Paused => write!(f, "The contract is paused"),
+            SdkError::DepositNotAccepted { method_name } => {
+                write!(f, "Method {} doesn't accept deposit", method_name)
+            }
+            SdkError::OneYoctoRequired => {
+                write!(f, "Requires attached deposit of exactly 1 yoctoNEAR")
+            }
+            SdkError::InvalidProof => write!(f, "Invalid proof"),
+            SdkError::Reentrancy { method_name } => {
+                write!(f, "Reentrant call of method {}", method_name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SdkError {}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::test_env;
+
+    #[test]
+    fn test_messages() {
+        assert_eq!(
+            SdkError::DeserializationFailed {
+                serializer: "JSON",
+                error: Some("EOF while parsing a value at line 1 column 0".to_string()),
+            }
+            .to_string(),
+            "Failed to deserialize input from JSON: EOF while parsing a value at line 1 column 0"
+        );
+        assert_eq!(
+            SdkError::DeserializationFailed { serializer: "JSON or Borsh", error: None }
+                .to_string(),
+            "Failed to deserialize input from JSON or Borsh"
+        );
+        assert_eq!(SdkError::InsufficientBalance.to_string(), "Insufficient balance");
+        assert_eq!(SdkError::Unauthorized.to_string(), "Unauthorized");
+        assert_eq!(SdkError::NotOwner.to_string(), "Method can only be called by the owner");
+        assert_eq!(SdkError::NoOwner.to_string(), "The contract has no owner");
+        assert_eq!(SdkError::Paused.to_string(), "The contract is paused");
+        assert_eq!(
+            SdkError::DepositNotAccepted { method_name: "withdraw".to_string() }.to_string(),
+            "Method withdraw doesn't accept deposit"
+        );
+        assert_eq!(
+            SdkError::OneYoctoRequired.to_string(),
+            "Requires attached deposit of exactly 1 yoctoNEAR"
+        );
+        assert_eq!(SdkError::InvalidProof.to_string(), "Invalid proof");
+        assert_eq!(
+            SdkError::Reentrancy { method_name: "withdraw".to_string() }.to_string(),
+            "Reentrant call of method withdraw"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid proof")]
+    fn test_panic() {
+        test_env::setup();
+        SdkError::InvalidProof.panic();
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{env, Balance, Promise, StorageUsage};
+
+/// The cost of storing one byte in yoctoNEAR, i.e. 1 NEAR per 100 kB. The contract account has to
+/// keep this amount locked per byte of its storage.
+pub const STORAGE_BYTE_COST: Balance = 10_000_000_000_000_000_000;
+
+/// Makes the caller pay for the storage added by a method with the attached deposit:
+///
+/// ```
+/// # use near_sdk::{env, testing_env, MockedBlockchain, StorageManagement, STORAGE_BYTE_COST};
+/// # use near_sdk::test_utils::VMContextBuilder;
+/// # testing_env!(VMContextBuilder::new().attached_deposit(STORAGE_BYTE_COST * 1_000).build());
+/// let storage = StorageManagement::start();
+/// env::storage_write(b"key", b"value");
+/// let added_bytes = storage.settle();
+/// env::log(format!("Added {} bytes", added_bytes).as_bytes());
+/// ```
+pub struct StorageManagement {
+    initial_usage: StorageUsage,
+}
+
+impl StorageManagement {
+    /// Records the storage usage before the method changes the state.
+    pub fn start() -> Self {
+        Self { initial_usage: env::storage_usage() }
+    }
+
+    /// Charges the attached deposit for the bytes added since `start`, refunds the rest of it to
+    /// the predecessor and returns the change of the storage usage in bytes. The change is negative
+    /// if the method freed storage, and nothing is charged in that case. Panics with the shortfall
+    /// if the deposit doesn't cover the cost.
+    pub fn settle(self) -> i64 {
+        let usage = env::storage_usage();
+        let change = usage as i64 - self.initial_usage as i64;
+        let added = usage.saturating_sub(self.initial_usage);
+        let refund = storage_refund(added, env::attached_deposit());
+        if refund > 0 {
+            Promise::new(env::predecessor_account_id().into()).transfer(refund);
+        }
+        change
+    }
+}
+
+/// The storage cost of `bytes`.
+pub fn storage_cost(bytes: StorageUsage) -> Balance {
+    Balance::from(bytes) * STORAGE_BYTE_COST
+}
+
+/// The part of `attached_deposit` left after paying for `bytes` of storage. Panics with the
+/// shortfall if the deposit doesn't cover the cost.
+pub fn storage_refund(bytes: StorageUsage, attached_deposit: Balance) -> Balance {
+    let cost = storage_cost(bytes);
+    if attached_deposit < cost {
+        env::panic(
+            format!(
+                "Attached deposit is {} yoctoNEAR short of the cost of storing {} bytes",
+                cost - attached_deposit,
+                bytes
+            )
+            .as_bytes(),
+        );
+    }
+    attached_deposit - cost
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{get_created_receipts, test_env, VMContextBuilder};
+    use crate::testing_env;
+
+    #[test]
+    fn test_exact_payment() {
+        assert_eq!(storage_refund(100, 100 * STORAGE_BYTE_COST), 0);
+        assert_eq!(storage_refund(0, 0), 0);
+    }
+
+    #[test]
+    fn test_overpayment() {
+        assert_eq!(storage_refund(100, 101 * STORAGE_BYTE_COST + 5), STORAGE_BYTE_COST + 5);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Attached deposit is 10000000000000000000 yoctoNEAR short of the cost of storing 100 bytes"
+    )]
+    fn test_underpayment() {
+        test_env::setup();
+        storage_refund(100, 99 * STORAGE_BYTE_COST);
+    }
+
+    #[test]
+    fn test_settle_refunds_excess() {
+        testing_env!(VMContextBuilder::new().attached_deposit(STORAGE_BYTE_COST * 1_000).build());
+        let storage = StorageManagement::start();
+        let initial_usage = env::storage_usage();
+        env::storage_write(b"key", &[0u8; 100]);
+        let added = env::storage_usage() - initial_usage;
+        assert_eq!(storage.settle(), added as i64);
+
+        let receipts = get_created_receipts();
+        assert_eq!(receipts.len(), 1);
+        let refund = STORAGE_BYTE_COST * (1_000 - u128::from(added));
+        assert!(format!("{:?}", receipts[0]).contains(&format!("deposit: {}", refund)));
+    }
+
+    #[test]
+    fn test_settle_freed_storage() {
+        testing_env!(VMContextBuilder::new().attached_deposit(0).build());
+        env::storage_write(b"key", &[0u8; 100]);
+        let storage = StorageManagement::start();
+        env::storage_remove(b"key");
+        assert!(storage.settle() < 0);
+        assert!(get_created_receipts().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "yoctoNEAR short of the cost of storing")]
+    fn test_settle_underpayment() {
+        testing_env!(VMContextBuilder::new().attached_deposit(STORAGE_BYTE_COST).build());
+        let storage = StorageManagement::start();
+        env::storage_write(b"key", &[0u8; 100]);
+        storage.settle();
+    }
+}
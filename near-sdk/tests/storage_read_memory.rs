@@ -0,0 +1,83 @@
+//! Compares the peak memory of reading a 1MB value with `env::storage_read` against reading it
+//! from a `ChunkedValue`. The allocations of the test binary are counted by a global allocator,
+//! so the measurements are in a single test that doesn't run in parallel with other tests.
+//! Run with `cargo test --test storage_read_memory -- --nocapture` to see the numbers.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::io::Read;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use near_sdk::collections::ChunkedValue;
+use near_sdk::env;
+use near_sdk::test_utils::test_env;
+
+struct CountingAllocator;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let allocated = ALLOCATED.fetch_add(layout.size(), Ordering::SeqCst) + layout.size();
+        PEAK.fetch_max(allocated, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        ALLOCATED.fetch_sub(layout.size(), Ordering::SeqCst);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+const VALUE_LEN: usize = 1 << 20;
+const CHUNK_LEN: u32 = 1 << 14;
+
+/// Returns the peak of the memory allocated by `f` above the memory allocated before the call.
+fn peak_memory<F: FnOnce()>(f: F) -> usize {
+    let before = ALLOCATED.load(Ordering::SeqCst);
+    PEAK.store(before, Ordering::SeqCst);
+    f();
+    PEAK.load(Ordering::SeqCst) - before
+}
+
+/// A stand-in for the processing of the value, which reads every byte of it.
+fn checksum(bytes: &[u8]) -> u64 {
+    bytes.iter().map(|&b| b as u64).sum()
+}
+
+#[test]
+fn test_peak_memory_of_storage_reads() {
+    test_env::setup_free();
+    let value: Vec<u8> = (0..VALUE_LEN).map(|i| i as u8).collect();
+    let expected = checksum(&value);
+    env::storage_write(b"value", &value);
+    let mut blob = ChunkedValue::new(b"b".to_vec(), CHUNK_LEN);
+    blob.set(&value);
+    drop(value);
+
+    let full_read = peak_memory(|| {
+        let value = env::storage_read(b"value").unwrap();
+        assert_eq!(checksum(&value), expected);
+    });
+    let chunked_read = peak_memory(|| {
+        let mut reader = blob.reader();
+        let mut buf = vec![0u8; CHUNK_LEN as usize];
+        let mut sum = 0;
+        loop {
+            let len = reader.read(&mut buf).unwrap();
+            if len == 0 {
+                break;
+            }
+            sum += checksum(&buf[..len]);
+        }
+        assert_eq!(sum, expected);
+    });
+    println!("Peak memory: full read {} bytes, chunked read {} bytes", full_read, chunked_read);
+
+    // The full read holds the value in the register and in the returned vector.
+    assert!(full_read >= 2 * VALUE_LEN);
+    // The chunked read holds a few chunks: in the register, in the reader and in `buf`.
+    assert!(chunked_read < 8 * CHUNK_LEN as usize);
+}